@@ -0,0 +1,138 @@
+//! Benchmarks comparing figures whose traces copy the same data series with figures whose traces
+//! share it.
+//!
+//! Besides the timings reported by criterion, the `memory` benchmark prints the number of bytes
+//! allocated when placing the same data series in several figures.
+//!
+//! Run using `cargo bench --bench shared_data`.
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use plotting::{AxisData, Figure, Format, Trace};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Number of data points in each data series.
+const POINTS: usize = 1_000_000;
+
+/// Number of figures that the data series are placed in.
+const FIGURES: usize = 4;
+
+/// Allocator counting the number of bytes allocated.
+struct CountingAllocator;
+
+/// Number of bytes allocated so far.
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Data series (time and signal).
+///
+/// # Returns
+///
+/// Time and signal data.
+fn data() -> (Vec<f64>, Vec<f64>) {
+    let time: Vec<f64> = (0..POINTS).map(|i| i as f64 * 1e-3).collect();
+    let signal = time.iter().map(|t| t.sin()).collect();
+    (time, signal)
+}
+
+/// Place copies of the data series in several figures.
+///
+/// # Arguments
+///
+/// * `time` - Time data.
+/// * `signal` - Signal data.
+///
+/// # Returns
+///
+/// Figures.
+fn copied_figures(time: &[f64], signal: &[f64]) -> Vec<Figure> {
+    (0..FIGURES)
+        .map(|_| Figure::new(vec![Trace::new_2d(time, signal)], Format::default()))
+        .collect()
+}
+
+/// Place the shared data series in several figures.
+///
+/// # Arguments
+///
+/// * `time` - Time data.
+/// * `signal` - Signal data.
+///
+/// # Returns
+///
+/// Figures.
+fn shared_figures(time: &AxisData, signal: &AxisData) -> Vec<Figure> {
+    (0..FIGURES)
+        .map(|_| Figure::new(vec![Trace::new_2d(time, signal)], Format::default()))
+        .collect()
+}
+
+/// Number of bytes allocated by a function.
+///
+/// # Arguments
+///
+/// * `f` - Function.
+///
+/// # Returns
+///
+/// Number of bytes allocated.
+fn allocated<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    black_box(f());
+    ALLOCATED.load(Ordering::Relaxed) - before
+}
+
+fn memory(_: &mut Criterion) {
+    let (time, signal) = data();
+    let (shared_time, shared_signal) = (AxisData::from(&time[..]), AxisData::from(&signal[..]));
+    let copied = allocated(|| copied_figures(&time, &signal));
+    let shared = allocated(|| shared_figures(&shared_time, &shared_signal));
+    let figures = shared_figures(&shared_time, &shared_signal);
+    let plotly = allocated(|| figures[0].plotly());
+    println!("memory allocated to place {POINTS} points in {FIGURES} figures:");
+    println!("  copied: {:>12} bytes", copied);
+    println!("  shared: {:>12} bytes", shared);
+    println!("memory allocated to create a plotly plot from a figure:");
+    println!("  plotly: {:>12} bytes", plotly);
+}
+
+fn figures(c: &mut Criterion) {
+    let (time, signal) = data();
+    let (shared_time, shared_signal) = (AxisData::from(&time[..]), AxisData::from(&signal[..]));
+    let mut group = c.benchmark_group("figures");
+    group.sample_size(10);
+    group.bench_function(BenchmarkId::new("copied", FIGURES), |b| {
+        b.iter(|| copied_figures(black_box(&time), black_box(&signal)))
+    });
+    group.bench_function(BenchmarkId::new("shared", FIGURES), |b| {
+        b.iter(|| shared_figures(black_box(&shared_time), black_box(&shared_signal)))
+    });
+    group.finish();
+}
+
+fn render(c: &mut Criterion) {
+    let (time, signal) = data();
+    let figure = Figure::new(vec![Trace::new_2d(time, signal)], Format::default());
+    let mut group = c.benchmark_group("render");
+    group.sample_size(10);
+    group.bench_function("plotly", |b| b.iter(|| black_box(&figure).plotly()));
+    group.bench_function("json", |b| b.iter(|| black_box(&figure).to_plotly_json()));
+    group.finish();
+}
+
+criterion_group!(benches, memory, figures, render);
+criterion_main!(benches);
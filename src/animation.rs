@@ -0,0 +1,629 @@
+use crate::figure::Figure;
+use crate::html::{ArrayEncoding, HtmlOptions};
+use crate::trace::Trace;
+use file_io::save_string_to_file;
+use plotly::common::{Anchor, Pad};
+use plotly::layout::update_menu::{Button, ButtonMethod, UpdateMenu, UpdateMenuDirection};
+use plotly::layout::{
+    Animation as PlotlyAnimation, AnimationMode, AnimationOptions, Frame as PlotlyFrame,
+    FrameSettings, Slider, SliderCurrentValue, SliderMethod, SliderStep, TransitionSettings,
+};
+use plotly::{Layout, Plot, Traces};
+use std::path::Path;
+
+/// Default duration of each frame of an animation (in milliseconds).
+const DEFAULT_FRAME_DURATION: usize = 500;
+
+/// Default duration of the transition between two frames of an animation (in milliseconds).
+const DEFAULT_TRANSITION_DURATION: usize = 300;
+
+/// Frame of an animation.
+///
+/// A frame is a set of traces that replace (the data and formatting of) traces of the animated
+/// figure. By default, the `i`th trace of the frame replaces the `i`th trace of the figure, but
+/// the traces to replace can also be specified explicitly (see [`Frame::trace_indices`]).
+///
+/// # Example
+///
+/// ```
+/// use plotting::{Frame, Trace};
+///
+/// // Frame replacing the second trace of the figure.
+/// let frame = Frame::new("t = 10 s", vec![Trace::new_2d([0.0, 1.0], [2.0, 3.0])])
+///     .trace_indices(vec![1]);
+/// ```
+pub struct Frame {
+    /// Label of the frame (shown on the slider of the animation).
+    pub(crate) label: String,
+
+    /// Traces of the frame.
+    pub(crate) traces: Vec<Trace>,
+
+    /// Indices of the traces of the figure replaced by the traces of the frame.
+    pub(crate) trace_indices: Option<Vec<usize>>,
+
+    /// Title of the figure while the frame is shown.
+    pub(crate) title: Option<String>,
+}
+
+impl Frame {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label of the frame (shown on the slider of the animation, must be unique within
+    ///   the animation).
+    /// * `traces` - Traces of the frame (the `i`th trace replaces the `i`th trace of the animated
+    ///   figure).
+    ///
+    /// # Returns
+    ///
+    /// Frame.
+    pub fn new(label: impl Into<String>, traces: Vec<Trace>) -> Frame {
+        Frame {
+            label: label.into(),
+            traces,
+            trace_indices: None,
+            title: None,
+        }
+    }
+
+    /// Set the indices of the traces of the animated figure replaced by the traces of the frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `trace_indices` - Indices of the traces of the animated figure (the `i`th trace of the
+    ///   frame replaces the trace at the `i`th index). Traces of the figure that are not listed are
+    ///   left unchanged.
+    ///
+    /// # Returns
+    ///
+    /// The frame with the trace indices set.
+    pub fn trace_indices(mut self, trace_indices: Vec<usize>) -> Self {
+        self.trace_indices = Some(trace_indices);
+        self
+    }
+
+    /// Set the title of the figure while the frame is shown.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Title of the figure (e.g. showing the time or iteration of the frame).
+    ///
+    /// # Returns
+    ///
+    /// The frame with the title set.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Indices of the traces of the animated figure replaced by the traces of the frame.
+    ///
+    /// # Returns
+    ///
+    /// Trace indices.
+    pub(crate) fn indices(&self) -> Vec<usize> {
+        self.trace_indices
+            .clone()
+            .unwrap_or_else(|| (0..self.traces.len()).collect())
+    }
+}
+
+/// Animated figure.
+///
+/// An animated figure is a figure (showing the initial state of the animation) together with a
+/// sequence of frames (see [`Frame`]). When exported to HTML, the animation is controlled using
+/// play/pause buttons and a slider stepping through the frames.
+///
+/// # Example
+///
+/// ```
+/// use plotting::{Animation, Figure, FormatBuilder, Frame, Trace};
+///
+/// // Fix the axis ranges, since they are not rescaled while the animation plays.
+/// let format = FormatBuilder::default()
+///     .title("Optimization")
+///     .x_range((0.0, 10.0))
+///     .y_range((-1.0, 1.0))
+///     .build()
+///     .unwrap();
+/// let x: Vec<f64> = (0..=100).map(|i| i as f64 / 10.0).collect();
+/// let curve = |k: usize| Trace::new_2d(&x[..], x.iter().map(|x| (x - k as f64).sin()));
+///
+/// // One frame per iteration.
+/// let animation = Animation::new(Figure::new(vec![curve(0)], format))
+///     .add_frames((0..10).map(|k| Frame::new(format!("{k}"), vec![curve(k)])))
+///     .frame_duration(200)
+///     .transition_duration(100)
+///     .slider_prefix("Iteration: ");
+/// animation.save_html("folder/animation.html");
+/// ```
+pub struct Animation {
+    /// Figure showing the initial state of the animation.
+    pub(crate) figure: Figure,
+
+    /// Frames.
+    pub(crate) frames: Vec<Frame>,
+
+    /// Duration of each frame (in milliseconds).
+    pub(crate) frame_duration: usize,
+
+    /// Duration of the transition between two frames (in milliseconds).
+    pub(crate) transition_duration: usize,
+
+    /// Text shown before the label of the current frame above the slider.
+    pub(crate) slider_prefix: String,
+}
+
+impl Animation {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `figure` - Figure showing the initial state of the animation.
+    ///
+    /// # Returns
+    ///
+    /// Animated figure without frames.
+    ///
+    /// # Note
+    ///
+    /// plotly.js does not rescale the axes while an animation plays, so the axis ranges should
+    /// usually be set in the format of the figure.
+    pub fn new(figure: Figure) -> Animation {
+        Animation {
+            figure,
+            frames: Vec::new(),
+            frame_duration: DEFAULT_FRAME_DURATION,
+            transition_duration: DEFAULT_TRANSITION_DURATION,
+            slider_prefix: String::new(),
+        }
+    }
+
+    /// Create an animated figure from a sequence of figures.
+    ///
+    /// Each figure becomes a frame (labeled by its index in the sequence) replacing all traces and
+    /// the title of the animated figure. Apart from the title, the formatting, annotations, and
+    /// shapes of the animated figure are those of the first figure.
+    ///
+    /// # Arguments
+    ///
+    /// * `figures` - Figures (which should all have the same number of traces).
+    ///
+    /// # Returns
+    ///
+    /// Animated figure.
+    ///
+    /// # Panics
+    ///
+    /// * If there are no figures.
+    /// * If a figure has more traces than the first figure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{Animation, quick_plot_2d};
+    ///
+    /// let animation = Animation::from_figures(
+    ///     (1..=5)
+    ///         .map(|k| quick_plot_2d([0.0, 1.0, 2.0], [0.0, k as f64, 0.0]))
+    ///         .collect(),
+    /// );
+    /// ```
+    pub fn from_figures(figures: Vec<Figure>) -> Animation {
+        let frames: Vec<Frame> = figures
+            .iter()
+            .enumerate()
+            .map(|(index, figure)| {
+                let frame = Frame::new(index.to_string(), figure.traces.clone());
+                match &figure.format.title {
+                    Some(title) => frame.title(title.as_str()),
+                    None => frame,
+                }
+            })
+            .collect();
+        let first = figures
+            .into_iter()
+            .next()
+            .expect("An animation needs at least one figure.");
+        Animation::new(first).add_frames(frames)
+    }
+
+    /// Add a frame to the animation.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - Frame.
+    ///
+    /// # Returns
+    ///
+    /// The animated figure with the frame added.
+    ///
+    /// # Panics
+    ///
+    /// * If the frame replaces traces that the figure does not have.
+    /// * If the number of trace indices of the frame differs from its number of traces.
+    pub fn add_frame(mut self, frame: Frame) -> Self {
+        let indices = frame.indices();
+        assert_eq!(
+            indices.len(),
+            frame.traces.len(),
+            "Frame '{}' has {} traces but {} trace indices.",
+            frame.label,
+            frame.traces.len(),
+            indices.len()
+        );
+        assert!(
+            indices
+                .iter()
+                .all(|index| *index < self.figure.traces.len()),
+            "Frame '{}' replaces traces that the figure does not have.",
+            frame.label
+        );
+        self.frames.push(frame);
+        self
+    }
+
+    /// Add frames to the animation.
+    ///
+    /// # Arguments
+    ///
+    /// * `frames` - Frames.
+    ///
+    /// # Returns
+    ///
+    /// The animated figure with the frames added.
+    ///
+    /// # Panics
+    ///
+    /// See [`Animation::add_frame`].
+    pub fn add_frames(self, frames: impl IntoIterator<Item = Frame>) -> Self {
+        frames.into_iter().fold(self, Animation::add_frame)
+    }
+
+    /// Set the duration of each frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame_duration` - Duration of each frame (in milliseconds, defaults to 500 ms).
+    ///
+    /// # Returns
+    ///
+    /// The animated figure with the frame duration set.
+    pub fn frame_duration(mut self, frame_duration: usize) -> Self {
+        self.frame_duration = frame_duration;
+        self
+    }
+
+    /// Set the duration of the transition between two frames.
+    ///
+    /// # Arguments
+    ///
+    /// * `transition_duration` - Duration of the transition between two frames (in milliseconds,
+    ///   defaults to 300 ms). Use `0` to jump between frames without transitions.
+    ///
+    /// # Returns
+    ///
+    /// The animated figure with the transition duration set.
+    pub fn transition_duration(mut self, transition_duration: usize) -> Self {
+        self.transition_duration = transition_duration;
+        self
+    }
+
+    /// Set the text shown before the label of the current frame above the slider.
+    ///
+    /// # Arguments
+    ///
+    /// * `slider_prefix` - Text shown before the label of the current frame (e.g. `"t = "`).
+    ///
+    /// # Returns
+    ///
+    /// The animated figure with the slider prefix set.
+    pub fn slider_prefix(mut self, slider_prefix: impl Into<String>) -> Self {
+        self.slider_prefix = slider_prefix.into();
+        self
+    }
+
+    /// Create a plotly plot (with frames, play/pause buttons, and a slider) from the animated
+    /// figure.
+    ///
+    /// # Returns
+    ///
+    /// Plotly plot.
+    ///
+    /// # Panics
+    ///
+    /// If a menu or slider of the figure references a trace that the figure does not have.
+    ///
+    /// # Note
+    ///
+    /// Only the first 7 secondary y-axes of the figure format are plotted (see
+    /// [`Figure::plotly`]).
+    pub fn plotly(&self) -> Plot {
+        self.plotly_with_encoding(ArrayEncoding::default())
+    }
+
+    /// Create a plotly plot from the animated figure, encoding the data arrays of its traces in a
+    /// custom way.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - Encoding of the numeric data arrays of the traces.
+    ///
+    /// # Returns
+    ///
+    /// Plotly plot.
+    ///
+    /// # Panics
+    ///
+    /// If a menu or slider of the figure references a trace that the figure does not have.
+    ///
+    /// # Note
+    ///
+    /// Only the first 7 secondary y-axes of the figure format are plotted (see
+    /// [`Figure::plotly`]).
+    pub(crate) fn plotly_with_encoding(&self, encoding: ArrayEncoding) -> Plot {
+        let mut plot = self.figure.plotly_with_encoding(encoding);
+
+        // Frames.
+        for frame in &self.frames {
+            let mut data = Traces::new();
+            for trace in &frame.traces {
+                data.push(trace.plotly(&self.figure.format, encoding));
+            }
+            let mut plotly_frame = PlotlyFrame::new()
+                .name(frame.label.as_str())
+                .traces(frame.indices())
+                .data(data);
+            if let Some(title) = &frame.title {
+                plotly_frame = plotly_frame.layout(Layout::new().title(title.as_str()));
+            }
+            plot.add_frame(plotly_frame);
+        }
+        if self.frames.is_empty() {
+            return plot;
+        }
+
+        // Play/pause buttons.
+        let play = PlotlyAnimation::all_frames().options(
+            self.animation_options()
+                .fromcurrent(true)
+                .mode(AnimationMode::Immediate),
+        );
+        let buttons = vec![
+            Button::new()
+                .label("Play")
+                .method(ButtonMethod::Animate)
+                .args(serde_json::to_value(play).unwrap()),
+            Button::new()
+                .label("Pause")
+                .method(ButtonMethod::Animate)
+                .args(serde_json::to_value(PlotlyAnimation::pause()).unwrap()),
+        ];
+        let menu = UpdateMenu::new()
+            .buttons(buttons)
+            .direction(UpdateMenuDirection::Left)
+            .show_active(false)
+            .x(0.1)
+            .x_anchor(Anchor::Right)
+            .y(0.0)
+            .y_anchor(Anchor::Top)
+            .pad(Pad::new(60, 0, 0));
+
+        // Slider stepping through the frames.
+        let steps = self
+            .frames
+            .iter()
+            .map(|frame| {
+                let animation = PlotlyAnimation::frames(vec![frame.label.clone()])
+                    .options(self.animation_options().mode(AnimationMode::Immediate));
+                SliderStep::new()
+                    .label(frame.label.as_str())
+                    .value(serde_json::Value::from(frame.label.as_str()))
+                    .method(SliderMethod::Animate)
+                    .args(serde_json::to_value(animation).unwrap())
+            })
+            .collect();
+        let slider = Slider::new()
+            .active(0)
+            .steps(steps)
+            .current_value(SliderCurrentValue::new().prefix(self.slider_prefix.as_str()))
+            .x(0.1)
+            .x_anchor(Anchor::Left)
+            .length(0.9)
+            .y(0.0)
+            .y_anchor(Anchor::Top)
+            .pad(Pad::new(50, 10, 0));
+
+        let layout = plot
+            .layout()
+            .clone()
+            .update_menus([self.figure.plotly_menus(), vec![menu]].concat())
+            .sliders([self.figure.plotly_sliders(), vec![slider]].concat());
+        plot.set_layout(layout);
+        plot
+    }
+
+    /// Options of the plotly animations triggered by the play button and the slider.
+    ///
+    /// # Returns
+    ///
+    /// Plotly animation options.
+    fn animation_options(&self) -> AnimationOptions {
+        AnimationOptions::new()
+            .frame(
+                FrameSettings::new()
+                    .duration(self.frame_duration)
+                    .redraw(true),
+            )
+            .transition(TransitionSettings::new().duration(self.transition_duration))
+    }
+
+    /// Show the animated figure (opens the figure in a web browser).
+    pub fn show(&self) {
+        self.plotly().show();
+    }
+
+    /// Convert the animated figure to a standalone HTML document.
+    ///
+    /// # Returns
+    ///
+    /// HTML document.
+    pub fn to_html(&self) -> String {
+        self.plotly().to_html()
+    }
+
+    /// Convert the animated figure to a standalone HTML document, using custom export options (see
+    /// [`HtmlOptions`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - HTML export options.
+    ///
+    /// # Returns
+    ///
+    /// HTML document.
+    pub fn to_html_with(&self, options: &HtmlOptions) -> String {
+        options.to_html(&self.plotly_with_encoding(options.encoding()))
+    }
+
+    /// Save the animated figure to a standalone HTML file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the HTML file.
+    ///
+    /// # Panics
+    ///
+    /// If some error is encountered while creating the file or writing to it.
+    pub fn save_html<P: AsRef<Path>>(&self, path: P) {
+        save_string_to_file(&self.to_html(), path.as_ref());
+    }
+
+    /// Save the animated figure to a standalone HTML file, using custom export options (see
+    /// [`HtmlOptions`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the HTML file.
+    /// * `options` - HTML export options.
+    ///
+    /// # Panics
+    ///
+    /// If some error is encountered while creating the file or writing to it.
+    pub fn save_html_with<P: AsRef<Path>>(&self, path: P, options: &HtmlOptions) {
+        save_string_to_file(&self.to_html_with(options), path.as_ref());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::Format;
+    use serde_json::Value;
+
+    /// Animated figure with two traces and two frames.
+    ///
+    /// # Returns
+    ///
+    /// Animated figure.
+    fn animation() -> Animation {
+        let figure = Figure::new(
+            vec![
+                Trace::new_2d([0.0, 1.0], [0.0, 1.0]),
+                Trace::new_2d([0.0, 1.0], [1.0, 0.0]),
+            ],
+            Format::default(),
+        );
+        Animation::new(figure)
+            .add_frame(Frame::new(
+                "a",
+                vec![
+                    Trace::new_2d([0.0, 1.0], [0.0, 2.0]),
+                    Trace::new_2d([0.0, 1.0], [2.0, 0.0]),
+                ],
+            ))
+            .add_frame(
+                Frame::new("b", vec![Trace::new_2d([0.0, 1.0], [3.0, 3.0])]).trace_indices(vec![1]),
+            )
+            .frame_duration(100)
+            .transition_duration(50)
+            .slider_prefix("k = ")
+    }
+
+    #[test]
+    fn test_frames() {
+        let json: Value = serde_json::from_str(&animation().plotly().to_json()).unwrap();
+        let frames = json["frames"].as_array().unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0]["name"], "a");
+        assert_eq!(frames[0]["traces"], serde_json::json!([0, 1]));
+        assert_eq!(frames[0]["data"][1]["y"], serde_json::json!([2.0, 0.0]));
+        assert_eq!(frames[1]["name"], "b");
+        assert_eq!(frames[1]["traces"], serde_json::json!([1]));
+        assert_eq!(frames[1]["data"][0]["y"], serde_json::json!([3.0, 3.0]));
+    }
+
+    #[test]
+    fn test_controls() {
+        let json: Value = serde_json::from_str(&animation().plotly().to_json()).unwrap();
+        let layout = &json["layout"];
+
+        // Play/pause buttons.
+        let buttons = &layout["updatemenus"][0]["buttons"];
+        assert_eq!(buttons[0]["label"], "Play");
+        assert_eq!(buttons[0]["method"], "animate");
+        assert_eq!(buttons[0]["args"][0], Value::Null);
+        assert_eq!(buttons[0]["args"][1]["frame"]["duration"], 100);
+        assert_eq!(buttons[0]["args"][1]["transition"]["duration"], 50);
+        assert_eq!(buttons[1]["label"], "Pause");
+        assert_eq!(buttons[1]["args"][0], serde_json::json!([null]));
+
+        // Slider.
+        let slider = &layout["sliders"][0];
+        assert_eq!(slider["currentvalue"]["prefix"], "k = ");
+        let steps = slider["steps"].as_array().unwrap();
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[1]["label"], "b");
+        assert_eq!(steps[1]["args"][0], serde_json::json!(["b"]));
+    }
+
+    #[test]
+    fn test_from_figures() {
+        let format = |title: &str| {
+            crate::format::FormatBuilder::default()
+                .title(title)
+                .build()
+                .unwrap()
+        };
+        let animation = Animation::from_figures(vec![
+            Figure::new(vec![Trace::new_2d([0.0], [1.0])], format("a")),
+            Figure::new(vec![Trace::new_2d([0.0], [2.0])], format("b")),
+        ]);
+        let json: Value = serde_json::from_str(&animation.plotly().to_json()).unwrap();
+        assert_eq!(json["frames"][1]["name"], "1");
+        assert_eq!(json["frames"][1]["layout"]["title"]["text"], "b");
+    }
+
+    #[test]
+    fn test_without_frames() {
+        let animation = Animation::new(Figure::new(
+            vec![Trace::new_2d([0.0, 1.0], [0.0, 1.0])],
+            Format::default(),
+        ));
+        let json: Value = serde_json::from_str(&animation.plotly().to_json()).unwrap();
+        assert!(json["layout"].get("sliders").is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_trace_index() {
+        let figure = Figure::new(
+            vec![Trace::new_2d([0.0, 1.0], [0.0, 1.0])],
+            Format::default(),
+        );
+        let _ = Animation::new(figure)
+            .add_frame(Frame::new("a", vec![Trace::new_2d([0.0], [0.0])]).trace_indices(vec![1]));
+    }
+}
@@ -0,0 +1,391 @@
+use crate::colors::Color;
+use crate::coordinates::Coordinates;
+use plotly::common::Font;
+use plotly::layout::{Annotation as PlotlyAnnotation, HAlign};
+
+/// Horizontal text alignment.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Alignment {
+    /// Left-aligned text.
+    Left,
+
+    /// Centered text.
+    Center,
+
+    /// Right-aligned text.
+    Right,
+}
+
+impl From<Alignment> for HAlign {
+    fn from(alignment: Alignment) -> Self {
+        match alignment {
+            Alignment::Left => HAlign::Left,
+            Alignment::Center => HAlign::Center,
+            Alignment::Right => HAlign::Right,
+        }
+    }
+}
+
+/// Annotation.
+///
+/// An annotation is a text label placed on a figure, optionally with an arrow pointing from the
+/// text to the annotated point.
+///
+/// # Example
+///
+/// ```
+/// use plotting::{Annotation, Color, Coordinates, NamedColor, quick_plot_2d};
+///
+/// // Mark the peak value with an arrow pointing to it.
+/// let peak = Annotation::new("Peak", 2.0, 4.0)
+///     .arrow(0.0, -40.0)
+///     .font_color(Color::named(NamedColor::Red))
+///     .background_color(Color::named(NamedColor::White));
+///
+/// // Add a note in the top-left corner of the plotting area.
+/// let note = Annotation::new("Simulated data", 0.0, 1.0).coordinates(Coordinates::Paper);
+///
+/// let fig = quick_plot_2d([1.0, 2.0, 3.0], [1.0, 4.0, 2.0])
+///     .add_annotation(peak)
+///     .add_annotation(note);
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Annotation {
+    /// Text.
+    pub(crate) text: String,
+
+    /// x-coordinate of the annotated point.
+    pub(crate) x: f64,
+
+    /// y-coordinate of the annotated point.
+    pub(crate) y: f64,
+
+    /// Coordinate system of the annotated point.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) coordinates: Coordinates,
+
+    /// Offset of the text from the annotated point (in pixels) if an arrow is drawn.
+    pub(crate) arrow: Option<(f64, f64)>,
+
+    /// Arrow color.
+    pub(crate) arrow_color: Option<Color>,
+
+    /// Font family.
+    pub(crate) font_family: Option<String>,
+
+    /// Font size.
+    pub(crate) font_size: Option<usize>,
+
+    /// Font color.
+    pub(crate) font_color: Option<Color>,
+
+    /// Background color of the text box.
+    pub(crate) background_color: Option<Color>,
+
+    /// Border color of the text box.
+    pub(crate) border_color: Option<Color>,
+
+    /// Horizontal alignment of the text.
+    pub(crate) alignment: Option<Alignment>,
+}
+
+impl Annotation {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text.
+    /// * `x` - x-coordinate of the annotated point (in data coordinates by default).
+    /// * `y` - y-coordinate of the annotated point (in data coordinates by default).
+    ///
+    /// # Returns
+    ///
+    /// Annotation (without an arrow) whose text is centered on the annotated point.
+    pub fn new(text: impl Into<String>, x: f64, y: f64) -> Annotation {
+        Annotation {
+            text: text.into(),
+            x,
+            y,
+            coordinates: Coordinates::Data,
+            arrow: None,
+            arrow_color: None,
+            font_family: None,
+            font_size: None,
+            font_color: None,
+            background_color: None,
+            border_color: None,
+            alignment: None,
+        }
+    }
+
+    /// Set the coordinate system of the annotated point.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinates` - Coordinate system.
+    ///
+    /// # Returns
+    ///
+    /// The annotation with the updated coordinate system.
+    pub fn coordinates(mut self, coordinates: Coordinates) -> Self {
+        self.coordinates = coordinates;
+        self
+    }
+
+    /// Draw an arrow from the text to the annotated point.
+    ///
+    /// # Arguments
+    ///
+    /// * `dx` - Horizontal offset of the text from the annotated point (in pixels, positive to
+    ///   the right).
+    /// * `dy` - Vertical offset of the text from the annotated point (in pixels, positive
+    ///   downwards).
+    ///
+    /// # Returns
+    ///
+    /// The annotation with an arrow.
+    pub fn arrow(mut self, dx: f64, dy: f64) -> Self {
+        self.arrow = Some((dx, dy));
+        self
+    }
+
+    /// Set the arrow color.
+    ///
+    /// # Arguments
+    ///
+    /// * `arrow_color` - Arrow color.
+    ///
+    /// # Returns
+    ///
+    /// The annotation with the updated arrow color.
+    pub fn arrow_color(mut self, arrow_color: Color) -> Self {
+        self.arrow_color = Some(arrow_color);
+        self
+    }
+
+    /// Set the font family.
+    ///
+    /// # Arguments
+    ///
+    /// * `font_family` - Font family (e.g. `"Arial"`).
+    ///
+    /// # Returns
+    ///
+    /// The annotation with the updated font family.
+    pub fn font_family(mut self, font_family: impl Into<String>) -> Self {
+        self.font_family = Some(font_family.into());
+        self
+    }
+
+    /// Set the font size.
+    ///
+    /// # Arguments
+    ///
+    /// * `font_size` - Font size.
+    ///
+    /// # Returns
+    ///
+    /// The annotation with the updated font size.
+    pub fn font_size(mut self, font_size: usize) -> Self {
+        self.font_size = Some(font_size);
+        self
+    }
+
+    /// Set the font color.
+    ///
+    /// # Arguments
+    ///
+    /// * `font_color` - Font color.
+    ///
+    /// # Returns
+    ///
+    /// The annotation with the updated font color.
+    pub fn font_color(mut self, font_color: Color) -> Self {
+        self.font_color = Some(font_color);
+        self
+    }
+
+    /// Set the background color of the text box.
+    ///
+    /// # Arguments
+    ///
+    /// * `background_color` - Background color.
+    ///
+    /// # Returns
+    ///
+    /// The annotation with the updated background color.
+    pub fn background_color(mut self, background_color: Color) -> Self {
+        self.background_color = Some(background_color);
+        self
+    }
+
+    /// Set the border color of the text box.
+    ///
+    /// # Arguments
+    ///
+    /// * `border_color` - Border color.
+    ///
+    /// # Returns
+    ///
+    /// The annotation with the updated border color.
+    pub fn border_color(mut self, border_color: Color) -> Self {
+        self.border_color = Some(border_color);
+        self
+    }
+
+    /// Set the horizontal alignment of the text.
+    ///
+    /// # Arguments
+    ///
+    /// * `alignment` - Alignment.
+    ///
+    /// # Returns
+    ///
+    /// The annotation with the updated alignment.
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Create a plotly annotation from this annotation.
+    ///
+    /// # Returns
+    ///
+    /// Plotly annotation.
+    pub(crate) fn plotly(&self) -> PlotlyAnnotation {
+        let mut annotation = PlotlyAnnotation::new()
+            .text(&self.text)
+            .x(self.x)
+            .y(self.y)
+            .x_ref(self.coordinates.to_plotly_ref("x"))
+            .y_ref(self.coordinates.to_plotly_ref("y"));
+
+        // Arrow settings.
+        if let Some((dx, dy)) = self.arrow {
+            annotation = annotation.show_arrow(true).ax(dx).ay(dy);
+        } else {
+            annotation = annotation.show_arrow(false);
+        }
+        if let Some(arrow_color) = &self.arrow_color {
+            annotation = annotation.arrow_color(arrow_color.to_plotly_rgba());
+        }
+
+        // Font settings.
+        if self.font_family.is_some() || self.font_size.is_some() || self.font_color.is_some() {
+            let mut font = Font::new();
+            if let Some(font_family) = &self.font_family {
+                font = font.family(font_family);
+            }
+            if let Some(font_size) = self.font_size {
+                font = font.size(font_size);
+            }
+            if let Some(font_color) = &self.font_color {
+                font = font.color(font_color.to_plotly_rgba());
+            }
+            annotation = annotation.font(font);
+        }
+
+        // Text box settings.
+        if let Some(background_color) = &self.background_color {
+            annotation = annotation.background_color(background_color.to_plotly_rgba());
+        }
+        if let Some(border_color) = &self.border_color {
+            annotation = annotation.border_color(border_color.to_plotly_rgba());
+        }
+        if let Some(alignment) = self.alignment {
+            annotation = annotation.align(alignment.into());
+        }
+
+        annotation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colors::NamedColor;
+    use crate::quick::quick_plot_2d;
+    use serde_json::json;
+
+    #[test]
+    fn test_new() {
+        let annotation = Annotation::new("Peak", 2.0, 4.0);
+        assert_eq!(annotation.text, "Peak");
+        assert_eq!(annotation.x, 2.0);
+        assert_eq!(annotation.y, 4.0);
+        assert_eq!(annotation.coordinates, Coordinates::Data);
+        assert!(annotation.arrow.is_none());
+        assert!(annotation.font_color.is_none());
+        assert!(annotation.alignment.is_none());
+    }
+
+    #[test]
+    fn test_setters() {
+        let annotation = Annotation::new("Peak", 0.5, 0.5)
+            .coordinates(Coordinates::Paper)
+            .arrow(10.0, -20.0)
+            .font_size(14)
+            .font_color(Color::named(NamedColor::Red))
+            .alignment(Alignment::Left);
+        assert_eq!(annotation.coordinates, Coordinates::Paper);
+        assert_eq!(annotation.arrow, Some((10.0, -20.0)));
+        assert_eq!(annotation.font_size, Some(14));
+        assert_eq!(annotation.font_color, Some(Color::named(NamedColor::Red)));
+        assert_eq!(annotation.alignment, Some(Alignment::Left));
+    }
+
+    #[test]
+    fn test_coordinates_to_plotly_ref() {
+        assert_eq!(Coordinates::Data.to_plotly_ref("x"), "x");
+        assert_eq!(Coordinates::Paper.to_plotly_ref("y"), "paper");
+    }
+
+    #[test]
+    fn test_plotly() {
+        // Data coordinates, without an arrow.
+        let json = serde_json::to_value(Annotation::new("Peak", 2.0, 4.0).plotly()).unwrap();
+        assert_eq!(json["text"], "Peak");
+        assert_eq!((&json["x"], &json["y"]), (&json!(2.0), &json!(4.0)));
+        assert_eq!((&json["xref"], &json["yref"]), (&json!("x"), &json!("y")));
+        assert_eq!(json["showarrow"], false);
+        assert!(json.get("font").is_none());
+
+        // Paper coordinates, with an arrow and text settings.
+        let annotation = Annotation::new("Note", 0.25, 0.75)
+            .coordinates(Coordinates::Paper)
+            .arrow(10.0, -20.0)
+            .font_size(14)
+            .alignment(Alignment::Left);
+        let json = serde_json::to_value(annotation.plotly()).unwrap();
+        assert_eq!((&json["x"], &json["y"]), (&json!(0.25), &json!(0.75)));
+        assert_eq!(
+            (&json["xref"], &json["yref"]),
+            (&json!("paper"), &json!("paper"))
+        );
+        assert_eq!(json["showarrow"], true);
+        assert_eq!((&json["ax"], &json["ay"]), (&json!(10.0), &json!(-20.0)));
+        assert_eq!(json["font"]["size"], 14);
+        assert_eq!(json["align"], "left");
+    }
+
+    #[test]
+    fn test_figure_plotly() {
+        let fig = quick_plot_2d([0.0, 1.0], [0.0, 1.0])
+            .add_annotation(Annotation::new("Data", 1.0, 1.0))
+            .add_annotation(Annotation::new("Paper", 0.5, 1.0).coordinates(Coordinates::Paper));
+        let json: serde_json::Value = serde_json::from_str(&fig.to_plotly_json()).unwrap();
+        let annotations = &json["layout"]["annotations"];
+        assert_eq!(annotations.as_array().unwrap().len(), 2);
+        assert_eq!(annotations[0]["text"], "Data");
+        assert_eq!(annotations[0]["xref"], "x");
+        assert_eq!(annotations[0]["yref"], "y");
+        assert_eq!(annotations[1]["text"], "Paper");
+        assert_eq!(annotations[1]["xref"], "paper");
+        assert_eq!(annotations[1]["yref"], "paper");
+        assert_eq!(annotations[1]["x"], 0.5);
+    }
+}
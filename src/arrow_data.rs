@@ -0,0 +1,123 @@
+use crate::axis_data::AxisData;
+use crate::columns::{Column, ColumnSource, Columns};
+use crate::error::Error;
+use crate::trace::Trace;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Float64Type;
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::DataType;
+
+impl ColumnSource for RecordBatch {
+    fn axis_data(&self, column: &Column) -> Result<AxisData, Error> {
+        let array: &ArrayRef = match column {
+            Column::Name(name) => self.column_by_name(name),
+            Column::Index(index) => self.columns().get(*index),
+        }
+        .ok_or_else(|| Error::MissingColumn(column.clone()))?;
+
+        // Numeric columns produce numeric data (with missing values plotted as gaps).
+        if array.data_type().is_numeric() {
+            let data = arrow_cast::cast(array, &DataType::Float64)?;
+            return Ok(AxisData::Numeric(
+                data.as_primitive::<Float64Type>()
+                    .iter()
+                    .map(|value| value.unwrap_or(f64::NAN))
+                    .collect(),
+            ));
+        }
+
+        // All other columns (e.g. strings, booleans, or dates) produce categorical data.
+        let data = arrow_cast::cast(array, &DataType::Utf8)?;
+        Ok(AxisData::Categorical(
+            data.as_string::<i32>()
+                .iter()
+                .map(|value| String::from(value.unwrap_or_default()))
+                .collect(),
+        ))
+    }
+}
+
+impl Trace {
+    /// Constructor for a set of traces from the columns of an Arrow [`RecordBatch`].
+    ///
+    /// # Arguments
+    ///
+    /// * `record_batch` - Record batch.
+    /// * `columns` - Columns to build the traces from (see [`Columns`]).
+    ///
+    /// # Returns
+    ///
+    /// Traces (one per group if a color-by and/or group-by column is specified, otherwise a single
+    /// trace).
+    ///
+    /// # Errors
+    ///
+    /// If one of the columns does not exist or cannot be converted to axis data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use arrow_array::{ArrayRef, Float64Array, RecordBatch, StringArray};
+    /// use plotting::{ColumnsBuilder, Trace};
+    /// use std::sync::Arc;
+    ///
+    /// let record_batch = RecordBatch::try_from_iter([
+    ///     ("time", Arc::new(Float64Array::from(vec![0.0, 0.0, 1.0, 1.0])) as ArrayRef),
+    ///     ("speed", Arc::new(Float64Array::from(vec![10.0, 12.0, 11.0, 15.0])) as ArrayRef),
+    ///     ("vehicle", Arc::new(StringArray::from(vec!["car", "truck", "car", "truck"])) as ArrayRef),
+    /// ])
+    /// .unwrap();
+    /// let columns = ColumnsBuilder::default()
+    ///     .x("time")
+    ///     .y("speed")
+    ///     .color_by("vehicle")
+    ///     .build()
+    ///     .unwrap();
+    /// let traces = Trace::from_arrow(&record_batch, &columns).unwrap();
+    /// ```
+    pub fn from_arrow(record_batch: &RecordBatch, columns: &Columns) -> Result<Vec<Trace>, Error> {
+        columns.traces(record_batch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::columns::ColumnsBuilder;
+    use arrow_array::{BooleanArray, Int32Array};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_from_arrow() {
+        let record_batch = RecordBatch::try_from_iter([
+            (
+                "t",
+                Arc::new(Int32Array::from(vec![Some(0), None, Some(2)])) as ArrayRef,
+            ),
+            (
+                "ok",
+                Arc::new(BooleanArray::from(vec![true, false, true])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let columns = ColumnsBuilder::default().x(0).y("ok").build().unwrap();
+        let traces = Trace::from_arrow(&record_batch, &columns).unwrap();
+        assert_eq!(traces.len(), 1);
+        let x = traces[0].x.as_numeric().unwrap();
+        assert_eq!(x[0], 0.0);
+        assert!(x[1].is_nan());
+        assert_eq!(traces[0].y, ["true", "false", "true"]);
+    }
+
+    #[test]
+    fn test_missing_column() {
+        let record_batch =
+            RecordBatch::try_from_iter([("t", Arc::new(Int32Array::from(vec![0, 1])) as ArrayRef)])
+                .unwrap();
+        let columns = ColumnsBuilder::default().x("t").y(3).build().unwrap();
+        assert!(matches!(
+            Trace::from_arrow(&record_batch, &columns),
+            Err(Error::MissingColumn(Column::Index(3)))
+        ));
+    }
+}
@@ -0,0 +1,748 @@
+use crate::axis_data::AxisData;
+use crate::axis_type::AxisType;
+use crate::colors::Color;
+use crate::figure::Figure;
+use crate::fill::Fill;
+use crate::mode::Mode;
+use crate::plot_type::PlotType;
+use crate::scale::{Categories, Scale};
+use crate::theme::ThemeStyle;
+use crate::trace::Trace;
+use std::collections::HashMap;
+
+/// Fraction of the spacing between neighboring x-axis values taken up by a group of bars (as in
+/// plotly.js, where the default gap between groups of bars is 20%).
+const BAR_GROUP_WIDTH: f64 = 0.8;
+
+/// Fraction of the spacing between neighboring x-axis values taken up by a box.
+const BOX_WIDTH: f64 = 0.5;
+
+/// Scatter plots with fewer data points than this are drawn with lines and markers by default
+/// (as in plotly.js).
+const MARKERS_THRESHOLD: usize = 20;
+
+/// Summary statistics of a box in a box plot.
+pub(crate) struct BoxStats {
+    /// Position of the box along the x-axis.
+    pub(crate) x: f64,
+
+    /// Lower end of the lower whisker.
+    pub(crate) lower_whisker: f64,
+
+    /// First quartile.
+    pub(crate) q1: f64,
+
+    /// Median.
+    pub(crate) median: f64,
+
+    /// Third quartile.
+    pub(crate) q3: f64,
+
+    /// Upper end of the upper whisker.
+    pub(crate) upper_whisker: f64,
+
+    /// Outliers (values further than 1.5 times the interquartile range from the box).
+    pub(crate) outliers: Vec<f64>,
+}
+
+/// Trace resolved against the axes of a figure (i.e. with its data converted to positions along
+/// the axes and its default styling filled in).
+pub(crate) struct PlotTrace<'a> {
+    /// Trace.
+    pub(crate) trace: &'a Trace,
+
+    /// Index of the trace in the figure.
+    pub(crate) index: usize,
+
+    /// Positions of the data points along the x-axis.
+    pub(crate) x: Vec<f64>,
+
+    /// Positions of the data points along the y-axis (after stacking).
+    pub(crate) y: Vec<f64>,
+
+    /// Positions of the data points along the z-axis (only used for 3D traces).
+    pub(crate) z: Option<Vec<f64>>,
+
+    /// Index of the y-axis that the trace is plotted against (`0` for the primary y-axis).
+    pub(crate) y_axis: usize,
+
+    /// Default color of the trace (from the colorway of the theme).
+    pub(crate) color: Color,
+
+    /// Drawing mode (only used for scatter plots).
+    pub(crate) mode: Mode,
+
+    /// Closed polygon (in data coordinates) enclosing the filled area of the trace.
+    pub(crate) fill_polygon: Option<Vec<(f64, f64)>>,
+
+    /// Offset of the bars from the x-axis values and width of the bars (in x-axis units, only
+    /// used for bar charts).
+    pub(crate) bar: (f64, f64),
+
+    /// Boxes (only used for box plots).
+    pub(crate) boxes: Vec<BoxStats>,
+
+    /// Width of the boxes (in x-axis units, only used for box plots).
+    pub(crate) box_width: f64,
+}
+
+impl PlotTrace<'_> {
+    /// Name of the trace, as shown in the legend.
+    ///
+    /// # Returns
+    ///
+    /// Name of the trace (`"trace <index>"` if the trace is not named, as in plotly.js).
+    pub(crate) fn name(&self) -> String {
+        self.trace
+            .name
+            .clone()
+            .unwrap_or_else(|| format!("trace {}", self.index))
+    }
+
+    /// Line color.
+    ///
+    /// # Returns
+    ///
+    /// Line color of the trace.
+    pub(crate) fn line_color(&self) -> Color {
+        self.trace
+            .line_color
+            .clone()
+            .unwrap_or_else(|| self.color.clone())
+    }
+
+    /// Marker color.
+    ///
+    /// # Returns
+    ///
+    /// Marker color of the trace (defaults to the line color if lines are drawn, as in
+    /// plotly.js).
+    pub(crate) fn marker_color(&self) -> Color {
+        match &self.trace.marker_color {
+            Some(marker_color) => marker_color.clone(),
+            None if self.mode != Mode::Markers => self.line_color(),
+            None => self.color.clone(),
+        }
+    }
+
+    /// Fill color.
+    ///
+    /// # Returns
+    ///
+    /// Fill color of the trace (defaults to the trace color at half opacity, as in plotly.js).
+    pub(crate) fn fill_color(&self) -> Color {
+        self.trace.fill_color.clone().unwrap_or_else(|| {
+            let color = self.line_color();
+            let opacity = color.opacity();
+            color.alpha(opacity * 0.5)
+        })
+    }
+
+    /// Whether lines are drawn.
+    ///
+    /// # Returns
+    ///
+    /// `true` if lines connecting the data points are drawn, `false` otherwise.
+    pub(crate) fn has_lines(&self) -> bool {
+        self.trace.plot_type == PlotType::Scatter && self.mode != Mode::Markers
+    }
+
+    /// Whether markers are drawn.
+    ///
+    /// # Returns
+    ///
+    /// `true` if markers are drawn at the data points, `false` otherwise.
+    pub(crate) fn has_markers(&self) -> bool {
+        self.trace.plot_type == PlotType::Scatter && self.mode != Mode::Lines
+    }
+}
+
+/// Axes of a figure, used by the native (non-plotly.js) renderers.
+pub(crate) struct Axes<'a> {
+    /// Figure.
+    pub(crate) figure: &'a Figure,
+
+    /// Colors and fonts of the theme.
+    pub(crate) style: ThemeStyle,
+
+    /// x-axis scale.
+    pub(crate) x: Scale,
+
+    /// y-axis scales (primary y-axis followed by the secondary y-axes).
+    pub(crate) y: Vec<Scale>,
+
+    /// z-axis scale (only used for 3D figures).
+    pub(crate) z: Option<Scale>,
+
+    /// Resolved traces.
+    pub(crate) traces: Vec<PlotTrace<'a>>,
+}
+
+impl<'a> Axes<'a> {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `figure` - Figure.
+    ///
+    /// # Returns
+    ///
+    /// Axes of the figure.
+    pub(crate) fn new(figure: &'a Figure) -> Axes<'a> {
+        let format = &figure.format;
+        let style = format.theme.unwrap_or_default().style();
+        let is_3d = figure.traces.iter().any(|trace| trace.z.is_some());
+        let y_axis_count = format.plotted_secondary_y_axes().len() + 1;
+        let y_axis_of = |trace: &Trace| format.y_axis(trace) - 1;
+
+        // Axis types.
+        let x_type = format.x_axis_type.unwrap_or_else(|| {
+            if figure.traces.iter().any(|trace| trace.time_series) {
+                AxisType::Date
+            } else {
+                auto_axis_type(figure.traces.iter().map(|trace| &trace.x))
+            }
+        });
+        let y_types: Vec<AxisType> = (0..y_axis_count)
+            .map(|y_axis| {
+                let data = figure
+                    .traces
+                    .iter()
+                    .filter(|trace| y_axis_of(trace) == y_axis)
+                    .map(|trace| &trace.y);
+                match format.y_axis_type {
+                    Some(y_type) if y_axis == 0 => y_type,
+                    _ => auto_axis_type(data),
+                }
+            })
+            .collect();
+
+        // Categories.
+        let x_categories = if x_type == AxisType::Category && !is_3d {
+            Categories::collect(
+                figure.traces.iter().map(|trace| (&trace.x, &trace.y)),
+                format.x_category_order.as_ref(),
+            )
+        } else {
+            Categories::default()
+        };
+        let y_categories: Vec<Categories> = y_types
+            .iter()
+            .enumerate()
+            .map(|(y_axis, y_type)| {
+                if *y_type == AxisType::Category && !is_3d {
+                    Categories::collect(
+                        figure
+                            .traces
+                            .iter()
+                            .filter(|trace| y_axis_of(trace) == y_axis)
+                            .map(|trace| (&trace.y, &trace.x)),
+                        format.y_category_order.as_ref().filter(|_| y_axis == 0),
+                    )
+                } else {
+                    Categories::default()
+                }
+            })
+            .collect();
+
+        // Resolve the positions of the data points.
+        let mut stacks: HashMap<(usize, &str), Vec<f64>> = HashMap::new();
+        let mut traces: Vec<PlotTrace> = Vec::new();
+        for (index, trace) in figure.traces.iter().enumerate() {
+            // Hidden traces are skipped (but still count towards the colors of later traces).
+            if trace.visible == Some(false) {
+                continue;
+            }
+            let y_axis = if is_3d { 0 } else { y_axis_of(trace) };
+            let (x_data, y_data, z_data) = trace.data(figure.format.downsampling);
+            let x = positions(&x_data, x_type, &x_categories);
+            let mut y = positions(&y_data, y_types[y_axis], &y_categories[y_axis]);
+            let z = z_data.map(|z| positions(&z, AxisType::Linear, &Categories::default()));
+            let colorway = style.colorway;
+            let color = Color::hex_literal(colorway[index % colorway.len()]);
+            let mode = trace.mode.unwrap_or({
+                if trace.stack_group.is_some() || x.len() >= MARKERS_THRESHOLD {
+                    Mode::Lines
+                } else {
+                    Mode::LinesMarkers
+                }
+            });
+
+            // Stack the trace on top of the previous traces in its stack group.
+            let mut fill = trace.fill;
+            let mut base = None;
+            if let Some(stack_group) = trace.stack_group.as_deref()
+                && trace.plot_type == PlotType::Scatter
+                && !is_3d
+            {
+                let cumulative = stacks.entry((y_axis, stack_group)).or_default();
+                let first = cumulative.is_empty();
+                cumulative.resize(y.len().max(cumulative.len()), 0.0);
+                for (total, value) in cumulative.iter_mut().zip(y.iter_mut()) {
+                    let value_or_zero = if value.is_nan() { 0.0 } else { *value };
+                    *total += value_or_zero;
+                    *value = *total;
+                }
+                let below: Vec<f64> = y
+                    .iter()
+                    .zip(y_data.as_numeric().unwrap_or_default())
+                    .map(|(total, value)| total - if value.is_nan() { 0.0 } else { *value })
+                    .collect();
+                base = Some(below);
+                fill = fill.or(Some(if first { Fill::ToZeroY } else { Fill::ToNextY }));
+            }
+
+            // Filled area.
+            let points: Vec<(f64, f64)> = x
+                .iter()
+                .zip(&y)
+                .map(|(x, y)| (*x, *y))
+                .filter(|(x, y)| !x.is_nan() && !y.is_nan())
+                .collect();
+            let previous = traces.iter().rev().find(|previous: &&PlotTrace| {
+                previous.y_axis == y_axis && previous.trace.plot_type == PlotType::Scatter
+            });
+            let fill_polygon = match fill {
+                _ if is_3d || trace.plot_type != PlotType::Scatter || points.is_empty() => None,
+                Some(Fill::None) | None => None,
+                Some(Fill::ToZeroY | Fill::ToNextY | Fill::ToNext) if base.is_some() => {
+                    let below = base.as_deref().unwrap_or_default();
+                    let mut polygon = points.clone();
+                    polygon.extend(
+                        x.iter()
+                            .zip(below)
+                            .rev()
+                            .map(|(x, y)| (*x, *y))
+                            .filter(|(x, y)| !x.is_nan() && !y.is_nan()),
+                    );
+                    Some(polygon)
+                }
+                Some(Fill::ToZeroY) => {
+                    let mut polygon = points.clone();
+                    polygon.push((points[points.len() - 1].0, 0.0));
+                    polygon.push((points[0].0, 0.0));
+                    Some(polygon)
+                }
+                Some(Fill::ToZeroX) => {
+                    let mut polygon = points.clone();
+                    polygon.push((0.0, points[points.len() - 1].1));
+                    polygon.push((0.0, points[0].1));
+                    Some(polygon)
+                }
+                Some(Fill::ToSelf) => Some(points.clone()),
+                Some(Fill::ToNextY | Fill::ToNextX | Fill::ToNext) => {
+                    let mut polygon = points.clone();
+                    match previous {
+                        Some(previous) => polygon.extend(
+                            previous
+                                .x
+                                .iter()
+                                .zip(&previous.y)
+                                .rev()
+                                .map(|(x, y)| (*x, *y))
+                                .filter(|(x, y)| !x.is_nan() && !y.is_nan()),
+                        ),
+                        // The first trace is filled to zero (as in plotly.js).
+                        None if fill == Some(Fill::ToNextX) => {
+                            polygon.push((0.0, points[points.len() - 1].1));
+                            polygon.push((0.0, points[0].1));
+                        }
+                        None => {
+                            polygon.push((points[points.len() - 1].0, 0.0));
+                            polygon.push((points[0].0, 0.0));
+                        }
+                    }
+                    Some(polygon)
+                }
+            };
+
+            // Box plots.
+            let boxes = if trace.plot_type == PlotType::Box && !is_3d {
+                box_stats(&x, &y)
+            } else {
+                Vec::new()
+            };
+
+            traces.push(PlotTrace {
+                trace,
+                index,
+                x,
+                y,
+                z,
+                y_axis,
+                color,
+                mode,
+                fill_polygon,
+                bar: (0.0, 0.0),
+                boxes,
+                box_width: 0.0,
+            });
+        }
+
+        // Spacing between neighboring x-axis values (used to size bars and boxes).
+        let spacing = min_spacing(
+            traces
+                .iter()
+                .filter(|trace| trace.trace.plot_type != PlotType::Scatter)
+                .flat_map(|trace| trace.x.iter().copied()),
+        );
+
+        // Group the bars of all bar charts plotted against the same y-axis.
+        for y_axis in 0..y_axis_count {
+            let bar_traces: Vec<usize> = traces
+                .iter()
+                .enumerate()
+                .filter(|(_, trace)| {
+                    trace.y_axis == y_axis && trace.trace.plot_type == PlotType::Bar && !is_3d
+                })
+                .map(|(index, _)| index)
+                .collect();
+            let width = spacing * BAR_GROUP_WIDTH / bar_traces.len().max(1) as f64;
+            for (position, index) in bar_traces.iter().enumerate() {
+                let offset = (position as f64 - (bar_traces.len() - 1) as f64 / 2.0) * width;
+                traces[*index].bar = (offset, width);
+            }
+        }
+        for trace in traces.iter_mut() {
+            trace.box_width = spacing * BOX_WIDTH;
+        }
+
+        // Scales.
+        let has_markers = traces
+            .iter()
+            .any(|trace| trace.has_markers() || trace.trace.plot_type != PlotType::Scatter);
+        let padding = if has_markers { 0.05 } else { 0.0 };
+        let (x, y, z) = if is_3d {
+            let scale = |values: Vec<f64>, range: Option<(f64, f64)>| {
+                Scale::new(
+                    AxisType::Linear,
+                    Categories::default(),
+                    values.into_iter(),
+                    range,
+                    0.0,
+                )
+            };
+            (
+                scale(
+                    traces.iter().flat_map(|trace| trace.x.clone()).collect(),
+                    format.x_range,
+                ),
+                vec![scale(
+                    traces.iter().flat_map(|trace| trace.y.clone()).collect(),
+                    format.y_range,
+                )],
+                Some(scale(
+                    traces
+                        .iter()
+                        .flat_map(|trace| trace.z.clone().unwrap_or_default())
+                        .collect(),
+                    format.z_range,
+                )),
+            )
+        } else {
+            let x_values: Vec<f64> = traces
+                .iter()
+                .flat_map(|trace| {
+                    let half_width = match trace.trace.plot_type {
+                        PlotType::Scatter => 0.0,
+                        PlotType::Bar => trace.bar.1 / 2.0,
+                        PlotType::Box => trace.box_width / 2.0,
+                    };
+                    let offset = trace.bar.0;
+                    trace
+                        .x
+                        .iter()
+                        .flat_map(move |x| [x + offset - half_width, x + offset + half_width])
+                })
+                .collect();
+            let x = Scale::new(
+                x_type,
+                x_categories,
+                x_values.into_iter(),
+                format.x_range,
+                padding,
+            );
+            let y: Vec<Scale> = y_types
+                .into_iter()
+                .zip(y_categories)
+                .enumerate()
+                .map(|(y_axis, (y_type, categories))| {
+                    let mut values: Vec<f64> = Vec::new();
+                    for trace in traces.iter().filter(|trace| trace.y_axis == y_axis) {
+                        values.extend(&trace.y);
+                        if trace.trace.plot_type == PlotType::Bar {
+                            values.push(0.0);
+                        }
+                        if let Some(polygon) = &trace.fill_polygon {
+                            values.extend(polygon.iter().map(|(_, y)| *y));
+                        }
+                    }
+                    let range = if y_axis == 0 {
+                        format.y_range
+                    } else {
+                        format.plotted_secondary_y_axes()[y_axis - 1].range
+                    };
+                    Scale::new(y_type, categories, values.into_iter(), range, padding)
+                })
+                .collect();
+            (x, y, None)
+        };
+
+        Axes {
+            figure,
+            style,
+            x,
+            y,
+            z,
+            traces,
+        }
+    }
+
+    /// Whether the figure is a 3D figure.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the figure contains 3D traces, `false` otherwise.
+    pub(crate) fn is_3d(&self) -> bool {
+        self.z.is_some()
+    }
+
+    /// Project a point in 3D data coordinates onto the screen, as seen from the default plotly.js
+    /// camera position (looking at the origin from `(1.25, 1.25, 1.25)`, with the z-axis pointing
+    /// up).
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - x-coordinate.
+    /// * `y` - y-coordinate.
+    /// * `z` - z-coordinate.
+    ///
+    /// # Returns
+    ///
+    /// Horizontal and vertical position of the point, as fractions of the size of a square
+    /// enclosing the projected axes cube (`(0.0, 0.0)` is the bottom-left corner).
+    pub(crate) fn project(&self, x: f64, y: f64, z: f64) -> (f64, f64) {
+        let z_scale = self.z.as_ref().unwrap_or(&self.x);
+        project_cube(
+            2.0 * self.x.fraction(x) - 1.0,
+            2.0 * self.y[0].fraction(y) - 1.0,
+            2.0 * z_scale.fraction(z) - 1.0,
+        )
+    }
+
+    /// Whether the legend is shown.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the legend is shown (i.e. if the figure contains more than one trace, as in
+    /// plotly.js), `false` otherwise.
+    pub(crate) fn show_legend(&self) -> bool {
+        self.traces.len() > 1
+    }
+}
+
+/// Project a point of the cube `[-1, 1]^3` onto the screen (see [`Axes::project`]).
+///
+/// # Arguments
+///
+/// * `x` - x-coordinate.
+/// * `y` - y-coordinate.
+/// * `z` - z-coordinate.
+///
+/// # Returns
+///
+/// Horizontal and vertical position of the point, as fractions of the size of a square enclosing
+/// the projected cube.
+pub(crate) fn project_cube(x: f64, y: f64, z: f64) -> (f64, f64) {
+    // Screen coordinates of the point (the projected cube spans [-2, 2] in both directions).
+    let u = (y - x) / 2f64.sqrt();
+    let v = (2.0 * z - x - y) / 6f64.sqrt();
+    ((u + 2.0) / 4.0, (v + 2.0) / 4.0)
+}
+
+/// Determine the type of an axis from the data plotted along it.
+///
+/// # Arguments
+///
+/// * `data` - Data plotted along the axis.
+///
+/// # Returns
+///
+/// [`AxisType::Category`] if any of the data is categorical, [`AxisType::Linear`] otherwise.
+fn auto_axis_type<'a>(mut data: impl Iterator<Item = &'a AxisData>) -> AxisType {
+    if data.any(|data| matches!(data, AxisData::Categorical(_))) {
+        AxisType::Category
+    } else {
+        AxisType::Linear
+    }
+}
+
+/// Get the positions of data along an axis.
+///
+/// # Arguments
+///
+/// * `data` - Data plotted along the axis.
+/// * `axis_type` - Axis type.
+/// * `categories` - Categories (only used for categorical axes).
+///
+/// # Returns
+///
+/// Positions of the data points (`NaN` for data that cannot be shown on the axis).
+fn positions(data: &AxisData, axis_type: AxisType, categories: &Categories) -> Vec<f64> {
+    match (data, axis_type) {
+        (_, AxisType::Category) => categories.positions(data),
+        (AxisData::Numeric(data), _) => data.to_vec(),
+        (AxisData::Categorical(data), _) => data
+            .iter()
+            .map(|value| value.parse().unwrap_or(f64::NAN))
+            .collect(),
+    }
+}
+
+/// Get the minimum spacing between distinct values.
+///
+/// # Arguments
+///
+/// * `values` - Values.
+///
+/// # Returns
+///
+/// Minimum spacing between distinct values (`1.0` if there are fewer than two distinct values).
+fn min_spacing(values: impl Iterator<Item = f64>) -> f64 {
+    let mut values: Vec<f64> = values.filter(|value| value.is_finite()).collect();
+    values.sort_by(f64::total_cmp);
+    values.dedup();
+    let spacing = values
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .fold(f64::INFINITY, f64::min);
+    if spacing.is_finite() { spacing } else { 1.0 }
+}
+
+/// Compute the boxes of a box plot (one box per distinct x-axis value).
+///
+/// # Arguments
+///
+/// * `x` - Positions of the data points along the x-axis.
+/// * `y` - Positions of the data points along the y-axis.
+///
+/// # Returns
+///
+/// Boxes.
+fn box_stats(x: &[f64], y: &[f64]) -> Vec<BoxStats> {
+    let mut groups: Vec<(f64, Vec<f64>)> = Vec::new();
+    for (x, y) in x.iter().zip(y) {
+        if x.is_nan() || y.is_nan() {
+            continue;
+        }
+        match groups.iter_mut().find(|(group, _)| group == x) {
+            Some((_, values)) => values.push(*y),
+            None => groups.push((*x, vec![*y])),
+        }
+    }
+    groups
+        .into_iter()
+        .map(|(x, mut values)| {
+            values.sort_by(f64::total_cmp);
+            let q1 = quantile(&values, 0.25);
+            let median = quantile(&values, 0.5);
+            let q3 = quantile(&values, 0.75);
+            let iqr = q3 - q1;
+            let (lower_fence, upper_fence) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+            let inside = || {
+                values
+                    .iter()
+                    .filter(|v| (lower_fence..=upper_fence).contains(*v))
+            };
+            BoxStats {
+                x,
+                lower_whisker: inside().copied().fold(q1, f64::min),
+                q1,
+                median,
+                q3,
+                upper_whisker: inside().copied().fold(q3, f64::max),
+                outliers: values
+                    .iter()
+                    .filter(|v| !(lower_fence..=upper_fence).contains(*v))
+                    .copied()
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+/// Compute a quantile of sorted values (using linear interpolation, as in plotly.js).
+///
+/// # Arguments
+///
+/// * `sorted` - Sorted values (must not be empty).
+/// * `p` - Probability between 0 and 1.
+///
+/// # Returns
+///
+/// Quantile.
+fn quantile(sorted: &[f64], p: f64) -> f64 {
+    let position = p * (sorted.len() - 1) as f64;
+    let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::FormatBuilder;
+
+    #[test]
+    fn test_stacking() {
+        let fig = Figure::new(
+            vec![
+                Trace::new_2d([1.0, 2.0], [1.0, 2.0]).stack_group("a"),
+                Trace::new_2d([1.0, 2.0], [3.0, 1.0]).stack_group("a"),
+            ],
+            FormatBuilder::default().build().unwrap(),
+        );
+        let axes = Axes::new(&fig);
+        assert_eq!(axes.traces[1].y, [4.0, 3.0]);
+        assert_eq!(axes.traces[1].mode, Mode::Lines);
+        assert_eq!(
+            axes.traces[1].fill_polygon.as_deref().unwrap(),
+            [(1.0, 4.0), (2.0, 3.0), (2.0, 2.0), (1.0, 1.0)]
+        );
+    }
+
+    #[test]
+    fn test_bars_and_colors() {
+        let fig = Figure::new(
+            vec![
+                Trace::new_2d(["a", "b"], [1.0, 2.0]).plot_type(PlotType::Bar),
+                Trace::new_2d(["a", "b"], [3.0, 1.0]).plot_type(PlotType::Bar),
+            ],
+            FormatBuilder::default().build().unwrap(),
+        );
+        let axes = Axes::new(&fig);
+        assert_eq!(axes.x.axis_type, AxisType::Category);
+        assert_eq!(axes.traces[0].bar, (-0.2, 0.4));
+        assert_eq!(axes.traces[1].bar, (0.2, 0.4));
+        assert_eq!(axes.traces[1].line_color(), Color::hex_literal(0xFF7F0E));
+        assert_eq!(axes.traces[0].mode, Mode::LinesMarkers);
+    }
+
+    #[test]
+    fn test_box_stats() {
+        let boxes = box_stats(&[0.0; 6], &[1.0, 2.0, 3.0, 4.0, 5.0, 100.0]);
+        assert_eq!(boxes.len(), 1);
+        assert_eq!(boxes[0].q1, 2.25);
+        assert_eq!(boxes[0].median, 3.5);
+        assert_eq!(boxes[0].q3, 4.75);
+        assert_eq!(boxes[0].upper_whisker, 5.0);
+        assert_eq!(boxes[0].outliers, [100.0]);
+    }
+
+    #[test]
+    fn test_project_cube() {
+        assert_eq!(project_cube(0.0, 0.0, 0.0), (0.5, 0.5));
+        let (u, v) = project_cube(1.0, 1.0, -1.0);
+        assert!((u - 0.5).abs() < 1e-12);
+        assert!(v < 0.5);
+    }
+}
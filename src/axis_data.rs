@@ -0,0 +1,270 @@
+use std::sync::Arc;
+
+/// Data along a single axis of a trace.
+///
+/// Axis data is either numeric (e.g. measurements) or categorical (e.g. names of groups). Axis
+/// data is typically constructed implicitly when constructing a trace (see
+/// [`crate::Trace::new_2d`] and [`crate::Trace::new_3d`]), using the [`From`] implementations
+/// listed below.
+///
+/// # Sharing data
+///
+/// The data is stored behind an [`Arc`], so cloning axis data is cheap (the data points themselves
+/// are not copied). To place the same (large) data series in several traces or figures without
+/// duplicating it in memory, construct the axis data once and pass references to it (or clones of
+/// it) to the trace constructors. Axis data can also be constructed from an existing `Arc<[f64]>`
+/// or `Arc<[String]>` without copying.
+///
+/// # Examples
+///
+/// ## Numeric and categorical data
+///
+/// ```
+/// use plotting::{AxisData, Trace};
+///
+/// // Numeric data.
+/// let numeric = AxisData::from([1.0, 2.0, 3.0]);
+///
+/// // Categorical data.
+/// let categorical = AxisData::from(["Test A", "Test B", "Test C"]);
+///
+/// // Trace with categorical x-axis data and numeric y-axis data.
+/// let trace = Trace::new_2d(categorical, numeric);
+/// ```
+///
+/// ## Sharing data between figures
+///
+/// ```
+/// use plotting::{AxisData, Figure, Format, Trace};
+///
+/// let time = AxisData::from((0..1_000_000).map(|i| i as f64 * 1e-3).collect::<Vec<f64>>());
+/// let signal = AxisData::from(vec![0.0; 1_000_000]);
+///
+/// // Both figures share the same time and signal data (it is not copied).
+/// let fig_1 = Figure::new(vec![Trace::new_2d(&time, &signal)], Format::default());
+/// let fig_2 = Figure::new(vec![Trace::new_2d(&time, &signal).name("Signal")], Format::default());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum AxisData {
+    /// Numeric data.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serde_numeric::serialize",
+            deserialize_with = "serde_numeric::deserialize"
+        )
+    )]
+    Numeric(Arc<[f64]>),
+
+    /// Categorical data.
+    Categorical(Arc<[String]>),
+}
+
+impl AxisData {
+    /// Number of data points.
+    ///
+    /// # Returns
+    ///
+    /// Number of data points.
+    pub fn len(&self) -> usize {
+        match self {
+            AxisData::Numeric(data) => data.len(),
+            AxisData::Categorical(data) => data.len(),
+        }
+    }
+
+    /// Determine whether there are no data points.
+    ///
+    /// # Returns
+    ///
+    /// `true` if there are no data points, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the numeric data.
+    ///
+    /// # Returns
+    ///
+    /// Numeric data (`None` if the data is categorical).
+    pub fn as_numeric(&self) -> Option<&[f64]> {
+        match self {
+            AxisData::Numeric(data) => Some(data),
+            AxisData::Categorical(_) => None,
+        }
+    }
+
+    /// Get the categorical data.
+    ///
+    /// # Returns
+    ///
+    /// Categorical data (`None` if the data is numeric).
+    pub fn as_categorical(&self) -> Option<&[String]> {
+        match self {
+            AxisData::Numeric(_) => None,
+            AxisData::Categorical(data) => Some(data),
+        }
+    }
+
+    /// Select a subset of the data points.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - Indices of the data points to select.
+    ///
+    /// # Returns
+    ///
+    /// Selected data points.
+    pub(crate) fn select(&self, indices: &[usize]) -> AxisData {
+        match self {
+            AxisData::Numeric(data) => {
+                AxisData::Numeric(indices.iter().map(|index| data[*index]).collect())
+            }
+            AxisData::Categorical(data) => {
+                AxisData::Categorical(indices.iter().map(|index| data[*index].clone()).collect())
+            }
+        }
+    }
+}
+
+impl From<Arc<[f64]>> for AxisData {
+    fn from(data: Arc<[f64]>) -> Self {
+        AxisData::Numeric(data)
+    }
+}
+
+impl From<Vec<f64>> for AxisData {
+    fn from(data: Vec<f64>) -> Self {
+        AxisData::Numeric(data.into())
+    }
+}
+
+impl<const N: usize> From<[f64; N]> for AxisData {
+    fn from(data: [f64; N]) -> Self {
+        AxisData::Numeric(data.into())
+    }
+}
+
+impl From<&[f64]> for AxisData {
+    fn from(data: &[f64]) -> Self {
+        AxisData::Numeric(data.into())
+    }
+}
+
+impl From<Arc<[String]>> for AxisData {
+    fn from(data: Arc<[String]>) -> Self {
+        AxisData::Categorical(data)
+    }
+}
+
+impl From<Vec<String>> for AxisData {
+    fn from(data: Vec<String>) -> Self {
+        AxisData::Categorical(data.into())
+    }
+}
+
+impl From<Vec<&str>> for AxisData {
+    fn from(data: Vec<&str>) -> Self {
+        AxisData::Categorical(data.into_iter().map(String::from).collect())
+    }
+}
+
+impl<const N: usize> From<[&str; N]> for AxisData {
+    fn from(data: [&str; N]) -> Self {
+        AxisData::Categorical(data.into_iter().map(String::from).collect())
+    }
+}
+
+impl From<&[&str]> for AxisData {
+    fn from(data: &[&str]) -> Self {
+        AxisData::Categorical(data.iter().map(|s| String::from(*s)).collect())
+    }
+}
+
+impl<const N: usize> PartialEq<[f64; N]> for AxisData {
+    fn eq(&self, other: &[f64; N]) -> bool {
+        self.as_numeric() == Some(other.as_slice())
+    }
+}
+
+impl<const N: usize> PartialEq<[&str; N]> for AxisData {
+    fn eq(&self, other: &[&str; N]) -> bool {
+        self.as_categorical()
+            .is_some_and(|data| data.iter().eq(other.iter().copied()))
+    }
+}
+
+/// Serialization of numeric data, with `NaN` values (i.e. gaps) represented as `null` (since they
+/// cannot be represented in formats such as JSON).
+#[cfg(feature = "serde")]
+mod serde_numeric {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use std::sync::Arc;
+
+    pub(super) fn serialize<S: Serializer>(data: &[f64], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(data.iter().map(|value| (!value.is_nan()).then_some(*value)))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Arc<[f64]>, D::Error> {
+        let data: Vec<Option<f64>> = Vec::deserialize(deserializer)?;
+        Ok(data
+            .into_iter()
+            .map(|value| value.unwrap_or(f64::NAN))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_numeric() {
+        let expected = AxisData::Numeric(Arc::from([1.0, 2.0, 3.0]));
+        assert_eq!(AxisData::from(vec![1.0, 2.0, 3.0]), expected);
+        assert_eq!(
+            AxisData::from(Arc::<[f64]>::from([1.0, 2.0, 3.0])),
+            expected
+        );
+        assert_eq!(AxisData::from([1.0, 2.0, 3.0]), expected);
+        assert_eq!(AxisData::from(&[1.0, 2.0, 3.0][..]), expected);
+    }
+
+    #[test]
+    fn test_from_categorical() {
+        let expected = AxisData::Categorical(Arc::from([String::from("a"), String::from("b")]));
+        assert_eq!(
+            AxisData::from(vec![String::from("a"), String::from("b")]),
+            expected
+        );
+        assert_eq!(AxisData::from(vec!["a", "b"]), expected);
+        assert_eq!(AxisData::from(["a", "b"]), expected);
+        assert_eq!(AxisData::from(&["a", "b"][..]), expected);
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(AxisData::from([1.0, 2.0, 3.0]).len(), 3);
+        assert_eq!(AxisData::from(["a", "b"]).len(), 2);
+        assert!(AxisData::from(Vec::<f64>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_select() {
+        assert_eq!(AxisData::from([1.0, 2.0, 3.0]).select(&[0, 2]), [1.0, 3.0]);
+        assert_eq!(AxisData::from(["a", "b", "c"]).select(&[1]), ["b"]);
+    }
+
+    #[test]
+    fn test_partial_eq_array() {
+        assert_eq!(AxisData::from([1.0, 2.0]), [1.0, 2.0]);
+        assert_ne!(AxisData::from([1.0, 2.0]), [1.0, 3.0]);
+        assert_eq!(AxisData::from(["a", "b"]), ["a", "b"]);
+        assert_ne!(AxisData::from(["a", "b"]), [1.0, 2.0]);
+    }
+}
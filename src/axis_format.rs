@@ -0,0 +1,102 @@
+use derive_builder::Builder;
+use plotly::common::AxisSide as PlotlyAxisSide;
+use plotly::layout::Axis;
+
+/// Side of the plotting area on which an axis is drawn.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum AxisSide {
+    /// Left side of the plotting area.
+    Left,
+
+    /// Right side of the plotting area.
+    #[default]
+    Right,
+}
+
+impl From<AxisSide> for PlotlyAxisSide {
+    fn from(side: AxisSide) -> Self {
+        match side {
+            AxisSide::Left => PlotlyAxisSide::Left,
+            AxisSide::Right => PlotlyAxisSide::Right,
+        }
+    }
+}
+
+/// Format of a secondary y-axis.
+///
+/// Secondary y-axes are overlaid on the primary y-axis, sharing the same x-axis. Traces are
+/// assigned to a secondary y-axis using [`crate::Trace::y_axis`].
+///
+/// # Example
+///
+/// ```
+/// use plotting::{AxisFormat, AxisFormatBuilder, AxisSide};
+///
+/// // Secondary y-axis drawn on the right side of the plotting area.
+/// let speed_axis: AxisFormat = AxisFormatBuilder::default()
+///     .label("Speed [m/s]")
+///     .range((0.0, 300.0))
+///     .build()
+///     .unwrap();
+///
+/// // Additional y-axis drawn on the left side of the plotting area, offset from the primary
+/// // y-axis.
+/// let mass_axis: AxisFormat = AxisFormatBuilder::default()
+///     .label("Mass [kg]")
+///     .side(AxisSide::Left)
+///     .position(0.0)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Builder, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct AxisFormat {
+    /// Axis label.
+    #[builder(setter(into, strip_option), default)]
+    pub(crate) label: Option<String>,
+
+    /// Axis range (lower and upper limits).
+    #[builder(setter(strip_option), default)]
+    pub(crate) range: Option<(f64, f64)>,
+
+    /// Side of the plotting area on which the axis is drawn.
+    #[builder(default)]
+    pub(crate) side: AxisSide,
+
+    /// Position of the axis as a fraction of the width of the figure (where `0.0` corresponds to
+    /// the left edge and `1.0` corresponds to the right edge).
+    ///
+    /// If not specified, the axis is drawn at the edge of the plotting area. When specifying a
+    /// position, the domain of the x-axis (see [`crate::FormatBuilder::x_domain`]) should be
+    /// narrowed to leave space for the axis.
+    #[builder(setter(strip_option), default)]
+    pub(crate) position: Option<f64>,
+}
+
+impl AxisFormat {
+    /// Create a plotly axis from this axis format.
+    ///
+    /// # Arguments
+    ///
+    /// * `overlaying` - Plotly ID of the axis that this axis is overlaid on (e.g. `"y"`).
+    ///
+    /// # Returns
+    ///
+    /// Plotly axis.
+    pub(crate) fn plotly(&self, overlaying: &str) -> Axis {
+        let mut axis = Axis::new().overlaying(overlaying).side(self.side.into());
+        if let Some(label) = &self.label {
+            axis = axis.title(label.as_str());
+        }
+        if let Some((lower, upper)) = self.range {
+            axis = axis.range(vec![lower, upper]);
+        }
+        if let Some(position) = self.position {
+            axis = axis.anchor("free").position(position);
+        }
+        axis
+    }
+}
@@ -0,0 +1,44 @@
+use plotly::layout::AxisType as PlotlyAxisType;
+
+/// Axis type.
+///
+/// # Note
+///
+/// This enum is a partial re-implementation of the [`plotly::layout::AxisType`] enum from the
+/// [`plotly`] crate (Ref. \[1\]). As such, we have included the license of the [`plotly`] crate in
+/// the [`src/plotly_licenses`](https://github.com/tamaskis/plotting/tree/main/src/plotly_licenses/LICENSE)
+/// folder.
+///
+/// # References
+///
+/// * \[1\] <https://docs.rs/plotly/latest/plotly/layout/enum.AxisType.html>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum AxisType {
+    /// Linear axis.
+    Linear,
+
+    /// Logarithmic axis.
+    Log,
+
+    /// Date axis.
+    ///
+    /// Numeric data plotted against a date axis is interpreted as the number of milliseconds
+    /// since the Unix epoch (1970-01-01T00:00:00).
+    Date,
+
+    /// Categorical axis.
+    Category,
+}
+
+impl From<AxisType> for PlotlyAxisType {
+    fn from(axis_type: AxisType) -> Self {
+        match axis_type {
+            AxisType::Linear => PlotlyAxisType::Linear,
+            AxisType::Log => PlotlyAxisType::Log,
+            AxisType::Date => PlotlyAxisType::Date,
+            AxisType::Category => PlotlyAxisType::Category,
+        }
+    }
+}
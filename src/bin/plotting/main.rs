@@ -0,0 +1,191 @@
+//! `plotting` command-line tool.
+//!
+//! Renders figures described by TOML or JSON figure spec files (see [`spec::FigureSpec`]), or
+//! quickly plots columns of data read from standard input or files, to HTML, SVG, PNG, or
+//! plotly.js JSON files.
+
+mod output;
+mod quick;
+mod spec;
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// Usage of the command-line tool.
+const USAGE: &str = "\
+Usage: plotting <COMMAND>
+
+Commands:
+  render    Render a figure spec file
+  quick     Quickly plot columns of data read from standard input or files
+
+Options:
+  -h, --help       Print help
+  -V, --version    Print version
+";
+
+/// Usage of the `render` subcommand.
+const RENDER_USAGE: &str = "\
+Render a figure spec file (TOML, or JSON if the file has a .json extension).
+
+Usage: plotting render [OPTIONS] <SPEC>
+
+Arguments:
+  <SPEC>    Path to the figure spec file
+
+Options:
+  -o, --output <FILE>    Output file (.html, .svg, .png, or .json); opens the figure in a web
+                         browser if not specified
+  -h, --help             Print help
+";
+
+/// Usage of the `quick` subcommand.
+const QUICK_USAGE: &str = "\
+Quickly plot columns of data read from standard input or files.
+
+Fields are separated by commas or whitespace (detected automatically), and may be quoted as in CSV
+files. Empty lines and lines starting with '#' are skipped, missing values (empty fields, NA, N/A,
+and null) are plotted as gaps, and the first row is used for the axis labels if it is a header row.
+
+Usage: plotting quick [OPTIONS] [FILE]...
+
+Arguments:
+  [FILE]...    Input files, plotted as one trace each (standard input if not specified or '-')
+
+Options:
+  -x <COLUMN>              x-axis column, as a zero-based index or a name [default: 0, or the row
+                           index if there is only one column]
+  -y <COLUMN>              y-axis column [default: 1, or 0 if there is only one column]
+  -z <COLUMN>              z-axis column (creates a 3D plot)
+  -d, --delimiter <CHAR>   Delimiter between fields (' ' for runs of whitespace)
+      --x-label <LABEL>    x-axis label
+      --y-label <LABEL>    y-axis label
+      --z-label <LABEL>    z-axis label
+  -t, --title <TITLE>      Title
+  -o, --output <FILE>      Output file (.html, .svg, .png, or .json); opens the figure in a web
+                           browser if not specified
+  -h, --help               Print help
+";
+
+fn main() -> ExitCode {
+    match run(std::env::args().skip(1).collect()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Run the command-line tool.
+///
+/// # Arguments
+///
+/// * `args` - Command-line arguments (excluding the program name).
+///
+/// # Errors
+///
+/// If the arguments are invalid or the command fails.
+fn run(args: Vec<String>) -> Result<(), String> {
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("render") => render(args),
+        Some("quick") => quick(args),
+        Some("-h" | "--help") | None => {
+            print!("{USAGE}");
+            Ok(())
+        }
+        Some("-V" | "--version") => {
+            println!("plotting {}", env!("CARGO_PKG_VERSION"));
+            Ok(())
+        }
+        Some(command) => Err(format!("unknown command \"{command}\"\n\n{USAGE}")),
+    }
+}
+
+/// Run the `render` subcommand.
+///
+/// # Arguments
+///
+/// * `args` - Arguments of the subcommand.
+///
+/// # Errors
+///
+/// If the arguments are invalid, or if the figure spec cannot be loaded or rendered.
+fn render(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut spec_path: Option<PathBuf> = None;
+    let mut output: Option<PathBuf> = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--output" => {
+                output = Some(args.next().ok_or("missing value for --output")?.into());
+            }
+            "-h" | "--help" => {
+                print!("{RENDER_USAGE}");
+                return Ok(());
+            }
+            _ if arg.starts_with('-') => {
+                return Err(format!("unknown option \"{arg}\"\n\n{RENDER_USAGE}"));
+            }
+            _ if spec_path.is_none() => spec_path = Some(arg.into()),
+            _ => return Err(format!("unexpected argument \"{arg}\"\n\n{RENDER_USAGE}")),
+        }
+    }
+    let spec_path = spec_path.ok_or(format!("missing figure spec file\n\n{RENDER_USAGE}"))?;
+
+    // CSV files are located relative to the figure spec file.
+    let directory = spec_path.parent().unwrap_or(Path::new("."));
+    let figure = spec::load(&spec_path)?.figure(directory)?;
+    output::write(&figure, output.as_deref())
+}
+
+/// Run the `quick` subcommand.
+///
+/// # Arguments
+///
+/// * `args` - Arguments of the subcommand.
+///
+/// # Errors
+///
+/// If the arguments are invalid, or if the data cannot be read or plotted.
+fn quick(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut options = quick::QuickOptions::default();
+    let mut output: Option<PathBuf> = None;
+    while let Some(arg) = args.next() {
+        let mut value = |option: &str| {
+            args.next()
+                .ok_or(format!("missing value for {option}\n\n{QUICK_USAGE}"))
+        };
+        match arg.as_str() {
+            "-x" => options.x = Some(quick::parse_column(value("-x")?)),
+            "-y" => options.y = Some(quick::parse_column(value("-y")?)),
+            "-z" => options.z = Some(quick::parse_column(value("-z")?)),
+            "-d" | "--delimiter" => {
+                let delimiter = value("--delimiter")?;
+                let mut chars = delimiter.chars();
+                options.delimiter = match (chars.next(), chars.next()) {
+                    (Some(delimiter), None) => Some(delimiter),
+                    _ if delimiter == "\\t" => Some('\t'),
+                    _ => return Err(format!("invalid delimiter \"{delimiter}\"")),
+                };
+            }
+            "--x-label" => options.x_label = Some(value("--x-label")?),
+            "--y-label" => options.y_label = Some(value("--y-label")?),
+            "--z-label" => options.z_label = Some(value("--z-label")?),
+            "-t" | "--title" => options.title = Some(value("--title")?),
+            "-o" | "--output" => output = Some(value("--output")?.into()),
+            "-h" | "--help" => {
+                print!("{QUICK_USAGE}");
+                return Ok(());
+            }
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option \"{arg}\"\n\n{QUICK_USAGE}"));
+            }
+            _ => options.inputs.push(arg.into()),
+        }
+    }
+
+    let texts = options.read_inputs()?;
+    let figure = options.figure(&texts)?;
+    output::write(&figure, output.as_deref())
+}
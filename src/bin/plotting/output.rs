@@ -0,0 +1,68 @@
+use plotting::{Figure, HtmlOptions};
+use std::path::Path;
+
+/// Write a figure to a file, or show it in a web browser.
+///
+/// # Arguments
+///
+/// * `figure` - Figure.
+/// * `output` - Path to the output file, whose extension determines the output format (`.html`,
+///   `.svg`, `.png`, or `.json` for the plotly.js figure specification). If not specified, the
+///   figure is opened in a web browser.
+///
+/// # Errors
+///
+/// If the extension of the output file is not supported, or if the output file cannot be written.
+pub(crate) fn write(figure: &Figure, output: Option<&Path>) -> Result<(), String> {
+    let Some(output) = output else {
+        figure.show();
+        return Ok(());
+    };
+    let extension = output
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let contents = match extension.as_str() {
+        "html" | "htm" => figure.to_html_with(&HtmlOptions::default()).into_bytes(),
+        "svg" => figure.to_svg().into_bytes(),
+        "png" => figure.to_png(),
+        "json" => figure.to_plotly_json().into_bytes(),
+        _ => {
+            return Err(format!(
+                "unsupported output file {} (expected a .html, .svg, .png, or .json file)",
+                output.display()
+            ));
+        }
+    };
+    let error = |error: std::io::Error| format!("cannot write {}: {error}", output.display());
+    if let Some(directory) = output
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(directory).map_err(error)?;
+    }
+    std::fs::write(output, contents).map_err(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use plotting::quick_plot_2d;
+
+    #[test]
+    fn test_write() {
+        let fig = quick_plot_2d([1.0, 2.0], [3.0, 4.0]);
+        let directory = std::env::temp_dir().join("plotting_output");
+        let path = directory.join("figure.json");
+        write(&fig, Some(&path)).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            fig.to_plotly_json()
+        );
+
+        // Output files that cannot be written are reported as errors.
+        let error = write(&fig, Some(&path.join("figure.svg"))).unwrap_err();
+        assert!(error.starts_with("cannot write"), "{error}");
+        assert!(write(&fig, Some(&directory.join("figure.txt"))).is_err());
+    }
+}
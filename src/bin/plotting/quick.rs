@@ -0,0 +1,309 @@
+use plotting::{AxisData, Column, CsvOptionsBuilder, CsvTable, Figure, FormatBuilder, Trace};
+use std::io::Read;
+use std::path::PathBuf;
+
+/// Options of the `quick` subcommand.
+#[derive(Default)]
+pub(crate) struct QuickOptions {
+    /// Input files (standard input if empty or `-`).
+    pub(crate) inputs: Vec<PathBuf>,
+
+    /// Delimiter between fields (commas or whitespace are detected automatically if not
+    /// specified, and `' '` separates fields by runs of whitespace).
+    pub(crate) delimiter: Option<char>,
+
+    /// x-axis column (the first column, or the row index if there is only one column, if not
+    /// specified).
+    pub(crate) x: Option<Column>,
+
+    /// y-axis column (the second column, or the only column, if not specified).
+    pub(crate) y: Option<Column>,
+
+    /// z-axis column (the plot is 3D if specified).
+    pub(crate) z: Option<Column>,
+
+    /// x-axis label (the name of the x-axis column if not specified).
+    pub(crate) x_label: Option<String>,
+
+    /// y-axis label (the name of the y-axis column if not specified).
+    pub(crate) y_label: Option<String>,
+
+    /// z-axis label (the name of the z-axis column if not specified).
+    pub(crate) z_label: Option<String>,
+
+    /// Title.
+    pub(crate) title: Option<String>,
+}
+
+/// Parse a table from whitespace- or delimiter-separated text.
+///
+/// Lines starting with `#` are comments. The first row is treated as a header row if none of its
+/// fields are numbers but some field of the second row is a number (see [`CsvTable::parse`]).
+///
+/// # Arguments
+///
+/// * `text` - Text.
+/// * `delimiter` - Delimiter between fields. If not specified, fields are separated by commas if
+///   the first row contains a comma, and by whitespace otherwise.
+///
+/// # Returns
+///
+/// Table.
+///
+/// # Errors
+///
+/// If the text is malformed (i.e. contains an unterminated quoted field).
+fn parse_table(text: &str, delimiter: Option<char>) -> Result<CsvTable, String> {
+    let delimiter = delimiter.unwrap_or_else(|| {
+        let first_row = text
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'));
+        if first_row.is_some_and(|line| line.contains(',')) {
+            ','
+        } else {
+            ' '
+        }
+    });
+    let options = CsvOptionsBuilder::default()
+        .delimiter(delimiter)
+        .comment('#')
+        .build()
+        .unwrap();
+    CsvTable::parse(text, &options).map_err(|error| error.to_string())
+}
+
+impl QuickOptions {
+    /// Build the figure from the contents of the inputs.
+    ///
+    /// One trace is created per input (named after its file if there are several inputs). Unless
+    /// axis labels are specified, the names of the columns of the first input are used as axis
+    /// labels.
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - Names and contents of the inputs.
+    ///
+    /// # Returns
+    ///
+    /// Figure.
+    ///
+    /// # Errors
+    ///
+    /// If one of the inputs is malformed or empty, or if one of the columns does not exist.
+    pub(crate) fn figure(mut self, texts: &[(String, String)]) -> Result<Figure, String> {
+        let mut traces = Vec::new();
+        for (name, text) in texts {
+            let table =
+                parse_table(text, self.delimiter).map_err(|error| format!("{name}: {error}"))?;
+            if table.row_count() == 0 {
+                return Err(format!("{name}: no data"));
+            }
+            let columns = table.column_count();
+            let axis_data = |column: &Column| {
+                table
+                    .axis_data(column)
+                    .map(|data| (data, table.column_name(column)))
+                    .map_err(|error| format!("{name}: {error}"))
+            };
+
+            // Default columns.
+            let y = self
+                .y
+                .clone()
+                .unwrap_or(Column::Index(usize::from(columns > 1)));
+            let x = self.x.clone().or((columns > 1).then_some(Column::Index(0)));
+
+            let (y_data, y_name) = axis_data(&y)?;
+            let (x_data, x_name) = match &x {
+                Some(x) => axis_data(x)?,
+                None => (
+                    AxisData::Numeric((0..table.row_count()).map(|i| i as f64).collect()),
+                    None,
+                ),
+            };
+            let mut trace = match &self.z {
+                Some(z) => {
+                    let (z_data, z_name) = axis_data(z)?;
+                    if traces.is_empty() && self.z_label.is_none() {
+                        self.z_label = z_name;
+                    }
+                    Trace::new_3d(x_data, y_data, z_data)
+                }
+                None => Trace::new_2d(x_data, y_data),
+            };
+            if texts.len() > 1 {
+                trace = trace.name(name);
+            }
+            if traces.is_empty() {
+                self.x_label = self.x_label.or(x_name);
+                self.y_label = self.y_label.or(y_name);
+            }
+            traces.push(trace);
+        }
+
+        // Formatting.
+        let mut format = FormatBuilder::default();
+        if let Some(x_label) = self.x_label {
+            format.x_label(x_label);
+        }
+        if let Some(y_label) = self.y_label {
+            format.y_label(y_label);
+        }
+        if let Some(z_label) = self.z_label {
+            format.z_label(z_label);
+        }
+        if let Some(title) = self.title {
+            format.title(title);
+        }
+        Ok(Figure::new(traces, format.build().unwrap()))
+    }
+
+    /// Read the inputs.
+    ///
+    /// # Returns
+    ///
+    /// Names and contents of the inputs (standard input is named `stdin`).
+    ///
+    /// # Errors
+    ///
+    /// If one of the inputs cannot be read, or if standard input (`-`) is given several times.
+    pub(crate) fn read_inputs(&self) -> Result<Vec<(String, String)>, String> {
+        let stdin_count = self
+            .inputs
+            .iter()
+            .filter(|path| path.as_os_str() == "-")
+            .count();
+        if stdin_count > 1 {
+            return Err(String::from("standard input (-) can only be read once"));
+        }
+        let read_stdin = || {
+            let mut text = String::new();
+            std::io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|error| format!("failed to read standard input: {error}"))?;
+            Ok((String::from("stdin"), text))
+        };
+        if self.inputs.is_empty() {
+            return Ok(vec![read_stdin()?]);
+        }
+        self.inputs
+            .iter()
+            .map(|path| {
+                if path.as_os_str() == "-" {
+                    return read_stdin();
+                }
+                let text = std::fs::read_to_string(path)
+                    .map_err(|error| format!("failed to read {}: {error}", path.display()))?;
+                Ok((path.display().to_string(), text))
+            })
+            .collect()
+    }
+}
+
+/// Parse a column given on the command line (a zero-based index or a column name).
+///
+/// # Arguments
+///
+/// * `column` - Column.
+///
+/// # Returns
+///
+/// Column.
+pub(crate) fn parse_column(column: String) -> Column {
+    match column.parse::<usize>() {
+        Ok(index) => Column::Index(index),
+        Err(_) => Column::Name(column),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a figure from a single input and get its plotly.js figure specification.
+    fn figure(options: QuickOptions, text: &str) -> serde_json::Value {
+        let fig = options
+            .figure(&[(String::from("stdin"), String::from(text))])
+            .unwrap();
+        serde_json::from_str(&fig.to_plotly_json()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_table() {
+        let table = parse_table("# output\n t  speed\n0 1.5\n\n1\tNA\n", None).unwrap();
+        assert_eq!(
+            table.column_name(&Column::from(1)).as_deref(),
+            Some("speed")
+        );
+        assert_eq!(table.axis_data(&Column::from("t")).unwrap(), [0.0, 1.0]);
+        let speed = table.axis_data(&Column::from(1)).unwrap();
+        let speed = speed.as_numeric().unwrap();
+        assert_eq!(speed[0], 1.5);
+        assert!(speed[1].is_nan());
+
+        let table = parse_table("a, 1\n\"b, c\", 2\n", None).unwrap();
+        assert_eq!(table.column_name(&Column::from(0)), None);
+        assert_eq!(table.axis_data(&Column::from(0)).unwrap(), ["a", "b, c"]);
+        assert!(table.axis_data(&Column::from(2)).is_err());
+        assert!(parse_table("\"a\n", None).is_err());
+    }
+
+    #[test]
+    fn test_figure_2d() {
+        let fig = figure(QuickOptions::default(), "1\n4\n9\n");
+        assert_eq!(fig["data"][0]["x"], serde_json::json!([0.0, 1.0, 2.0]));
+        assert_eq!(fig["data"][0]["y"], serde_json::json!([1.0, 4.0, 9.0]));
+
+        let options = QuickOptions {
+            y: Some(Column::from("c")),
+            title: Some(String::from("Title")),
+            ..QuickOptions::default()
+        };
+        let fig = figure(options, "a,b,c\n1,2,3\n4,5,6\n");
+        assert_eq!(fig["data"][0]["x"], serde_json::json!([1.0, 4.0]));
+        assert_eq!(fig["data"][0]["y"], serde_json::json!([3.0, 6.0]));
+        assert_eq!(fig["layout"]["xaxis"]["title"]["text"], "a");
+        assert_eq!(fig["layout"]["yaxis"]["title"]["text"], "c");
+        assert_eq!(fig["layout"]["title"]["text"], "Title");
+    }
+
+    #[test]
+    fn test_figure_3d() {
+        let options = QuickOptions {
+            z: Some(Column::Index(2)),
+            z_label: Some(String::from("height")),
+            ..QuickOptions::default()
+        };
+        let fig = figure(options, "1 2 3\n4 5 6\n");
+        assert_eq!(fig["data"][0]["z"], serde_json::json!([3.0, 6.0]));
+        assert_eq!(fig["layout"]["scene"]["zaxis"]["title"]["text"], "height");
+        assert!(fig["layout"]["xaxis"]["title"].is_null());
+
+        let options = QuickOptions {
+            x: Some(Column::from("x")),
+            ..QuickOptions::default()
+        };
+        let texts = [
+            (String::from("a.txt"), String::from("1 2\n")),
+            (String::from("b.txt"), String::from("")),
+        ];
+        assert!(options.figure(&texts).is_err());
+    }
+
+    #[test]
+    fn test_read_inputs() {
+        let options = QuickOptions {
+            inputs: vec![PathBuf::from("-"), PathBuf::from("-")],
+            ..QuickOptions::default()
+        };
+        let error = options.read_inputs().unwrap_err();
+        assert!(error.contains("standard input"), "{error}");
+    }
+
+    #[test]
+    fn test_parse_column() {
+        assert_eq!(parse_column(String::from("2")), Column::Index(2));
+        assert_eq!(parse_column(String::from("time")), Column::from("time"));
+    }
+}
@@ -0,0 +1,395 @@
+use plotting::{
+    Annotation, Color, Columns, CsvOptions, Figure, Fill, Format, LineStyle, Mode, PlotType, Shape,
+    Trace,
+};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Figure spec.
+///
+/// A figure spec is a TOML or JSON file describing a figure whose traces are read from the
+/// columns of CSV files.
+///
+/// # Example
+///
+/// ```toml
+/// [format]
+/// title = "Speed"
+/// theme = "plotly_white"
+///
+/// [[traces]]
+/// csv = "runs.csv"
+/// x = "time"
+/// y = "speed"
+/// group_by = "vehicle"
+///
+/// [[shapes]]
+/// kind = "line"
+/// x0 = 0.0
+/// y0 = 30.0
+/// x1 = 1.0
+/// y1 = 30.0
+/// x_coordinates = "paper"
+/// ```
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct FigureSpec {
+    /// Figure format.
+    #[serde(default)]
+    format: Format,
+
+    /// Traces.
+    #[serde(default)]
+    traces: Vec<TraceSpec>,
+
+    /// Annotations.
+    #[serde(default)]
+    annotations: Vec<Annotation>,
+
+    /// Shapes.
+    #[serde(default)]
+    shapes: Vec<Shape>,
+}
+
+/// Spec of a set of traces read from the columns of a CSV file.
+#[derive(Deserialize)]
+struct TraceSpec {
+    /// Path to the CSV file (relative to the directory containing the figure spec).
+    csv: PathBuf,
+
+    /// Columns to build the traces from.
+    #[serde(flatten)]
+    columns: Columns,
+
+    /// Options for reading the CSV file.
+    #[serde(flatten)]
+    options: CsvOptions,
+
+    /// Styling of the traces.
+    #[serde(flatten)]
+    style: TraceStyle,
+
+    /// Keys not used by any of the above (which are rejected, since `deny_unknown_fields` does not
+    /// work together with `flatten`).
+    #[serde(flatten)]
+    unknown: BTreeMap<String, Value>,
+}
+
+/// Styling of a set of traces (see the corresponding setters of [`Trace`]).
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct TraceStyle {
+    /// Trace name (only used if the rows are not split into groups).
+    name: Option<String>,
+
+    /// Type of plot.
+    plot_type: Option<PlotType>,
+
+    /// Drawing mode.
+    mode: Option<Mode>,
+
+    /// Marker color.
+    marker_color: Option<Color>,
+
+    /// Marker size.
+    marker_size: Option<usize>,
+
+    /// Line color.
+    line_color: Option<Color>,
+
+    /// Line width.
+    line_width: Option<f64>,
+
+    /// Line style.
+    line_style: Option<LineStyle>,
+
+    /// Fill mode.
+    fill: Option<Fill>,
+
+    /// Fill color.
+    fill_color: Option<Color>,
+
+    /// Stack group.
+    stack_group: Option<String>,
+
+    /// y-axis.
+    y_axis: Option<usize>,
+}
+
+impl TraceStyle {
+    /// Apply the styling to a trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `trace` - Trace.
+    /// * `grouped` - Whether the trace is one of several groups (in which case it keeps the name
+    ///   of its group).
+    ///
+    /// # Returns
+    ///
+    /// The styled trace.
+    fn apply(&self, mut trace: Trace, grouped: bool) -> Trace {
+        if let Some(name) = self.name.as_ref().filter(|_| !grouped) {
+            trace = trace.name(name);
+        }
+        if let Some(plot_type) = self.plot_type {
+            trace = trace.plot_type(plot_type);
+        }
+        if let Some(mode) = self.mode {
+            trace = trace.mode(mode);
+        }
+        if let Some(marker_color) = &self.marker_color {
+            trace = trace.marker_color(marker_color.clone());
+        }
+        if let Some(marker_size) = self.marker_size {
+            trace = trace.marker_size(marker_size);
+        }
+        if let Some(line_color) = &self.line_color {
+            trace = trace.line_color(line_color.clone());
+        }
+        if let Some(line_width) = self.line_width {
+            trace = trace.line_width(line_width);
+        }
+        if let Some(line_style) = self.line_style {
+            trace = trace.line_style(line_style);
+        }
+        if let Some(fill) = self.fill {
+            trace = trace.fill(fill);
+        }
+        if let Some(fill_color) = &self.fill_color {
+            trace = trace.fill_color(fill_color.clone());
+        }
+        if let Some(stack_group) = &self.stack_group {
+            trace = trace.stack_group(stack_group);
+        }
+        if let Some(y_axis) = self.y_axis {
+            trace = trace.y_axis(y_axis);
+        }
+        trace
+    }
+}
+
+/// Parse a figure spec.
+///
+/// Unless axis labels are specified in the format, the names of the x-, y-, and z-axis columns of
+/// the first set of traces are used as axis labels.
+///
+/// # Arguments
+///
+/// * `text` - Contents of the figure spec file.
+/// * `json` - Whether the figure spec is in JSON format (otherwise it is in TOML format).
+///
+/// # Returns
+///
+/// Figure spec.
+///
+/// # Errors
+///
+/// If the figure spec is malformed (including if a trace has unknown keys).
+pub(crate) fn parse(text: &str, json: bool) -> Result<FigureSpec, String> {
+    let mut value: Value = if json {
+        serde_json::from_str(text).map_err(|error| error.to_string())?
+    } else {
+        toml::from_str(text).map_err(|error| error.to_string())?
+    };
+
+    // Default axis labels.
+    let first_trace = value["traces"].get(0).cloned();
+    if let (Some(object), Some(first_trace)) = (value.as_object_mut(), first_trace) {
+        let format = object
+            .entry("format")
+            .or_insert_with(|| Value::Object(Default::default()));
+        if let Some(format) = format.as_object_mut() {
+            for axis in ["x", "y", "z"] {
+                if let Some(Value::String(name)) = first_trace.get(axis) {
+                    format
+                        .entry(format!("{axis}_label"))
+                        .or_insert_with(|| Value::String(name.clone()));
+                }
+            }
+        }
+    }
+
+    let spec: FigureSpec = serde_json::from_value(value).map_err(|error| error.to_string())?;
+    for (index, trace) in spec.traces.iter().enumerate() {
+        if let Some(key) = trace.unknown.keys().next() {
+            return Err(format!("unknown field `{key}` in trace {index}"));
+        }
+        if trace.style.y_axis == Some(0) {
+            return Err(format!(
+                "invalid y_axis 0 in trace {index} (y-axes start at 1)"
+            ));
+        }
+    }
+    Ok(spec)
+}
+
+/// Load a figure spec file.
+///
+/// # Arguments
+///
+/// * `path` - Path to the figure spec file (a `.json` file, or a TOML file otherwise).
+///
+/// # Returns
+///
+/// Figure spec.
+///
+/// # Errors
+///
+/// If the file cannot be read or is malformed.
+pub(crate) fn load(path: &Path) -> Result<FigureSpec, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|error| format!("failed to read {}: {error}", path.display()))?;
+    let json = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+    parse(&text, json).map_err(|error| format!("invalid figure spec {}: {error}", path.display()))
+}
+
+impl FigureSpec {
+    /// Build the figure described by the spec.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - Directory that the paths of the CSV files are relative to.
+    ///
+    /// # Returns
+    ///
+    /// Figure.
+    ///
+    /// # Errors
+    ///
+    /// If one of the CSV files cannot be read or is malformed, or if one of the columns does not
+    /// exist.
+    pub(crate) fn figure(self, directory: &Path) -> Result<Figure, String> {
+        let mut traces = Vec::new();
+        for spec in &self.traces {
+            let path = directory.join(&spec.csv);
+            let new_traces = Trace::from_csv(&path, &spec.columns, &spec.options)
+                .map_err(|error| format!("{}: {error}", path.display()))?;
+            let grouped = new_traces.len() > 1;
+            traces.extend(
+                new_traces
+                    .into_iter()
+                    .map(|trace| spec.style.apply(trace, grouped)),
+            );
+        }
+        let mut figure = Figure::new(traces, self.format);
+        for annotation in self.annotations {
+            figure = figure.add_annotation(annotation);
+        }
+        for shape in self.shapes {
+            figure = figure.add_shape(shape);
+        }
+        Ok(figure)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_build() {
+        let directory = std::env::temp_dir().join("plotting_cli_spec");
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(
+            directory.join("runs.csv"),
+            "time,speed,vehicle\n0,1,car\n1,2,car\n0,3,bike\n1,4,bike\n",
+        )
+        .unwrap();
+
+        let spec = parse(
+            r##"
+                [format]
+                title = "Speed"
+                theme = "plotly_dark"
+                y_label = "Speed [m/s]"
+
+                [[traces]]
+                csv = "runs.csv"
+                x = "time"
+                y = 1
+                group_by = "vehicle"
+                line_color = "#ff0000"
+
+                [[traces]]
+                csv = "runs.csv"
+                x = "time"
+                y = "speed"
+                name = "All"
+                plot_type = "bar"
+
+                [[annotations]]
+                text = "Start"
+                x = 0.0
+                y = 1.0
+            "##,
+            false,
+        )
+        .unwrap();
+        let figure = spec.figure(&directory).unwrap();
+        let svg = figure.to_svg();
+        assert!(svg.contains(">Speed</text>"));
+        assert!(svg.contains(">time</text>"));
+        assert!(svg.contains(">Speed [m/s]</text>"));
+        assert!(svg.contains(">car</text>"));
+        assert!(svg.contains(">bike</text>"));
+        assert!(svg.contains(">All</text>"));
+        assert!(svg.contains(">Start</text>"));
+        assert!(svg.contains(r##"stroke="#ff0000""##));
+    }
+
+    #[test]
+    fn test_parse_json() {
+        let spec = parse(
+            r#"{"traces": [{"csv": "data.tsv", "x": 0, "y": 1, "delimiter": "\t"}]}"#,
+            true,
+        )
+        .unwrap();
+        assert_eq!(spec.traces[0].csv, Path::new("data.tsv"));
+        assert!(format!("{:?}", spec.traces[0].options).contains(r"delimiter: '\t'"));
+        assert!(spec.figure(Path::new("does/not/exist")).is_err());
+        assert!(parse(r#"{"trace": []}"#, true).is_err());
+        assert!(parse("[[traces]]\ncsv = \"data.csv\"\nx = 0\n", false).is_err());
+    }
+
+    #[test]
+    fn test_parse_unknown_trace_key() {
+        let error = parse(
+            "[[traces]]\ncsv = \"data.csv\"\nx = 0\ny = 1\nline_colr = \"red\"\n",
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error, "unknown field `line_colr` in trace 0");
+        assert!(
+            parse(
+                "[[traces]]\ncsv = \"data.csv\"\nx = 0\ny = 1\ny_axis = 0\n",
+                false
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_keys() {
+        let error = |text: &str| parse(text, false).err().unwrap();
+        assert!(error("[format]\ntitel = \"x\"\n").contains("unknown field `titel`"));
+        assert!(
+            error("[[format.secondary_y_axes]]\nlable = \"x\"\n").contains("unknown field `lable`")
+        );
+        assert!(
+            error("[[annotations]]\ntext = \"a\"\nx = 0.0\ny = 0.0\nfont_colour = \"red\"\n")
+                .contains("unknown field `font_colour`")
+        );
+        assert!(
+            error(
+                "[[shapes]]\nkind = \"line\"\nx0 = 0.0\ny0 = 0.0\nx1 = 1.0\ny1 = 1.0\nwidth = 2\n"
+            )
+            .contains("unknown field `width`")
+        );
+    }
+}
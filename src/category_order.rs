@@ -0,0 +1,68 @@
+use plotly::layout::{Axis, CategoryOrder as PlotlyCategoryOrder};
+
+/// Order of the categories along a categorical axis.
+///
+/// # Example
+///
+/// ```
+/// use plotting::{CategoryOrder, Format, FormatBuilder};
+///
+/// let format: Format = FormatBuilder::default()
+///     .x_category_order(CategoryOrder::Explicit(vec![
+///         String::from("small"),
+///         String::from("medium"),
+///         String::from("large"),
+///     ]))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CategoryOrder {
+    /// Categories are ordered as they are given in the data (in the order in which the traces were
+    /// added to the figure).
+    AsGiven,
+
+    /// Categories are ordered alphabetically.
+    Alphabetical,
+
+    /// Categories are ordered by increasing total value (summed over all traces).
+    TotalAscending,
+
+    /// Categories are ordered by decreasing total value (summed over all traces).
+    TotalDescending,
+
+    /// Categories are ordered explicitly (categories that are not listed are placed after the
+    /// listed categories).
+    Explicit(Vec<String>),
+}
+
+impl CategoryOrder {
+    /// Apply this category order to a plotly axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - Plotly axis.
+    ///
+    /// # Returns
+    ///
+    /// Plotly axis with the category order applied.
+    pub(crate) fn apply_to_plotly(&self, axis: Axis) -> Axis {
+        match self {
+            CategoryOrder::AsGiven => axis.category_order(PlotlyCategoryOrder::Trace),
+            CategoryOrder::Alphabetical => {
+                axis.category_order(PlotlyCategoryOrder::CategoryAscending)
+            }
+            CategoryOrder::TotalAscending => {
+                axis.category_order(PlotlyCategoryOrder::TotalAscending)
+            }
+            CategoryOrder::TotalDescending => {
+                axis.category_order(PlotlyCategoryOrder::TotalDescending)
+            }
+            CategoryOrder::Explicit(categories) => axis
+                .category_order(PlotlyCategoryOrder::Array)
+                .category_array(categories.clone()),
+        }
+    }
+}
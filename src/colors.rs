@@ -15,6 +15,8 @@ use plotly::color::Rgba;
 /// * \[2\] <https://www.w3schools.com/cssref/css_colors.asp>
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[allow(missing_docs)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum NamedColor {
     AliceBlue = 0xF0F8FF,
     AntiqueWhite = 0xFAEBD7,
@@ -189,7 +191,7 @@ pub enum NamedColor {
 /// | ------ | ----------- |
 /// | [`Color::alpha`] | Set the opacity/transparency of the color. |
 /// | [`Color::to_plotly_rgba`] | Convert the color to an [`Rgba`] from the [`plotly`] crate. |
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Color {
     /// Red component.
     r: u8,
@@ -362,6 +364,33 @@ impl Color {
     pub fn to_plotly_rgba(&self) -> Rgba {
         Rgba::new(self.r, self.g, self.b, self.a)
     }
+
+    /// Get the opacity of the color.
+    ///
+    /// # Returns
+    ///
+    /// Alpha (opacity) between 0 (transparent) and 1 (opaque).
+    pub(crate) fn opacity(&self) -> f64 {
+        self.a
+    }
+
+    /// Convert the color to a hexadecimal string (ignoring the opacity).
+    ///
+    /// # Returns
+    ///
+    /// Hexadecimal string (e.g. `"#ff0000"`).
+    pub(crate) fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
+    /// Get the red, green, and blue components of the color (ignoring the opacity).
+    ///
+    /// # Returns
+    ///
+    /// Red, green, and blue components (each between 0 and 255).
+    pub(crate) fn to_rgb(&self) -> (u8, u8, u8) {
+        (self.r, self.g, self.b)
+    }
 }
 
 impl Default for Color {
@@ -385,6 +414,102 @@ impl Default for Color {
     }
 }
 
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::{Color, NamedColor};
+    use serde::de::{Error, IntoDeserializer, value::StrDeserializer};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    impl Color {
+        /// Convert the color to a CSS color string.
+        ///
+        /// # Returns
+        ///
+        /// CSS color string (a hexadecimal string for opaque colors, and an `rgba(...)` string
+        /// otherwise).
+        pub(crate) fn to_css(&self) -> String {
+            if self.a == 1.0 {
+                self.to_hex()
+            } else {
+                format!("rgba({}, {}, {}, {})", self.r, self.g, self.b, self.a)
+            }
+        }
+
+        /// Parse a CSS color string.
+        ///
+        /// # Arguments
+        ///
+        /// * `color` - CSS color string (a hexadecimal string, an `rgb(...)` or `rgba(...)`
+        ///   string, or a named color).
+        ///
+        /// # Returns
+        ///
+        /// Color (`None` if the string could not be parsed).
+        pub(crate) fn from_css(color: &str) -> Option<Color> {
+            let color = color.trim().to_lowercase();
+
+            // Hexadecimal string (`#rgb`, `#rrggbb`, or `#rrggbbaa`).
+            if let Some(hex) = color.strip_prefix('#') {
+                let digits: Vec<u8> = hex
+                    .chars()
+                    .map(|c| c.to_digit(16).map(|digit| digit as u8))
+                    .collect::<Option<_>>()?;
+                return match digits.as_slice() {
+                    [r, g, b] => Some(Color::rgb(r * 17, g * 17, b * 17)),
+                    [r1, r0, g1, g0, b1, b0] => {
+                        Some(Color::rgb(r1 * 16 + r0, g1 * 16 + g0, b1 * 16 + b0))
+                    }
+                    [r1, r0, g1, g0, b1, b0, a1, a0] => Some(Color::rgba(
+                        r1 * 16 + r0,
+                        g1 * 16 + g0,
+                        b1 * 16 + b0,
+                        f64::from(a1 * 16 + a0) / 255.0,
+                    )),
+                    _ => None,
+                };
+            }
+
+            // `rgb(...)` or `rgba(...)` string.
+            if let Some(components) = color
+                .strip_prefix("rgba(")
+                .or_else(|| color.strip_prefix("rgb("))
+                .and_then(|components| components.strip_suffix(')'))
+            {
+                let components: Vec<&str> = components.split(',').map(str::trim).collect();
+                let (rgb, a) = match components.as_slice() {
+                    [r, g, b] => ([r, g, b], 1.0),
+                    [r, g, b, a] => ([r, g, b], a.parse().ok()?),
+                    _ => return None,
+                };
+                let [r, g, b] = rgb.map(|component| component.parse::<u8>().ok());
+                return Some(Color::rgba(r?, g?, b?, a));
+            }
+
+            // Named color.
+            if color == "transparent" {
+                return Some(Color::rgba(0, 0, 0, 0.0));
+            }
+            let deserializer: StrDeserializer<serde::de::value::Error> =
+                color.as_str().into_deserializer();
+            NamedColor::deserialize(deserializer).ok().map(Color::named)
+        }
+    }
+
+    impl Serialize for Color {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&self.to_css())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Color {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let color = String::deserialize(deserializer)?;
+            Color::from_css(&color)
+                .ok_or_else(|| D::Error::custom(format!("invalid CSS color \"{color}\"")))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -574,4 +699,38 @@ mod tests {
     fn test_default() {
         assert_eq!(Color::default(), Color::rgba(0, 0, 0, 1.0));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_css() {
+        // Serialization.
+        assert_eq!(Color::rgb(255, 0, 16).to_css(), "#ff0010");
+        assert_eq!(
+            Color::rgba(20, 30, 40, 0.5).to_css(),
+            "rgba(20, 30, 40, 0.5)"
+        );
+
+        // Deserialization.
+        assert_eq!(Color::from_css("#FF0010"), Some(Color::rgb(255, 0, 16)));
+        assert_eq!(Color::from_css("#f00"), Some(Color::rgb(255, 0, 0)));
+        assert_eq!(
+            Color::from_css("#ff000080"),
+            Some(Color::rgba(255, 0, 0, 128.0 / 255.0))
+        );
+        assert_eq!(
+            Color::from_css("rgb(20, 30, 40)"),
+            Some(Color::rgb(20, 30, 40))
+        );
+        assert_eq!(
+            Color::from_css("rgba(20,30,40,0.5)"),
+            Some(Color::rgba(20, 30, 40, 0.5))
+        );
+        assert_eq!(
+            Color::from_css("SteelBlue"),
+            Some(Color::named(NamedColor::SteelBlue))
+        );
+        assert_eq!(Color::from_css("#ff00"), None);
+        assert_eq!(Color::from_css("rgb(300, 0, 0)"), None);
+        assert_eq!(Color::from_css("not a color"), None);
+    }
 }
@@ -0,0 +1,322 @@
+use crate::axis_data::AxisData;
+use crate::colors::Color;
+use crate::error::Error;
+use crate::trace::Trace;
+use derive_builder::Builder;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Colors assigned to the groups of a color-by column (plotly's default color sequence).
+const GROUP_COLORS: [u32; 10] = [
+    0x636EFA, 0xEF553B, 0x00CC96, 0xAB63FA, 0xFFA15A, 0x19D3F3, 0xFF6692, 0xB6E880, 0xFF97FF,
+    0xFECB52,
+];
+
+/// Reference to a column of tabular data, either by name or by (zero-based) index.
+///
+/// # Example
+///
+/// ```
+/// use plotting::Column;
+///
+/// let by_name = Column::from("speed");
+/// let by_index = Column::from(2);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum Column {
+    /// Column name.
+    Name(String),
+
+    /// Column index (zero-based).
+    Index(usize),
+}
+
+impl From<&str> for Column {
+    fn from(name: &str) -> Self {
+        Column::Name(String::from(name))
+    }
+}
+
+impl From<String> for Column {
+    fn from(name: String) -> Self {
+        Column::Name(name)
+    }
+}
+
+impl From<usize> for Column {
+    fn from(index: usize) -> Self {
+        Column::Index(index)
+    }
+}
+
+impl fmt::Display for Column {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Column::Name(name) => write!(f, "\"{name}\""),
+            Column::Index(index) => write!(f, "#{index}"),
+        }
+    }
+}
+
+/// Columns of tabular data to build traces from.
+///
+/// The x- and y-axis columns (and optionally a z-axis column) contain the data of the traces. If a
+/// color-by and/or group-by column is specified, the rows are split into one trace per distinct
+/// value (or combination of values) of those columns, named after the value(s). Traces that share a
+/// value of the color-by column are drawn in the same color.
+///
+/// # Example
+///
+/// ```
+/// use plotting::{Columns, ColumnsBuilder};
+///
+/// // One trace of "speed" vs. "time" per distinct value in the "vehicle" column.
+/// let columns: Columns = ColumnsBuilder::default()
+///     .x("time")
+///     .y("speed")
+///     .group_by("vehicle")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Builder, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Columns {
+    /// x-axis column.
+    #[builder(setter(into))]
+    pub(crate) x: Column,
+
+    /// y-axis column.
+    #[builder(setter(into))]
+    pub(crate) y: Column,
+
+    /// z-axis column (for 3D traces).
+    #[builder(setter(into, strip_option), default)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) z: Option<Column>,
+
+    /// Column whose values determine the color of each trace.
+    #[builder(setter(into, strip_option), default)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) color_by: Option<Column>,
+
+    /// Column whose values determine the grouping of rows into traces.
+    #[builder(setter(into, strip_option), default)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) group_by: Option<Column>,
+}
+
+/// Tabular data that traces can be built from.
+pub(crate) trait ColumnSource {
+    /// Get the data in a column.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - Column.
+    ///
+    /// # Returns
+    ///
+    /// Data in the column (numeric columns produce numeric data, and all other columns produce
+    /// categorical data).
+    ///
+    /// # Errors
+    ///
+    /// If the column does not exist or cannot be read.
+    fn axis_data(&self, column: &Column) -> Result<AxisData, Error>;
+}
+
+impl Columns {
+    /// Build traces from the columns of tabular data.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Tabular data.
+    ///
+    /// # Returns
+    ///
+    /// Traces.
+    ///
+    /// # Errors
+    ///
+    /// If one of the columns does not exist or cannot be read.
+    pub(crate) fn traces(&self, source: &impl ColumnSource) -> Result<Vec<Trace>, Error> {
+        let x = source.axis_data(&self.x)?;
+        let y = source.axis_data(&self.y)?;
+        let z = self
+            .z
+            .as_ref()
+            .map(|column| source.axis_data(column))
+            .transpose()?;
+        let color_keys = self
+            .color_by
+            .as_ref()
+            .map(|column| source.axis_data(column).map(keys))
+            .transpose()?;
+        let group_keys = self
+            .group_by
+            .as_ref()
+            .map(|column| source.axis_data(column).map(keys))
+            .transpose()?;
+
+        // Without any grouping, all of the rows form a single trace.
+        if color_keys.is_none() && group_keys.is_none() {
+            let trace = match z {
+                Some(z) => Trace::new_3d(x, y, z),
+                None => Trace::new_2d(x, y),
+            };
+            return Ok(vec![trace]);
+        }
+
+        // Split the rows by their (color, group) keys, in order of first appearance.
+        let mut groups: Vec<(Option<&str>, Option<&str>, Vec<usize>)> = Vec::new();
+        let mut group_indices: HashMap<(Option<&str>, Option<&str>), usize> = HashMap::new();
+        for row in 0..x.len() {
+            let color_key = color_keys.as_ref().map(|keys| keys[row].as_str());
+            let group_key = group_keys.as_ref().map(|keys| keys[row].as_str());
+            let index = *group_indices
+                .entry((color_key, group_key))
+                .or_insert_with(|| {
+                    groups.push((color_key, group_key, Vec::new()));
+                    groups.len() - 1
+                });
+            groups[index].2.push(row);
+        }
+
+        // Distinct values of the color-by column, in order of first appearance.
+        let mut colors: Vec<&str> = Vec::new();
+        for color_key in groups.iter().filter_map(|(color, _, _)| *color) {
+            if !colors.contains(&color_key) {
+                colors.push(color_key);
+            }
+        }
+
+        let traces = groups
+            .iter()
+            .map(|(color_key, group_key, rows)| {
+                let x = x.select(rows);
+                let y = y.select(rows);
+                let mut trace = match &z {
+                    Some(z) => Trace::new_3d(x, y, z.select(rows)),
+                    None => Trace::new_2d(x, y),
+                };
+                trace = match (color_key, group_key) {
+                    (Some(color_key), Some(group_key)) => {
+                        trace.name(format!("{color_key}, {group_key}"))
+                    }
+                    (Some(key), None) | (None, Some(key)) => trace.name(*key),
+                    (None, None) => trace,
+                };
+                if let Some(color_key) = color_key {
+                    let index = colors.iter().position(|color| color == color_key).unwrap();
+                    let color = GROUP_COLORS[index % GROUP_COLORS.len()];
+                    trace = trace
+                        .line_color(Color::hex_literal(color))
+                        .marker_color(Color::hex_literal(color));
+                }
+                trace
+            })
+            .collect();
+        Ok(traces)
+    }
+}
+
+/// Convert the data in a color-by or group-by column to keys.
+///
+/// # Arguments
+///
+/// * `data` - Data in the column.
+///
+/// # Returns
+///
+/// Key of each row.
+fn keys(data: AxisData) -> Vec<String> {
+    match data {
+        AxisData::Numeric(data) => data.iter().map(|value| value.to_string()).collect(),
+        AxisData::Categorical(data) => data.to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Table of named columns.
+    struct Table(Vec<(&'static str, AxisData)>);
+
+    impl ColumnSource for Table {
+        fn axis_data(&self, column: &Column) -> Result<AxisData, Error> {
+            let found = match column {
+                Column::Name(name) => self.0.iter().find(|(n, _)| n == name),
+                Column::Index(index) => self.0.get(*index),
+            };
+            found
+                .map(|(_, data)| data.clone())
+                .ok_or_else(|| Error::MissingColumn(column.clone()))
+        }
+    }
+
+    fn table() -> Table {
+        Table(vec![
+            ("t", AxisData::from([0.0, 0.0, 1.0, 1.0])),
+            ("v", AxisData::from([1.0, 2.0, 3.0, 4.0])),
+            ("car", AxisData::from(["a", "b", "a", "b"])),
+            ("run", AxisData::from([1.0, 1.0, 1.0, 2.0])),
+        ])
+    }
+
+    #[test]
+    fn test_single_trace() {
+        let columns = ColumnsBuilder::default().x("t").y(1).build().unwrap();
+        let traces = columns.traces(&table()).unwrap();
+        assert_eq!(traces.len(), 1);
+        assert_eq!(traces[0].x, [0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(traces[0].y, [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(traces[0].name, None);
+    }
+
+    #[test]
+    fn test_group_by() {
+        let columns = ColumnsBuilder::default()
+            .x("t")
+            .y("v")
+            .group_by("car")
+            .build()
+            .unwrap();
+        let traces = columns.traces(&table()).unwrap();
+        assert_eq!(traces.len(), 2);
+        assert_eq!(traces[0].name.as_deref(), Some("a"));
+        assert_eq!(traces[0].y, [1.0, 3.0]);
+        assert_eq!(traces[1].name.as_deref(), Some("b"));
+        assert_eq!(traces[1].y, [2.0, 4.0]);
+        assert_eq!(traces[0].line_color, None);
+    }
+
+    #[test]
+    fn test_color_and_group_by() {
+        let columns = ColumnsBuilder::default()
+            .x("t")
+            .y("v")
+            .color_by("car")
+            .group_by("run")
+            .build()
+            .unwrap();
+        let traces = columns.traces(&table()).unwrap();
+        assert_eq!(traces.len(), 3);
+        assert_eq!(traces[0].name.as_deref(), Some("a, 1"));
+        assert_eq!(traces[1].name.as_deref(), Some("b, 1"));
+        assert_eq!(traces[2].name.as_deref(), Some("b, 2"));
+        assert_eq!(traces[2].y, [4.0]);
+        assert_eq!(traces[1].line_color, traces[2].line_color);
+        assert_ne!(traces[0].line_color, traces[1].line_color);
+    }
+
+    #[test]
+    fn test_missing_column() {
+        let columns = ColumnsBuilder::default().x("t").y("w").build().unwrap();
+        assert!(matches!(
+            columns.traces(&table()),
+            Err(Error::MissingColumn(Column::Name(name))) if name == "w"
+        ));
+    }
+}
@@ -0,0 +1,31 @@
+/// Coordinate system used to position an element (e.g. an annotation or a shape) on a figure.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Coordinates {
+    /// Data coordinates (i.e. positions are specified in the units of the x- and y-axes).
+    #[default]
+    Data,
+
+    /// Paper coordinates (i.e. positions are specified as fractions of the plotting area, where
+    /// `0.0` corresponds to the left/bottom edge and `1.0` corresponds to the right/top edge).
+    Paper,
+}
+
+impl Coordinates {
+    /// Get the plotly axis reference for this coordinate system.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - Name of the axis in data coordinates (e.g. `"x"` or `"y"`).
+    ///
+    /// # Returns
+    ///
+    /// Plotly axis reference.
+    pub(crate) fn to_plotly_ref(self, axis: &str) -> String {
+        match self {
+            Coordinates::Data => axis.to_string(),
+            Coordinates::Paper => String::from("paper"),
+        }
+    }
+}
@@ -0,0 +1,596 @@
+use crate::axis_data::AxisData;
+use crate::columns::{Column, ColumnSource, Columns};
+use crate::error::Error;
+use crate::figure::Figure;
+use crate::format::Format;
+use crate::trace::Trace;
+use derive_builder::Builder;
+use std::path::Path;
+
+/// Options for reading delimited text (e.g. CSV) files.
+///
+/// Fields may be quoted with double quotes (in which case they may contain the delimiter, line
+/// breaks, and escaped double quotes written as `""`). Whitespace around unquoted fields is ignored.
+///
+/// # Examples
+///
+/// ## Default options
+///
+/// Comma-separated values, with the header row detected automatically and no comment lines.
+///
+/// ```
+/// use plotting::CsvOptions;
+///
+/// let options = CsvOptions::default();
+/// ```
+///
+/// ## Custom options
+///
+/// ```
+/// use plotting::{CsvOptions, CsvOptionsBuilder};
+///
+/// // Tab-separated values without a header row, with comment lines starting with '#'.
+/// let options: CsvOptions = CsvOptionsBuilder::default()
+///     .delimiter('\t')
+///     .header(false)
+///     .comment('#')
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Builder, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct CsvOptions {
+    /// Delimiter between fields.
+    ///
+    /// If the delimiter is a space (`' '`), fields are separated by any run of spaces and tabs
+    /// (e.g. for column-aligned text), and leading and trailing whitespace on a line is ignored.
+    #[builder(default = "','")]
+    pub(crate) delimiter: char,
+
+    /// Whether the first row is a header row containing the column names.
+    ///
+    /// If not specified, the first row is treated as a header row if none of its fields are numbers
+    /// but some field of the second row is a number.
+    #[builder(setter(strip_option), default)]
+    pub(crate) header: Option<bool>,
+
+    /// Character that starts a comment line, possibly after leading whitespace (comment lines are
+    /// skipped).
+    #[builder(setter(strip_option), default)]
+    pub(crate) comment: Option<char>,
+
+    /// Values that indicate missing data (in addition to empty fields). Missing values are plotted
+    /// as gaps in numeric columns and as empty strings in categorical columns.
+    #[builder(
+        setter(custom),
+        default = "vec![String::from(\"NA\"), String::from(\"N/A\"), String::from(\"null\")]"
+    )]
+    pub(crate) missing_values: Vec<String>,
+}
+
+impl CsvOptionsBuilder {
+    /// Values that indicate missing data (in addition to empty fields).
+    ///
+    /// # Arguments
+    ///
+    /// * `missing_values` - Values that indicate missing data (defaults to `NA`, `N/A`, and
+    ///   `null`).
+    ///
+    /// # Returns
+    ///
+    /// The builder with the missing values set.
+    pub fn missing_values<S: Into<String>>(
+        &mut self,
+        missing_values: impl IntoIterator<Item = S>,
+    ) -> &mut Self {
+        self.missing_values = Some(missing_values.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptionsBuilder::default().build().unwrap()
+    }
+}
+
+/// Table read from a delimited text (e.g. CSV) file.
+///
+/// Tables are usually read implicitly (see [`Trace::from_csv`] and [`Figure::from_csv`]), but can
+/// also be read explicitly to inspect their columns before building traces.
+///
+/// # Example
+///
+/// ```
+/// use plotting::{Column, CsvOptions, CsvTable, Trace};
+///
+/// let table = CsvTable::parse("time,speed\n0.0,10.0\n1.0,12.5\n", &CsvOptions::default()).unwrap();
+/// assert_eq!(table.column_count(), 2);
+/// assert_eq!(table.row_count(), 2);
+///
+/// let speed = table.axis_data(&Column::from("speed")).unwrap();
+/// let trace = Trace::new_2d(table.axis_data(&Column::from(0)).unwrap(), speed);
+/// ```
+pub struct CsvTable {
+    /// Column names (if the file has a header row).
+    header: Option<Vec<String>>,
+
+    /// Rows of fields (with missing values replaced by `None`).
+    rows: Vec<Vec<Option<String>>>,
+}
+
+impl CsvTable {
+    /// Read a table from a delimited text file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the file.
+    /// * `options` - Options for reading the file.
+    ///
+    /// # Returns
+    ///
+    /// Table.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read or is malformed.
+    pub fn read(path: &Path, options: &CsvOptions) -> Result<CsvTable, Error> {
+        CsvTable::parse(&std::fs::read_to_string(path)?, options)
+    }
+
+    /// Parse a table from delimited text.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Delimited text.
+    /// * `options` - Options for parsing the text.
+    ///
+    /// # Returns
+    ///
+    /// Table.
+    ///
+    /// # Errors
+    ///
+    /// If the text is malformed (i.e. contains an unterminated quoted field).
+    pub fn parse(text: &str, options: &CsvOptions) -> Result<CsvTable, Error> {
+        let mut records = parse_records(text, options)?;
+        let is_header = options.header.unwrap_or_else(|| {
+            let is_number = |field: &String| field.parse::<f64>().is_ok();
+            match (records.first(), records.get(1)) {
+                (Some(first), Some(second)) => {
+                    !first.iter().any(is_number) && second.iter().any(is_number)
+                }
+                _ => false,
+            }
+        });
+        let header = (is_header && !records.is_empty()).then(|| records.remove(0));
+        let rows = records
+            .into_iter()
+            .map(|record| {
+                record
+                    .into_iter()
+                    .map(|field| {
+                        (!field.is_empty() && !options.missing_values.contains(&field))
+                            .then_some(field)
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(CsvTable { header, rows })
+    }
+
+    /// Get the number of columns.
+    ///
+    /// # Returns
+    ///
+    /// Number of columns (the number of fields of the header row if the table has one, and the
+    /// largest number of fields of a row otherwise).
+    pub fn column_count(&self) -> usize {
+        match &self.header {
+            Some(header) => header.len(),
+            None => self.rows.iter().map(Vec::len).max().unwrap_or(0),
+        }
+    }
+
+    /// Get the number of rows.
+    ///
+    /// # Returns
+    ///
+    /// Number of rows (not counting the header row).
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Get the name of a column.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - Column.
+    ///
+    /// # Returns
+    ///
+    /// Column name (`None` if the column is specified by index and the table has no header row).
+    pub fn column_name(&self, column: &Column) -> Option<String> {
+        match column {
+            Column::Name(name) => Some(name.clone()),
+            Column::Index(index) => self.header.as_ref()?.get(*index).cloned(),
+        }
+    }
+
+    /// Get the data in a column.
+    ///
+    /// The column is numeric if all of its (non-missing) fields are numbers, and categorical
+    /// otherwise. Missing fields (and the fields of rows that are too short) are `NaN` in numeric
+    /// columns and empty strings in categorical columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - Column (columns can only be specified by name if the table has a header row).
+    ///
+    /// # Returns
+    ///
+    /// Data in the column.
+    ///
+    /// # Errors
+    ///
+    /// If the column does not exist.
+    pub fn axis_data(&self, column: &Column) -> Result<AxisData, Error> {
+        let index = match column {
+            Column::Name(name) => self
+                .header
+                .as_ref()
+                .and_then(|header| header.iter().position(|field| field == name)),
+            Column::Index(index) => (*index < self.column_count()).then_some(*index),
+        }
+        .ok_or_else(|| Error::MissingColumn(column.clone()))?;
+
+        // Fields of the column (rows with too few fields are treated as missing values).
+        let fields: Vec<Option<&str>> = self
+            .rows
+            .iter()
+            .map(|row| row.get(index).and_then(|field| field.as_deref()))
+            .collect();
+
+        // The column is numeric if all of its (non-missing) fields are numbers.
+        let numbers: Option<Vec<f64>> = fields
+            .iter()
+            .map(|field| match field {
+                Some(field) => field.parse().ok(),
+                None => Some(f64::NAN),
+            })
+            .collect();
+        Ok(match numbers {
+            Some(numbers) => AxisData::Numeric(numbers.into()),
+            None => AxisData::Categorical(
+                fields
+                    .iter()
+                    .map(|field| String::from(field.unwrap_or_default()))
+                    .collect(),
+            ),
+        })
+    }
+}
+
+impl ColumnSource for CsvTable {
+    fn axis_data(&self, column: &Column) -> Result<AxisData, Error> {
+        CsvTable::axis_data(self, column)
+    }
+}
+
+/// Parse delimited text into records of fields.
+///
+/// # Arguments
+///
+/// * `text` - Delimited text.
+/// * `options` - Options for parsing the text.
+///
+/// # Returns
+///
+/// Records (empty lines and comment lines are skipped).
+///
+/// # Errors
+///
+/// If the text contains an unterminated quoted field.
+fn parse_records(text: &str, options: &CsvOptions) -> Result<Vec<Vec<String>>, Error> {
+    let mut records = Vec::new();
+    let mut record: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut line = 1;
+    let mut chars = text.chars().peekable();
+
+    // Whether fields are separated by runs of whitespace.
+    let whitespace = options.delimiter == ' ';
+
+    // Finish the current field (trimming unquoted fields).
+    let finish_field = |field: &mut String, record: &mut Vec<String>, quoted: bool| {
+        let value = std::mem::take(field);
+        record.push(if quoted {
+            value
+        } else {
+            String::from(value.trim())
+        });
+    };
+
+    while let Some(c) = chars.next() {
+        // Skip comment lines (possibly indented).
+        if record.is_empty() && field.trim().is_empty() && !quoted && Some(c) == options.comment {
+            for c in chars.by_ref() {
+                if c == '\n' {
+                    break;
+                }
+            }
+            line += 1;
+            continue;
+        }
+
+        if c == '"' && field.trim().is_empty() && !quoted {
+            // Start of a quoted field.
+            field.clear();
+            quoted = true;
+            let quote_line = line;
+            loop {
+                match chars.next() {
+                    Some('"') if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    Some('"') => break,
+                    Some(c) => {
+                        if c == '\n' {
+                            line += 1;
+                        }
+                        field.push(c);
+                    }
+                    None => {
+                        return Err(Error::Csv {
+                            line: quote_line,
+                            message: String::from("unterminated quoted field"),
+                        });
+                    }
+                }
+            }
+        } else if c == options.delimiter || (whitespace && c == '\t') {
+            // Runs of whitespace delimiters separate a single pair of fields.
+            if !whitespace || quoted || !field.trim().is_empty() {
+                finish_field(&mut field, &mut record, quoted);
+            }
+            field.clear();
+            quoted = false;
+        } else if c == '\n' || c == '\r' {
+            if c == '\r' && chars.peek() == Some(&'\n') {
+                chars.next();
+            }
+            line += 1;
+            if quoted || !field.trim().is_empty() || (!whitespace && !record.is_empty()) {
+                finish_field(&mut field, &mut record, quoted);
+            }
+            if !record.is_empty() {
+                records.push(std::mem::take(&mut record));
+            }
+            field.clear();
+            quoted = false;
+        } else if !quoted {
+            field.push(c);
+        }
+    }
+    if quoted || !field.trim().is_empty() || (!whitespace && !record.is_empty()) {
+        finish_field(&mut field, &mut record, quoted);
+    }
+    if !record.is_empty() {
+        records.push(record);
+    }
+    Ok(records)
+}
+
+impl Trace {
+    /// Constructor for a set of traces from the columns of a delimited text (e.g. CSV) file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the file.
+    /// * `columns` - Columns to build the traces from (see [`Columns`]). Columns can only be
+    ///   specified by name if the file has a header row.
+    /// * `options` - Options for reading the file (see [`CsvOptions`]).
+    ///
+    /// # Returns
+    ///
+    /// Traces (one per group if a color-by and/or group-by column is specified, otherwise a single
+    /// trace).
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read or is malformed, or if one of the columns does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{ColumnsBuilder, CsvOptions, Trace};
+    ///
+    /// let path = std::env::temp_dir().join("plotting_trace_from_csv.csv");
+    /// std::fs::write(&path, "time,speed\n0.0,10.0\n1.0,12.5\n2.0,NA\n").unwrap();
+    ///
+    /// let columns = ColumnsBuilder::default().x("time").y("speed").build().unwrap();
+    /// let traces = Trace::from_csv(&path, &columns, &CsvOptions::default()).unwrap();
+    /// ```
+    pub fn from_csv<P: AsRef<Path>>(
+        path: P,
+        columns: &Columns,
+        options: &CsvOptions,
+    ) -> Result<Vec<Trace>, Error> {
+        columns.traces(&CsvTable::read(path.as_ref(), options)?)
+    }
+}
+
+impl Figure {
+    /// Create a figure from the columns of a delimited text (e.g. CSV) file.
+    ///
+    /// If the file has a header row, the names of the x-, y-, and z-axis columns are used as axis
+    /// labels (unless axis labels are specified in `format`).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the file.
+    /// * `columns` - Columns to build the traces from (see [`Columns`]). Columns can only be
+    ///   specified by name if the file has a header row.
+    /// * `options` - Options for reading the file (see [`CsvOptions`]).
+    /// * `format` - Formatting.
+    ///
+    /// # Returns
+    ///
+    /// Figure.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read or is malformed, or if one of the columns does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{ColumnsBuilder, CsvOptionsBuilder, Figure, Format};
+    ///
+    /// let path = std::env::temp_dir().join("plotting_figure_from_csv.csv");
+    /// std::fs::write(&path, "# Simulation output\nt;x;run\n0;1.0;a\n1;2.0;a\n0;1.5;b\n1;2.5;b\n")
+    ///     .unwrap();
+    ///
+    /// let columns = ColumnsBuilder::default()
+    ///     .x(0)
+    ///     .y(1)
+    ///     .group_by("run")
+    ///     .build()
+    ///     .unwrap();
+    /// let options = CsvOptionsBuilder::default()
+    ///     .delimiter(';')
+    ///     .comment('#')
+    ///     .build()
+    ///     .unwrap();
+    /// let fig = Figure::from_csv(&path, &columns, &options, Format::default()).unwrap();
+    /// fig.save_html("folder/file.html");
+    /// ```
+    pub fn from_csv<P: AsRef<Path>>(
+        path: P,
+        columns: &Columns,
+        options: &CsvOptions,
+        mut format: Format,
+    ) -> Result<Figure, Error> {
+        let table = CsvTable::read(path.as_ref(), options)?;
+        let traces = columns.traces(&table)?;
+        if table.header.is_some() {
+            if format.x_label.is_none() {
+                format.x_label = table.column_name(&columns.x);
+            }
+            if format.y_label.is_none() {
+                format.y_label = table.column_name(&columns.y);
+            }
+            if format.z_label.is_none() {
+                format.z_label = columns.z.as_ref().and_then(|z| table.column_name(z));
+            }
+        }
+        Ok(Figure::new(traces, format))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::columns::ColumnsBuilder;
+
+    #[test]
+    fn test_header_detection() {
+        let options = CsvOptions::default();
+        let table = CsvTable::parse("a,b\n1,2\n", &options).unwrap();
+        assert_eq!(
+            table.header,
+            Some(vec![String::from("a"), String::from("b")])
+        );
+        let table = CsvTable::parse("1,2\n3,4\n", &options).unwrap();
+        assert_eq!(table.header, None);
+        assert_eq!(table.rows.len(), 2);
+        let table = CsvTable::parse("a,b\nc,d\n", &options).unwrap();
+        assert_eq!(table.header, None);
+    }
+
+    #[test]
+    fn test_parse() {
+        let options: CsvOptions = CsvOptionsBuilder::default()
+            .delimiter(';')
+            .comment('%')
+            .missing_values(["-"])
+            .build()
+            .unwrap();
+        let text = "% comment\r\nname; value\r\n\r\n\"a; \"\"b\"\"\" ; 1.5\r\n% comment\r\n\"c\nd\";-\r\n;2";
+        let table = CsvTable::parse(text, &options).unwrap();
+        assert_eq!(
+            table.header,
+            Some(vec![String::from("name"), String::from("value")])
+        );
+        assert_eq!(
+            table.axis_data(&Column::from("name")).unwrap(),
+            ["a; \"b\"", "c\nd", ""]
+        );
+        let value = table.axis_data(&Column::from(1)).unwrap();
+        let value = value.as_numeric().unwrap();
+        assert_eq!(value[0], 1.5);
+        assert!(value[1].is_nan());
+        assert_eq!(value[2], 2.0);
+    }
+
+    #[test]
+    fn test_indented_comments() {
+        let options: CsvOptions = CsvOptionsBuilder::default().comment('#').build().unwrap();
+        let text = "x,y\n  # comment\n1,2\n\t# comment\n3,4\n";
+        let table = CsvTable::parse(text, &options).unwrap();
+        assert_eq!(table.row_count(), 2);
+        assert_eq!(table.axis_data(&Column::from("x")).unwrap(), [1.0, 3.0]);
+    }
+
+    #[test]
+    fn test_whitespace_delimiter() {
+        let options: CsvOptions = CsvOptionsBuilder::default()
+            .delimiter(' ')
+            .comment('#')
+            .build()
+            .unwrap();
+        let text = "  # comment\n t \t speed \n0  1.5\n\n1\tNA  \n\"a b\" 3\n";
+        let table = CsvTable::parse(text, &options).unwrap();
+        assert_eq!(
+            table.header,
+            Some(vec![String::from("t"), String::from("speed")])
+        );
+        assert_eq!(table.column_count(), 2);
+        assert_eq!(table.row_count(), 3);
+        assert_eq!(
+            table.axis_data(&Column::from("t")).unwrap(),
+            ["0", "1", "a b"]
+        );
+        let speed = table.axis_data(&Column::from(1)).unwrap();
+        let speed = speed.as_numeric().unwrap();
+        assert_eq!(speed[0], 1.5);
+        assert!(speed[1].is_nan());
+        assert_eq!(speed[2], 3.0);
+    }
+
+    #[test]
+    fn test_unterminated_quote() {
+        let result = CsvTable::parse("a,b\n1,\"2\n", &CsvOptions::default());
+        assert!(matches!(result, Err(Error::Csv { line: 2, .. })));
+    }
+
+    #[test]
+    fn test_missing_column() {
+        let table = CsvTable::parse("1,2\n3,4\n", &CsvOptions::default()).unwrap();
+        assert!(matches!(
+            table.axis_data(&Column::from("a")),
+            Err(Error::MissingColumn(_))
+        ));
+        assert!(matches!(
+            table.axis_data(&Column::from(2)),
+            Err(Error::MissingColumn(_))
+        ));
+        let columns = ColumnsBuilder::default().x(0).y(1).build().unwrap();
+        let traces = columns.traces(&table).unwrap();
+        assert_eq!(traces[0].x, [1.0, 3.0]);
+        assert_eq!(traces[0].y, [2.0, 4.0]);
+    }
+}
@@ -0,0 +1,163 @@
+use crate::axis_data::AxisData;
+
+/// Source of data along a single axis of a trace.
+///
+/// This trait is implemented for [`AxisData`] (and references to it, which share the data rather
+/// than copying it) and for anything that can be iterated over to produce elements implementing
+/// [`DataElement`] (i.e. numbers of any primitive numeric type, or strings). This includes:
+///
+/// * arrays, vectors, and slices (e.g. `[f64; N]`, `Vec<i32>`, `&[f32]`),
+/// * iterators (e.g. `(0..10).map(|i| i * i)`),
+/// * [`ndarray`](https://docs.rs/ndarray) arrays and views (e.g. `Array1<f64>` or `&Array1<f64>`),
+/// * [`nalgebra`](https://docs.rs/nalgebra) vectors by reference (e.g. `&DVector<f64>`).
+///
+/// Numeric data is converted to `f64` and string data is treated as categorical data.
+///
+/// # Example
+///
+/// ```
+/// use plotting::Trace;
+///
+/// // Integer x-axis data from an iterator and single-precision y-axis data from a slice.
+/// let y: Vec<f32> = vec![0.0, 1.0, 4.0, 9.0];
+/// let trace = Trace::new_2d(0..4, &y[..]);
+/// ```
+pub trait DataSource {
+    /// Convert the data source to axis data.
+    ///
+    /// # Returns
+    ///
+    /// Axis data.
+    fn into_axis_data(self) -> AxisData;
+}
+
+impl DataSource for AxisData {
+    fn into_axis_data(self) -> AxisData {
+        self
+    }
+}
+
+impl DataSource for &AxisData {
+    fn into_axis_data(self) -> AxisData {
+        self.clone()
+    }
+}
+
+impl<I> DataSource for I
+where
+    I: IntoIterator,
+    I::Item: DataElement,
+{
+    fn into_axis_data(self) -> AxisData {
+        I::Item::collect_axis_data(self.into_iter())
+    }
+}
+
+/// Single element of a [`DataSource`].
+///
+/// This trait is implemented for all primitive numeric types (which produce numeric axis data),
+/// for [`String`] and [`str`] (which produce categorical axis data), and for references to any of
+/// these types.
+pub trait DataElement {
+    /// Collect elements into axis data.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Elements to collect.
+    ///
+    /// # Returns
+    ///
+    /// Axis data.
+    fn collect_axis_data(elements: impl Iterator<Item = Self>) -> AxisData
+    where
+        Self: Sized;
+}
+
+/// Implement [`DataElement`] for primitive numeric types (and references to them).
+macro_rules! impl_numeric_data_element {
+    ($($t:ty),*) => {
+        $(
+            impl DataElement for $t {
+                fn collect_axis_data(elements: impl Iterator<Item = Self>) -> AxisData {
+                    AxisData::Numeric(elements.map(|element| element as f64).collect())
+                }
+            }
+
+            impl DataElement for &$t {
+                fn collect_axis_data(elements: impl Iterator<Item = Self>) -> AxisData {
+                    AxisData::Numeric(elements.map(|element| *element as f64).collect())
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_data_element!(
+    f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+impl DataElement for String {
+    fn collect_axis_data(elements: impl Iterator<Item = Self>) -> AxisData {
+        AxisData::Categorical(elements.collect())
+    }
+}
+
+impl DataElement for &String {
+    fn collect_axis_data(elements: impl Iterator<Item = Self>) -> AxisData {
+        AxisData::Categorical(elements.cloned().collect())
+    }
+}
+
+impl DataElement for &str {
+    fn collect_axis_data(elements: impl Iterator<Item = Self>) -> AxisData {
+        AxisData::Categorical(elements.map(String::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_numeric() {
+        let expected = AxisData::from([1.0, 2.0, 3.0]);
+        assert_eq!([1.0, 2.0, 3.0].into_axis_data(), expected);
+        assert_eq!(vec![1.0_f32, 2.0, 3.0].into_axis_data(), expected);
+        assert_eq!((&[1_i32, 2, 3][..]).into_axis_data(), expected);
+        assert_eq!((&vec![1_u8, 2, 3]).into_axis_data(), expected);
+        assert_eq!((1_usize..4).into_axis_data(), expected);
+        assert_eq!(
+            [2_i64, 4, 6].iter().map(|i| i / 2).into_axis_data(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_categorical() {
+        let expected = AxisData::from(["a", "b"]);
+        assert_eq!(["a", "b"].into_axis_data(), expected);
+        assert_eq!(
+            vec![String::from("a"), String::from("b")].into_axis_data(),
+            expected
+        );
+        assert_eq!(
+            (&vec![String::from("a"), String::from("b")]).into_axis_data(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_axis_data() {
+        let data = AxisData::from([1.0, 2.0]);
+        assert_eq!(data.clone().into_axis_data(), data);
+
+        // References share the data rather than copying it.
+        let (AxisData::Numeric(shared), AxisData::Numeric(original)) =
+            ((&data).into_axis_data(), &data)
+        else {
+            unreachable!();
+        };
+        assert!(Arc::ptr_eq(&shared, original));
+    }
+}
@@ -0,0 +1,396 @@
+/// Downsampling strategy.
+///
+/// Traces with a very large number of points produce large files that are slow to render (or
+/// that cannot be opened at all). A downsampling strategy reduces the number of points that are
+/// rendered, while keeping the overall shape of the trace. Downsampling is opt-in and is applied
+/// at render time (the data stored in the trace itself is left untouched). It can be configured
+/// for a single trace (see [`crate::Trace::downsampling`]) or for all traces of a figure (see
+/// [`crate::FormatBuilder::downsampling`]).
+///
+/// Each strategy takes the maximum number of points to render. Traces that do not have more
+/// points than this are never downsampled.
+///
+/// # Note
+///
+/// * Box plots are never downsampled, since doing so would change their statistics.
+/// * 3D traces, traces with categorical y-axis data, and traces in a stack group are always
+///   downsampled using [`Downsampling::Stride`] (so that the traces in a stack group remain
+///   aligned with one another).
+///
+/// # Example
+///
+/// ```
+/// use plotting::{Downsampling, Trace};
+///
+/// let x: Vec<f64> = (0..1_000_000).map(|i| i as f64 * 1e-3).collect();
+/// let y: Vec<f64> = x.iter().map(|x| x.sin()).collect();
+///
+/// // Render at most 2000 points.
+/// let trace = Trace::new_2d(x, y).downsampling(Downsampling::Lttb(2000));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Downsampling {
+    /// Largest-Triangle-Three-Buckets (LTTB).
+    ///
+    /// The points are divided into buckets, and from each bucket, the point forming the largest
+    /// triangle with the points selected from the neighboring buckets is kept. This preserves the
+    /// visual shape of the trace (including its peaks) well. If the trace has non-finite (e.g.
+    /// `NaN`) points, half as many buckets are used, and the first non-finite point in each bucket
+    /// is kept as well, so that gaps in the trace are preserved.
+    Lttb(usize),
+
+    /// Minimum and maximum per bucket.
+    ///
+    /// The points are divided into buckets of equal size (one bucket for every two points to
+    /// render), and the points with the minimum and maximum y-values in each bucket are kept.
+    /// This preserves every peak of the trace, making it well suited for noisy signals. If the
+    /// trace has non-finite (e.g. `NaN`) points, one bucket is used for every three points to
+    /// render instead, and the first non-finite point in each bucket is kept as well, so that gaps
+    /// in the trace are preserved.
+    MinMax(usize),
+
+    /// Uniform stride.
+    ///
+    /// Every n-th point is kept (where n is chosen so that at most the given number of points is
+    /// rendered). This is the fastest strategy, but peaks between the kept points are lost.
+    Stride(usize),
+}
+
+impl Downsampling {
+    /// Maximum number of points to render.
+    ///
+    /// # Returns
+    ///
+    /// Maximum number of points to render.
+    pub(crate) fn max_points(self) -> usize {
+        match self {
+            Downsampling::Lttb(max_points)
+            | Downsampling::MinMax(max_points)
+            | Downsampling::Stride(max_points) => max_points,
+        }
+    }
+
+    /// Select the points to render.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Numeric x-axis data (`None` if the x-axis data is not numeric, in which case the
+    ///   indices of the points are used instead).
+    /// * `y` - Numeric y-axis data (`None` if the y-axis data is not numeric, in which case
+    ///   [`Downsampling::Stride`] is used).
+    /// * `len` - Number of points.
+    ///
+    /// # Returns
+    ///
+    /// Indices (in increasing order) of the points to render (`None` if the trace does not need
+    /// to be downsampled).
+    ///
+    /// # Panics
+    ///
+    /// If `x` or `y` does not have exactly `len` points.
+    pub(crate) fn indices(
+        self,
+        x: Option<&[f64]>,
+        y: Option<&[f64]>,
+        len: usize,
+    ) -> Option<Vec<usize>> {
+        let max_points = self.max_points().max(2);
+        if len <= max_points {
+            return None;
+        }
+        assert!(x.is_none_or(|x| x.len() == len) && y.is_none_or(|y| y.len() == len));
+        let indices = match (self, y) {
+            (Downsampling::Lttb(_), Some(y)) => lttb(x, y, max_points),
+            (Downsampling::MinMax(_), Some(y)) => min_max(y, max_points),
+            _ => stride(len, max_points),
+        };
+        Some(indices)
+    }
+}
+
+/// Select points using a uniform stride.
+///
+/// # Arguments
+///
+/// * `len` - Number of points.
+/// * `max_points` - Maximum number of points to select (at least 2).
+///
+/// # Returns
+///
+/// Indices of the selected points (always including the first and last points).
+fn stride(len: usize, max_points: usize) -> Vec<usize> {
+    let step = (len - 1).div_ceil(max_points - 1);
+    let mut indices: Vec<usize> = (0..len).step_by(step).collect();
+    if indices.last() != Some(&(len - 1)) {
+        indices.push(len - 1);
+    }
+    indices
+}
+
+/// Check whether data has gaps.
+///
+/// # Arguments
+///
+/// * `x` - x-axis data (`None` if the indices of the points are used).
+/// * `y` - y-axis data.
+///
+/// # Returns
+///
+/// `true` if any point is non-finite, `false` otherwise.
+fn has_gaps(x: Option<&[f64]>, y: &[f64]) -> bool {
+    y.iter()
+        .chain(x.unwrap_or_default())
+        .any(|value| !value.is_finite())
+}
+
+/// Select points using the minimum and maximum of each bucket.
+///
+/// # Arguments
+///
+/// * `y` - y-axis data.
+/// * `max_points` - Maximum number of points to select (at least 2).
+///
+/// # Returns
+///
+/// Indices of the selected points.
+///
+/// # Note
+///
+/// If the data has gaps, the first non-finite point of each bucket is also kept, so that the gaps
+/// are preserved (in which case fewer buckets are used, so that at most `max_points` points are
+/// selected).
+fn min_max(y: &[f64], max_points: usize) -> Vec<usize> {
+    let buckets = if has_gaps(None, y) {
+        (max_points / 3).max(1)
+    } else {
+        max_points / 2
+    };
+    let mut indices = Vec::with_capacity(max_points);
+    for bucket in 0..buckets {
+        let start = bucket * y.len() / buckets;
+        let end = (bucket + 1) * y.len() / buckets;
+        let mut min: Option<usize> = None;
+        let mut max: Option<usize> = None;
+        let mut gap: Option<usize> = None;
+        for (index, value) in y.iter().enumerate().take(end).skip(start) {
+            if !value.is_finite() {
+                gap = gap.or(Some(index));
+                continue;
+            }
+            if min.is_none_or(|min| *value < y[min]) {
+                min = Some(index);
+            }
+            if max.is_none_or(|max| *value > y[max]) {
+                max = Some(index);
+            }
+        }
+        // With only two points to select, a bucket with a gap keeps its gap instead of its
+        // maximum.
+        let max = max.filter(|_| gap.is_none() || max_points >= 3);
+        let mut selected: Vec<usize> = [min, max, gap].into_iter().flatten().collect();
+        selected.sort_unstable();
+        selected.dedup();
+        indices.extend(selected);
+    }
+    indices
+}
+
+/// Select points using the Largest-Triangle-Three-Buckets (LTTB) algorithm.
+///
+/// # Arguments
+///
+/// * `x` - x-axis data (`None` to use the indices of the points).
+/// * `y` - y-axis data.
+/// * `max_points` - Maximum number of points to select (at least 2).
+///
+/// # Returns
+///
+/// Indices of the selected points (always including the first and last points).
+///
+/// # Note
+///
+/// If the data has gaps, the first non-finite point of each bucket is also kept, so that the gaps
+/// are preserved (in which case half as many buckets are used, so that at most `max_points`
+/// points are selected).
+///
+/// # References
+///
+/// * \[1\] Steinarsson, "Downsampling Time Series for Visual Representation", Master's thesis,
+///   University of Iceland (2013).
+fn lttb(x: Option<&[f64]>, y: &[f64], max_points: usize) -> Vec<usize> {
+    let len = y.len();
+    let point = |index: usize| {
+        let x = x.map_or(index as f64, |x| x[index]);
+        (x, y[index])
+    };
+    let is_finite = |(x, y): (f64, f64)| x.is_finite() && y.is_finite();
+
+    // The first and last points are always kept, and the remaining points are divided into
+    // buckets, from each of which a single point (and, if the data has gaps, the first
+    // non-finite point) is kept.
+    let buckets = if has_gaps(x, y) {
+        (max_points - 2) / 2
+    } else {
+        max_points - 2
+    };
+    if buckets == 0 {
+        return vec![0, len - 1];
+    }
+    let bucket_start = |bucket: usize| 1 + bucket * (len - 2) / buckets;
+    let mut indices = Vec::with_capacity(max_points);
+    indices.push(0);
+    let mut previous = 0;
+    for bucket in 0..buckets {
+        // Average of the finite points of the next bucket (or the last point, for the last
+        // bucket).
+        let (next_start, next_end) = if bucket + 1 < buckets {
+            (bucket_start(bucket + 1), bucket_start(bucket + 2))
+        } else {
+            (len - 1, len)
+        };
+        let (mut sum_x, mut sum_y, mut count) = (0.0, 0.0, 0.0);
+        for (x, y) in (next_start..next_end).map(point).filter(|p| is_finite(*p)) {
+            sum_x += x;
+            sum_y += y;
+            count += 1.0;
+        }
+        let average = (count > 0.0).then(|| (sum_x / count, sum_y / count));
+
+        // Point of the current bucket forming the largest triangle with the previously selected
+        // point and the average of the next bucket (or, if the next bucket has no finite points,
+        // the point furthest from the previously selected point in y).
+        let (previous_x, previous_y) = point(previous);
+        let mut selected: Option<usize> = None;
+        let mut gap: Option<usize> = None;
+        let mut largest_area = f64::NEG_INFINITY;
+        for index in bucket_start(bucket)..bucket_start(bucket + 1) {
+            let (x, y) = point(index);
+            if !is_finite((x, y)) {
+                gap = gap.or(Some(index));
+                continue;
+            }
+            let area = match average {
+                Some(average) => ((previous_x - average.0) * (y - previous_y)
+                    - (previous_x - x) * (average.1 - previous_y))
+                    .abs(),
+                None => (y - previous_y).abs(),
+            };
+            // (If the previously selected point is non-finite, which only happens for the first
+            // point, the area is undefined, and the first finite point is selected.)
+            if selected.is_none() || area > largest_area {
+                largest_area = area;
+                selected = Some(index);
+            }
+        }
+        let mut bucket_indices: Vec<usize> = [selected, gap].into_iter().flatten().collect();
+        bucket_indices.sort_unstable();
+        indices.extend(bucket_indices);
+        if let Some(selected) = selected {
+            previous = selected;
+        }
+    }
+    indices.push(len - 1);
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_downsampling() {
+        let y = [1.0, 2.0, 3.0];
+        assert_eq!(Downsampling::Lttb(3).indices(None, Some(&y), 3), None);
+        assert_eq!(Downsampling::MinMax(10).indices(None, Some(&y), 3), None);
+        assert_eq!(Downsampling::Stride(5).indices(None, None, 3), None);
+    }
+
+    #[test]
+    fn test_stride() {
+        assert_eq!(
+            Downsampling::Stride(4).indices(None, None, 10),
+            Some(vec![0, 3, 6, 9])
+        );
+        assert_eq!(
+            Downsampling::Stride(3).indices(None, None, 10),
+            Some(vec![0, 5, 9])
+        );
+    }
+
+    #[test]
+    fn test_min_max_preserves_peaks() {
+        let mut y = vec![0.0; 1000];
+        y[123] = 10.0;
+        y[456] = -10.0;
+        y[789] = f64::NAN;
+        let indices = Downsampling::MinMax(20)
+            .indices(None, Some(&y), y.len())
+            .unwrap();
+        assert!(indices.len() <= 20);
+        assert!(indices.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(indices.contains(&123));
+        assert!(indices.contains(&456));
+        assert!(indices.contains(&789));
+    }
+
+    #[test]
+    fn test_lttb() {
+        let x: Vec<f64> = (0..1000).map(|i| i as f64).collect();
+        let mut y = vec![0.0; 1000];
+        y[500] = 100.0;
+        let indices = Downsampling::Lttb(50)
+            .indices(Some(&x), Some(&y), y.len())
+            .unwrap();
+        assert_eq!(indices.len(), 50);
+        assert_eq!(indices[0], 0);
+        assert_eq!(indices[49], 999);
+        assert!(indices.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(indices.contains(&500));
+    }
+
+    #[test]
+    fn test_lttb_preserves_gaps() {
+        let x: Vec<f64> = (0..1000).map(|i| i as f64).collect();
+        let mut y: Vec<f64> = x.iter().map(|x| (x * 0.1).sin()).collect();
+        y[400..420].fill(f64::NAN);
+        let indices = Downsampling::Lttb(50)
+            .indices(Some(&x), Some(&y), y.len())
+            .unwrap();
+        assert!(indices.len() <= 50);
+        assert!(indices.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(indices.iter().any(|index| y[*index].is_nan()));
+    }
+
+    #[test]
+    fn test_lttb_non_finite_bucket() {
+        // The bucket following the first bucket is entirely non-finite.
+        let mut y = vec![0.0; 10];
+        y[2] = 5.0;
+        y[3] = f64::NAN;
+        y[4] = f64::NAN;
+        let indices = Downsampling::Lttb(6)
+            .indices(None, Some(&y), y.len())
+            .unwrap();
+        assert!(indices.len() <= 6);
+        assert!(indices.contains(&2));
+        assert!(indices.contains(&3));
+    }
+
+    #[test]
+    fn test_max_points() {
+        let mut y: Vec<f64> = (0..1000).map(|i| (i as f64).sin()).collect();
+        for max_points in 2..40 {
+            for downsampling in [
+                Downsampling::Lttb(max_points),
+                Downsampling::MinMax(max_points),
+                Downsampling::Stride(max_points),
+            ] {
+                let indices = downsampling.indices(None, Some(&y), y.len()).unwrap();
+                assert!(indices.len() <= max_points, "{downsampling:?}");
+            }
+            y[max_points * 20] = f64::NAN;
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use crate::columns::Column;
+use std::fmt;
+
+/// Error that can occur when loading data to plot.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// A column could not be found.
+    MissingColumn(Column),
+
+    /// I/O error (e.g. when reading a file).
+    Io(std::io::Error),
+
+    /// Malformed delimited text (e.g. CSV) file.
+    Csv {
+        /// Line number (one-based) at which the error occurred.
+        line: usize,
+
+        /// Description of the error.
+        message: String,
+    },
+
+    /// Malformed JSON.
+    #[cfg(feature = "serde")]
+    Json(serde_json::Error),
+
+    /// Invalid or unsupported plotly.js figure specification.
+    #[cfg(feature = "serde")]
+    PlotlyJson(String),
+
+    /// Error from the [`polars`](https://docs.rs/polars) crate.
+    #[cfg(feature = "polars")]
+    Polars(polars::error::PolarsError),
+
+    /// Error from the [`arrow`](https://docs.rs/arrow) crates.
+    #[cfg(feature = "arrow")]
+    Arrow(arrow_schema::ArrowError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingColumn(column) => write!(f, "column {column} not found"),
+            Error::Io(error) => write!(f, "I/O error: {error}"),
+            Error::Csv { line, message } => write!(f, "malformed CSV on line {line}: {message}"),
+            #[cfg(feature = "serde")]
+            Error::Json(error) => write!(f, "malformed JSON: {error}"),
+            #[cfg(feature = "serde")]
+            Error::PlotlyJson(message) => write!(f, "invalid plotly figure: {message}"),
+            #[cfg(feature = "polars")]
+            Error::Polars(error) => write!(f, "polars error: {error}"),
+            #[cfg(feature = "arrow")]
+            Error::Arrow(error) => write!(f, "arrow error: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::MissingColumn(_) | Error::Csv { .. } => None,
+            Error::Io(error) => Some(error),
+            #[cfg(feature = "serde")]
+            Error::Json(error) => Some(error),
+            #[cfg(feature = "serde")]
+            Error::PlotlyJson(_) => None,
+            #[cfg(feature = "polars")]
+            Error::Polars(error) => Some(error),
+            #[cfg(feature = "arrow")]
+            Error::Arrow(error) => Some(error),
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::Json(error)
+    }
+}
+
+#[cfg(feature = "polars")]
+impl From<polars::error::PolarsError> for Error {
+    fn from(error: polars::error::PolarsError) -> Self {
+        Error::Polars(error)
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl From<arrow_schema::ArrowError> for Error {
+    fn from(error: arrow_schema::ArrowError) -> Self {
+        Error::Arrow(error)
+    }
+}
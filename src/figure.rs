@@ -1,20 +1,44 @@
+use crate::annotation::Annotation;
+use crate::axis_type::AxisType;
 use crate::format::Format;
+use crate::html::ArrayEncoding;
+use crate::menu::Menu;
+use crate::shape::Shape;
+use crate::slider::Slider;
 use crate::trace::Trace;
 use file_io::{get_file_name, save_string_to_file};
-use plotly::{
-    Layout, Plot, Scatter, Scatter3D,
-    common::{Line, Title},
-    layout::Axis,
-};
+use plotly::layout::LayoutScene;
+use plotly::layout::themes::BuiltinTheme;
+use plotly::layout::update_menu::UpdateMenu;
+use plotly::{Layout, Plot, common::Title, layout::Axis};
 use std::path::Path;
 
 /// Figure.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Figure {
     /// Traces to plot on the figure.
     pub(crate) traces: Vec<Trace>,
 
     /// Formatting.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub(crate) format: Format,
+
+    /// Annotations.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) annotations: Vec<Annotation>,
+
+    /// Shapes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) shapes: Vec<Shape>,
+
+    /// Interactive menus.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) menus: Vec<Menu>,
+
+    /// Interactive sliders.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) sliders: Vec<Slider>,
 }
 
 impl Figure {
@@ -61,7 +85,111 @@ impl Figure {
     /// let fig = Figure::new(vec![trace_1, trace_2], format);
     /// ```
     pub fn new(traces: Vec<Trace>, format: Format) -> Figure {
-        Figure { traces, format }
+        Figure {
+            traces,
+            format,
+            annotations: Vec::new(),
+            shapes: Vec::new(),
+            menus: Vec::new(),
+            sliders: Vec::new(),
+        }
+    }
+
+    /// Add an annotation to the figure.
+    ///
+    /// # Arguments
+    ///
+    /// * `annotation` - Annotation.
+    ///
+    /// # Returns
+    ///
+    /// The figure with the annotation added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{Annotation, quick_plot_2d};
+    ///
+    /// let fig = quick_plot_2d([0.0, 1.0, 2.0, 3.0], [0.0, 5.0, 8.0, 9.0])
+    ///     .add_annotation(Annotation::new("Burn start", 1.0, 5.0).arrow(-30.0, -30.0));
+    /// ```
+    pub fn add_annotation(mut self, annotation: Annotation) -> Self {
+        self.annotations.push(annotation);
+        self
+    }
+
+    /// Add a shape to the figure.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - Shape.
+    ///
+    /// # Returns
+    ///
+    /// The figure with the shape added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{Color, NamedColor, Shape, quick_plot_2d};
+    ///
+    /// let fig = quick_plot_2d([0.0, 1.0, 2.0, 3.0], [0.0, 5.0, 8.0, 9.0])
+    ///     .add_shape(Shape::vspan(1.0, 2.0).fill_color(Color::named(NamedColor::Gray).alpha(0.3)));
+    /// ```
+    pub fn add_shape(mut self, shape: Shape) -> Self {
+        self.shapes.push(shape);
+        self
+    }
+
+    /// Add an interactive menu (a dropdown or a row of buttons) to the figure.
+    ///
+    /// # Arguments
+    ///
+    /// * `menu` - Menu.
+    ///
+    /// # Returns
+    ///
+    /// The figure with the menu added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{Figure, Format, Menu, MenuButton, Trace};
+    ///
+    /// let fig = Figure::new(
+    ///     vec![
+    ///         Trace::new_2d([0.0, 1.0], [0.0, 1.0]).name("Scenario 1"),
+    ///         Trace::new_2d([0.0, 1.0], [1.0, 0.0]).name("Scenario 2"),
+    ///     ],
+    ///     Format::default(),
+    /// )
+    /// .add_menu(
+    ///     Menu::new()
+    ///         .add_button(MenuButton::new("Scenario 1").show_only(["Scenario 1"]))
+    ///         .add_button(MenuButton::new("Scenario 2").show_only(["Scenario 2"])),
+    /// );
+    /// ```
+    pub fn add_menu(mut self, menu: Menu) -> Self {
+        self.menus.push(menu);
+        self
+    }
+
+    /// Add an interactive slider to the figure.
+    ///
+    /// # Arguments
+    ///
+    /// * `slider` - Slider.
+    ///
+    /// # Returns
+    ///
+    /// The figure with the slider added.
+    ///
+    /// # Example
+    ///
+    /// See [`Slider`].
+    pub fn add_slider(mut self, slider: Slider) -> Self {
+        self.sliders.push(slider);
+        self
     }
 
     /// Create a plotly plot from the figure.
@@ -69,60 +197,112 @@ impl Figure {
     /// # Returns
     ///
     /// Plotly plot.
+    ///
+    /// # Panics
+    ///
+    /// If a menu or slider of the figure references a trace that the figure does not have.
+    ///
+    /// # Note
+    ///
+    /// Only the first 7 secondary y-axes of the figure format are plotted (up to y-axis 8). Traces
+    /// plotted against a y-axis that is not plotted are plotted against the last plotted y-axis.
     pub fn plotly(&self) -> Plot {
+        self.plotly_with_encoding(ArrayEncoding::default())
+    }
+
+    /// Create a plotly plot from this figure, encoding the data arrays of its traces in a custom
+    /// way.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - Encoding of the numeric data arrays of the traces.
+    ///
+    /// # Returns
+    ///
+    /// Plotly plot.
+    ///
+    /// # Panics
+    ///
+    /// If a menu or slider of the figure references a trace that the figure does not have.
+    ///
+    /// # Note
+    ///
+    /// Only the first 7 secondary y-axes of the figure format are plotted (up to y-axis 8). Traces
+    /// plotted against a y-axis that is not plotted are plotted against the last plotted y-axis.
+    pub(crate) fn plotly_with_encoding(&self, encoding: ArrayEncoding) -> Plot {
         // Initialize the plot.
         let mut plot = Plot::new();
 
-        // Iterate over traces.
+        // Add the traces to the plot.
         for trace in self.traces.as_slice() {
-            // Line settings.
-            let mut line = Line::new();
-            if let Some(line_color) = &trace.line_color {
-                line = line.color(line_color.to_plotly_rgba());
-            }
-            if let Some(line_width) = trace.line_width {
-                line = line.width(line_width);
-            }
-            if let Some(line_style) = trace.line_style {
-                line = line.dash(line_style.into());
-            }
-
-            // Data alone each axis.
-            let x_data = trace.x.clone();
-            let y_data = trace.y.clone();
-            let z_data = trace.z.clone();
-
-            // Create the plotly trace.
-            let trace_plotly: Box<dyn plotly::Trace> = if let Some(z_data) = &z_data {
-                Scatter3D::new(x_data, y_data, z_data.clone()).line(line)
-            } else {
-                Scatter::new(x_data, y_data).line(line)
-            };
-
-            // Add the trace to the plot.
-            plot.add_trace(trace_plotly);
-        }
-
-        // x-axis label.
-        let x_label_plotly: Axis = if let Some(x_label) = &self.format.x_label {
+            plot.add_trace(trace.plotly(&self.format, encoding));
+        }
+
+        // x-axis settings.
+        let mut x_axis_plotly: Axis = if let Some(x_label) = &self.format.x_label {
             Axis::new().title(x_label)
         } else {
             Axis::new()
         };
+        if let Some((lower, upper)) = self.format.x_range {
+            x_axis_plotly = x_axis_plotly.range(vec![lower, upper]);
+        }
+        let x_axis_type = self.format.x_axis_type.or_else(|| {
+            self.traces
+                .iter()
+                .any(|trace| trace.time_series)
+                .then_some(AxisType::Date)
+        });
+        if let Some(x_axis_type) = x_axis_type {
+            x_axis_plotly = x_axis_plotly.type_(x_axis_type.into());
+        }
+        if let Some(x_category_order) = &self.format.x_category_order {
+            x_axis_plotly = x_category_order.apply_to_plotly(x_axis_plotly);
+        }
+        if let Some(x_tick_format) = &self.format.x_tick_format {
+            x_axis_plotly = x_axis_plotly.tick_format(x_tick_format);
+        }
+        if !self.format.x_range_breaks.is_empty() {
+            x_axis_plotly = x_axis_plotly.range_breaks(
+                self.format
+                    .x_range_breaks
+                    .iter()
+                    .map(|range_break| range_break.plotly())
+                    .collect(),
+            );
+        }
+        if let Some((start, end)) = self.format.x_domain {
+            x_axis_plotly = x_axis_plotly.domain(&[start, end]);
+        }
 
-        // y-axis label.
-        let y_label_plotly: Axis = if let Some(y_label) = &self.format.y_label {
+        // y-axis settings.
+        let mut y_axis_plotly: Axis = if let Some(y_label) = &self.format.y_label {
             Axis::new().title(y_label)
         } else {
             Axis::new()
         };
+        if let Some((lower, upper)) = self.format.y_range {
+            y_axis_plotly = y_axis_plotly.range(vec![lower, upper]);
+        }
+        if let Some(y_axis_type) = self.format.y_axis_type {
+            y_axis_plotly = y_axis_plotly.type_(y_axis_type.into());
+        }
+        if let Some(y_category_order) = &self.format.y_category_order {
+            y_axis_plotly = y_category_order.apply_to_plotly(y_axis_plotly);
+        }
+        if let Some(y_tick_format) = &self.format.y_tick_format {
+            y_axis_plotly = y_axis_plotly.tick_format(y_tick_format);
+        }
 
-        // z-axis label.
-        let z_label_plotly: Option<Axis> = self
+        // z-axis settings.
+        let mut z_axis_plotly: Option<Axis> = self
             .format
             .z_label
             .as_ref()
             .map(|z_label| Axis::new().title(z_label));
+        if let Some((lower, upper)) = self.format.z_range {
+            z_axis_plotly = Some(z_axis_plotly.unwrap_or_default().range(vec![lower, upper]));
+        }
 
         // Title.
         let title_plotly: Title = if let Some(title) = &self.format.title {
@@ -133,11 +313,29 @@ impl Figure {
 
         // Set the plot layout.
         let mut layout = Layout::new()
-            .x_axis(x_label_plotly)
-            .y_axis(y_label_plotly)
+            .x_axis(x_axis_plotly)
+            .y_axis(y_axis_plotly)
             .title(title_plotly);
-        if let Some(z_axis) = z_label_plotly {
-            layout = layout.z_axis(z_axis);
+        if let Some(z_axis) = z_axis_plotly {
+            // plotly.js only reads the z-axis of 3D traces from the scene.
+            layout = layout.scene(LayoutScene::new().z_axis(z_axis));
+        }
+        let y_axes = self
+            .format
+            .plotted_secondary_y_axes()
+            .iter()
+            .map(|axis_format| axis_format.plotly("y"));
+        let setters = [
+            Layout::y_axis2,
+            Layout::y_axis3,
+            Layout::y_axis4,
+            Layout::y_axis5,
+            Layout::y_axis6,
+            Layout::y_axis7,
+            Layout::y_axis8,
+        ];
+        for (setter, axis) in setters.into_iter().zip(y_axes) {
+            layout = setter(layout, axis);
         }
         if let Some(width) = self.format.width {
             layout = layout.width(width);
@@ -145,11 +343,63 @@ impl Figure {
         if let Some(height) = self.format.height {
             layout = layout.height(height);
         }
+        if let Some(theme) = self.format.theme {
+            layout = layout.template(BuiltinTheme::from(theme).build());
+        }
+        if !self.shapes.is_empty() {
+            layout = layout.shapes(self.shapes.iter().map(|shape| shape.plotly()).collect());
+        }
+        if !self.annotations.is_empty() {
+            layout = layout.annotations(
+                self.annotations
+                    .iter()
+                    .map(|annotation| annotation.plotly())
+                    .collect(),
+            );
+        }
+        if !self.menus.is_empty() {
+            layout = layout.update_menus(self.plotly_menus());
+        }
+        if !self.sliders.is_empty() {
+            layout = layout.sliders(self.plotly_sliders());
+        }
         plot.set_layout(layout);
 
         plot
     }
 
+    /// Create plotly menus from the menus of the figure.
+    ///
+    /// # Returns
+    ///
+    /// Plotly menus.
+    ///
+    /// # Panics
+    ///
+    /// If a menu of the figure references a trace that the figure does not have.
+    pub(crate) fn plotly_menus(&self) -> Vec<UpdateMenu> {
+        self.menus
+            .iter()
+            .map(|menu| menu.plotly(&self.traces))
+            .collect()
+    }
+
+    /// Create plotly sliders from the sliders of the figure.
+    ///
+    /// # Returns
+    ///
+    /// Plotly sliders.
+    ///
+    /// # Panics
+    ///
+    /// If a slider of the figure references a trace that the figure does not have.
+    pub(crate) fn plotly_sliders(&self) -> Vec<plotly::layout::Slider> {
+        self.sliders
+            .iter()
+            .map(|slider| slider.plotly(&self.traces))
+            .collect()
+    }
+
     /// Show the figure (opens the figure in a web browser).
     ///
     /// # Example
@@ -197,6 +447,51 @@ impl Figure {
         save_string_to_file(&html_str, path);
     }
 
+    /// Get the plotly.js figure specification of the figure in JSON format.
+    ///
+    /// The figure specification is an object with `data` (traces) and `layout` fields, which can be
+    /// passed directly to `Plotly.newPlot` in plotly.js.
+    ///
+    /// # Returns
+    ///
+    /// plotly.js figure specification in JSON format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::quick_plot_2d;
+    ///
+    /// let json = quick_plot_2d([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]).to_plotly_json();
+    /// ```
+    pub fn to_plotly_json(&self) -> String {
+        self.plotly().to_json()
+    }
+
+    /// Save the plotly.js figure specification of the figure to a JSON file (see
+    /// [`Figure::to_plotly_json`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the JSON file.
+    ///
+    /// # Panics
+    ///
+    /// If some error is encountered while creating the file or writing to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{quick_plot_2d, Figure};
+    ///
+    /// let fig: Figure = quick_plot_2d([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]);
+    ///
+    /// // Save the figure to a JSON file.
+    /// fig.save_json("folder/file.json");
+    /// ```
+    pub fn save_json<P: AsRef<Path>>(&self, path: P) {
+        save_string_to_file(&self.to_plotly_json(), path.as_ref());
+    }
+
     /// Save the figure to an HTML file meant to be used "in-line" in another HTML file.
     ///
     /// # Arguments
@@ -227,3 +522,128 @@ impl Figure {
         save_string_to_file(&html_str, path);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_plotly_secondary_y_axes() {
+        use super::*;
+        use crate::axis_format::{AxisFormatBuilder, AxisSide};
+        use crate::format::FormatBuilder;
+        use serde_json::{Value, json};
+
+        let speed_axis = AxisFormatBuilder::default()
+            .label("Speed")
+            .range((0.0, 300.0))
+            .side(AxisSide::Left)
+            .position(0.05)
+            .build()
+            .unwrap();
+        let format = FormatBuilder::default()
+            .secondary_y_axes(vec![speed_axis; 8])
+            .build()
+            .unwrap();
+        let fig = Figure::new(
+            vec![
+                Trace::new_2d([0.0, 1.0], [0.0, 1.0]),
+                Trace::new_2d([0.0, 1.0], [0.0, 100.0]).y_axis(2),
+                Trace::new_2d([0.0, 1.0], [0.0, 100.0]).y_axis(20),
+            ],
+            format,
+        );
+        let json: Value = serde_json::from_str(&fig.to_plotly_json()).unwrap();
+        assert_eq!(json["data"][0]["yaxis"], Value::Null);
+        assert_eq!(json["data"][1]["yaxis"], "y2");
+        assert_eq!(json["data"][2]["yaxis"], "y8");
+        let y_axis = &json["layout"]["yaxis2"];
+        assert_eq!(y_axis["title"]["text"], "Speed");
+        assert_eq!(y_axis["range"], json!([0.0, 300.0]));
+        assert_eq!(y_axis["overlaying"], "y");
+        assert_eq!(y_axis["side"], "left");
+        assert_eq!(y_axis["anchor"], "free");
+        assert_eq!(y_axis["position"], 0.05);
+        assert!(json["layout"]["yaxis8"].is_object());
+        assert!(json["layout"].get("yaxis9").is_none());
+    }
+
+    #[test]
+    fn test_plotly_z_axis() {
+        use super::*;
+        use crate::format::FormatBuilder;
+        use serde_json::{Value, json};
+
+        let format = FormatBuilder::default()
+            .z_label("Altitude")
+            .z_range((0.0, 5.0))
+            .build()
+            .unwrap();
+        let fig = Figure::new(vec![Trace::new_3d([0.0], [0.0], [1.0])], format);
+        let json: Value = serde_json::from_str(&fig.to_plotly_json()).unwrap();
+        let z_axis = &json["layout"]["scene"]["zaxis"];
+        assert_eq!(z_axis["title"]["text"], "Altitude");
+        assert_eq!(z_axis["range"], json!([0.0, 5.0]));
+        assert!(json["layout"].get("zaxis").is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use super::*;
+        use crate::annotation::Annotation;
+        use crate::category_order::CategoryOrder;
+        use crate::colors::{Color, NamedColor};
+        use crate::format::FormatBuilder;
+        use crate::line_style::LineStyle;
+        use crate::shape::Shape;
+
+        let format = FormatBuilder::default()
+            .title("Speed")
+            .x_label("Vehicle")
+            .y_range((0.0, 10.0))
+            .x_category_order(CategoryOrder::Explicit(vec![String::from("car")]))
+            .build()
+            .unwrap();
+        let fig = Figure::new(
+            vec![
+                Trace::new_2d(["car", "truck"], [1.0, f64::NAN])
+                    .name("Run 1")
+                    .line_color(Color::named(NamedColor::SteelBlue))
+                    .line_style(LineStyle::DashDot),
+                Trace::new_3d([1.0], [2.0], [3.0]).marker_color(Color::rgba(1, 2, 3, 0.5)),
+            ],
+            format,
+        )
+        .add_annotation(Annotation::new("Peak", 1.0, 2.0).arrow(-10.0, -10.0))
+        .add_shape(Shape::hline(5.0).line_color(Color::named(NamedColor::Red)));
+
+        // JSON.
+        let json = serde_json::to_string(&fig).unwrap();
+        assert!(json.contains("\"line_color\":\"#4682b4\""));
+        assert!(json.contains("\"line_style\":\"dashdot\""));
+        assert!(json.contains("\"y\":[1.0,null]"));
+        let round_trip: Figure = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&round_trip).unwrap(), json);
+        assert_eq!(round_trip.traces[0].x, ["car", "truck"]);
+        assert!(round_trip.traces[0].y.as_numeric().unwrap()[1].is_nan());
+
+        // TOML (which cannot represent gaps, so the trace with a gap is removed).
+        let mut fig = round_trip;
+        fig.traces.remove(0);
+        let toml = toml::to_string(&fig).unwrap();
+        let round_trip: Figure = toml::from_str(&toml).unwrap();
+        assert_eq!(toml::to_string(&round_trip).unwrap(), toml);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_defaults() {
+        use super::*;
+
+        let fig: Figure =
+            serde_json::from_str(r#"{"traces": [{"x": [1, 2], "y": ["a", "b"]}]}"#).unwrap();
+        assert_eq!(fig.traces[0].x, [1.0, 2.0]);
+        assert_eq!(fig.traces[0].y, ["a", "b"]);
+        assert!(fig.annotations.is_empty());
+        assert!(fig.format.title.is_none());
+    }
+}
@@ -0,0 +1,55 @@
+use plotly::common::Fill as PlotlyFill;
+
+/// Fill mode.
+///
+/// # Note
+///
+/// This enum is a direct re-implementation of the [`plotly::common::Fill`] enum from the
+/// [`plotly`] crate (Ref. \[1\]). As such, we have included the license of the [`plotly`] crate in
+/// the [`src/plotly_licenses`](https://github.com/tamaskis/plotting/tree/main/src/plotly_licenses/LICENSE)
+/// folder.
+///
+/// # References
+///
+/// * \[1\] <https://docs.rs/plotly/latest/plotly/common/enum.Fill.html>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Fill {
+    /// Fill the area between the trace and `y = 0`.
+    ToZeroY,
+
+    /// Fill the area between the trace and `x = 0`.
+    ToZeroX,
+
+    /// Fill the area between the trace and the previous trace (in the y-direction).
+    ToNextY,
+
+    /// Fill the area between the trace and the previous trace (in the x-direction).
+    ToNextX,
+
+    /// Connect the endpoints of the trace and fill the enclosed area (i.e. treat the trace as a
+    /// closed polygon).
+    ToSelf,
+
+    /// Fill the area between the trace and the previous trace, assuming that one of the traces
+    /// completely encloses the other.
+    ToNext,
+
+    /// No fill.
+    None,
+}
+
+impl From<Fill> for PlotlyFill {
+    fn from(fill: Fill) -> Self {
+        match fill {
+            Fill::ToZeroY => PlotlyFill::ToZeroY,
+            Fill::ToZeroX => PlotlyFill::ToZeroX,
+            Fill::ToNextY => PlotlyFill::ToNextY,
+            Fill::ToNextX => PlotlyFill::ToNextX,
+            Fill::ToSelf => PlotlyFill::ToSelf,
+            Fill::ToNext => PlotlyFill::ToNext,
+            Fill::None => PlotlyFill::None,
+        }
+    }
+}
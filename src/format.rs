@@ -1,5 +1,15 @@
+use crate::axis_format::AxisFormat;
+use crate::axis_type::AxisType;
+use crate::category_order::CategoryOrder;
+use crate::downsampling::Downsampling;
+use crate::range_break::RangeBreak;
+use crate::theme::Theme;
+use crate::trace::Trace;
 use derive_builder::Builder;
 
+/// Maximum number of secondary y-axes.
+pub(crate) const MAX_SECONDARY_Y_AXES: usize = 7;
+
 /// Figure format.
 ///
 /// # Examples
@@ -27,6 +37,8 @@ use derive_builder::Builder;
 ///     .unwrap();
 /// ```
 #[derive(Builder, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct Format {
     /// Title.
     #[builder(setter(into, strip_option), default)]
@@ -44,6 +56,59 @@ pub struct Format {
     #[builder(setter(into, strip_option), default)]
     pub(crate) z_label: Option<String>,
 
+    /// x-axis range (lower and upper limits).
+    #[builder(setter(strip_option), default)]
+    pub(crate) x_range: Option<(f64, f64)>,
+
+    /// y-axis range (lower and upper limits).
+    #[builder(setter(strip_option), default)]
+    pub(crate) y_range: Option<(f64, f64)>,
+
+    /// z-axis range (lower and upper limits, only used for 3D figures).
+    #[builder(setter(strip_option), default)]
+    pub(crate) z_range: Option<(f64, f64)>,
+
+    /// x-axis type (determined automatically from the data if not specified).
+    #[builder(setter(strip_option), default)]
+    pub(crate) x_axis_type: Option<AxisType>,
+
+    /// y-axis type (determined automatically from the data if not specified).
+    #[builder(setter(strip_option), default)]
+    pub(crate) y_axis_type: Option<AxisType>,
+
+    /// x-axis tick label format, using d3-format syntax for numeric axes (e.g. `".2f"`) or
+    /// d3-time-format syntax for date axes (e.g. `"%b %d\n%H:%M"`).
+    ///
+    /// If not specified, tick labels on date axes are formatted based on the zoom level.
+    #[builder(setter(into, strip_option), default)]
+    pub(crate) x_tick_format: Option<String>,
+
+    /// y-axis tick label format, using d3-format syntax (e.g. `".2f"`).
+    #[builder(setter(into, strip_option), default)]
+    pub(crate) y_tick_format: Option<String>,
+
+    /// Order of the categories along the x-axis (only used for categorical axes).
+    #[builder(setter(strip_option), default)]
+    pub(crate) x_category_order: Option<CategoryOrder>,
+
+    /// Order of the categories along the y-axis (only used for categorical axes).
+    #[builder(setter(strip_option), default)]
+    pub(crate) y_category_order: Option<CategoryOrder>,
+
+    /// Range breaks on the x-axis (only used for date axes).
+    #[builder(setter(into), default)]
+    pub(crate) x_range_breaks: Vec<RangeBreak>,
+
+    /// Domain of the x-axis as fractions of the width of the figure (e.g. `(0.1, 0.9)` to leave
+    /// space on both sides of the plotting area for offset secondary y-axes).
+    #[builder(setter(strip_option), default)]
+    pub(crate) x_domain: Option<(f64, f64)>,
+
+    /// Secondary y-axes (the first secondary y-axis is y-axis 2, the second is y-axis 3, and so
+    /// on, up to y-axis 8). Any secondary y-axes beyond the seventh are ignored.
+    #[builder(setter(into), default)]
+    pub(crate) secondary_y_axes: Vec<AxisFormat>,
+
     /// Width (in pixels).
     #[builder(setter(strip_option), default)]
     pub(crate) width: Option<usize>,
@@ -51,4 +116,48 @@ pub struct Format {
     /// Height (in pixels).
     #[builder(setter(strip_option), default)]
     pub(crate) height: Option<usize>,
+
+    /// Theme (the default plotly.js theme if not specified).
+    #[builder(setter(strip_option), default)]
+    pub(crate) theme: Option<Theme>,
+
+    /// Downsampling strategy applied to every trace that does not specify its own (no
+    /// downsampling if not specified).
+    #[builder(setter(strip_option), default)]
+    pub(crate) downsampling: Option<Downsampling>,
+
+    /// Number of points above which 2D scatter traces are rendered using WebGL (100,000 if not
+    /// specified). Individual traces can override this (see [`crate::Trace::web_gl`]).
+    #[builder(setter(strip_option), default)]
+    pub(crate) web_gl_threshold: Option<usize>,
+}
+
+impl Format {
+    /// Get the secondary y-axes that are plotted.
+    ///
+    /// # Returns
+    ///
+    /// Secondary y-axes (at most [`MAX_SECONDARY_Y_AXES`], since plotly.js figures created using
+    /// the plotly crate support y-axes up to y-axis 8).
+    pub(crate) fn plotted_secondary_y_axes(&self) -> &[AxisFormat] {
+        &self.secondary_y_axes[..self.secondary_y_axes.len().min(MAX_SECONDARY_Y_AXES)]
+    }
+
+    /// Get the y-axis that a trace is plotted against.
+    ///
+    /// # Arguments
+    ///
+    /// * `trace` - Trace.
+    ///
+    /// # Returns
+    ///
+    /// y-axis number (`1` for the primary y-axis, `2` for the first secondary y-axis, and so on).
+    /// Traces assigned to a y-axis that is not plotted are plotted against the last plotted
+    /// y-axis.
+    pub(crate) fn y_axis(&self, trace: &Trace) -> usize {
+        trace
+            .y_axis
+            .unwrap_or(1)
+            .clamp(1, self.plotted_secondary_y_axes().len() + 1)
+    }
 }
@@ -0,0 +1,429 @@
+use crate::figure::Figure;
+use crate::svg::escape;
+use derive_builder::Builder;
+use file_io::save_string_to_file;
+use plotly::Plot;
+use serde::Serializer;
+use std::path::Path;
+
+/// Source of the plotly.js library referenced by HTML files.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PlotlyJs {
+    /// Load plotly.js (and MathJax, used to render LaTeX) from a CDN (requires an internet
+    /// connection to view the HTML file).
+    #[default]
+    Cdn,
+
+    /// Embed plotly.js (and MathJax) in the HTML file, so that it can be viewed offline. This adds
+    /// several megabytes to the size of the HTML file.
+    Inline,
+
+    /// Load plotly.js from the given path or URL (e.g. a local copy of `plotly.min.js` next to the
+    /// HTML file). MathJax is not loaded, so LaTeX is not rendered.
+    Path(String),
+}
+
+impl PlotlyJs {
+    /// HTML script tags loading plotly.js.
+    ///
+    /// # Returns
+    ///
+    /// HTML script tags.
+    pub(crate) fn scripts(&self) -> String {
+        match self {
+            PlotlyJs::Cdn => Plot::online_cdn_js(),
+            PlotlyJs::Inline => Plot::offline_js_sources(),
+            PlotlyJs::Path(path) => format!("<script src=\"{}\"></script>\n", escape(path)),
+        }
+    }
+}
+
+/// Options for exporting figures to HTML files.
+///
+/// # Examples
+///
+/// ## Default options
+///
+/// plotly.js is loaded from a CDN, and data is written as decimal text with full precision (i.e.
+/// the same as [`Figure::save_html`]).
+///
+/// ```
+/// use plotting::HtmlOptions;
+///
+/// let options = HtmlOptions::default();
+/// ```
+///
+/// ## Compact, offline HTML files
+///
+/// ```
+/// use plotting::{HtmlOptions, HtmlOptionsBuilder, PlotlyJs};
+///
+/// // Embed plotly.js, encode numeric arrays with at least 1000 elements as base64 typed arrays,
+/// // and round data to 6 significant digits.
+/// let options: HtmlOptions = HtmlOptionsBuilder::default()
+///     .plotly_js(PlotlyJs::Inline)
+///     .binary_threshold(1000)
+///     .precision(6)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Builder, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct HtmlOptions {
+    /// Source of the plotly.js library.
+    #[builder(default)]
+    pub(crate) plotly_js: PlotlyJs,
+
+    /// Number of elements above which (or at which) numeric data arrays are encoded as base64
+    /// typed arrays rather than decimal text (data arrays are never encoded as typed arrays if not
+    /// specified).
+    ///
+    /// Typed arrays use 64-bit floats, or 32-bit floats if the precision is 7 significant digits
+    /// or less and all values are within the range of 32-bit floats.
+    #[builder(setter(strip_option), default)]
+    pub(crate) binary_threshold: Option<usize>,
+
+    /// Number of significant digits that numeric data is rounded to (full precision if not
+    /// specified). Points in time (see [`crate::Trace::new_time_series`]) are never rounded.
+    ///
+    /// Data arrays encoded as typed arrays (see [`HtmlOptions::binary_threshold`]) are not
+    /// rounded either: the precision only selects between 32-bit and 64-bit floats for them.
+    #[builder(setter(strip_option), default)]
+    pub(crate) precision: Option<usize>,
+}
+
+impl HtmlOptions {
+    /// Encoding of the numeric data arrays of traces.
+    ///
+    /// # Returns
+    ///
+    /// Encoding of the numeric data arrays of traces.
+    pub(crate) fn encoding(&self) -> ArrayEncoding {
+        ArrayEncoding {
+            binary_threshold: self.binary_threshold,
+            precision: self.precision.map(|precision| precision.max(1)),
+        }
+    }
+
+    /// Convert a plotly plot to a standalone HTML document using these options.
+    ///
+    /// # Arguments
+    ///
+    /// * `plot` - Plotly plot (whose data arrays are already encoded using
+    ///   [`HtmlOptions::encoding`]).
+    ///
+    /// # Returns
+    ///
+    /// HTML document.
+    pub(crate) fn to_html(&self, plot: &Plot) -> String {
+        let html = plot.to_html();
+        match self.plotly_js {
+            PlotlyJs::Cdn => html,
+            _ => html.replacen(&Plot::online_cdn_js(), &self.plotly_js.scripts(), 1),
+        }
+    }
+}
+
+/// Encoding of numeric data arrays in plotly.js figure specifications.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct ArrayEncoding {
+    /// Number of elements at which arrays are encoded as base64 typed arrays.
+    pub(crate) binary_threshold: Option<usize>,
+
+    /// Number of significant digits.
+    pub(crate) precision: Option<usize>,
+}
+
+impl ArrayEncoding {
+    /// Encoding that does not round the data (used for points in time).
+    ///
+    /// # Returns
+    ///
+    /// Encoding with full precision.
+    pub(crate) fn full_precision(self) -> ArrayEncoding {
+        ArrayEncoding {
+            precision: None,
+            ..self
+        }
+    }
+
+    /// Serialize a numeric data array.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Numeric data array.
+    /// * `serializer` - Serializer.
+    ///
+    /// # Returns
+    ///
+    /// Result of the serialization.
+    ///
+    /// # Errors
+    ///
+    /// If the serializer fails.
+    pub(crate) fn serialize<S: Serializer>(
+        &self,
+        data: &[f64],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        // Base64-encoded typed array (see https://github.com/plotly/plotly.js/pull/5230).
+        if self
+            .binary_threshold
+            .is_some_and(|threshold| data.len() >= threshold)
+        {
+            // Values that cannot be represented as 32-bit floats (i.e. that would overflow to
+            // infinity or lose precision as subnormal numbers) require 64-bit floats.
+            let single = self.precision.is_some_and(|precision| precision <= 7)
+                && data.iter().all(|value| {
+                    !value.is_finite()
+                        || *value == 0.0
+                        || (f64::from(f32::MIN_POSITIVE)..=f64::from(f32::MAX))
+                            .contains(&value.abs())
+                });
+            let bytes: Vec<u8> = if single {
+                data.iter()
+                    .flat_map(|value| (*value as f32).to_le_bytes())
+                    .collect()
+            } else {
+                data.iter().flat_map(|value| value.to_le_bytes()).collect()
+            };
+            let mut map = serializer.serialize_map(Some(2))?;
+            map.serialize_entry("dtype", if single { "f4" } else { "f8" })?;
+            map.serialize_entry("bdata", &base64(&bytes))?;
+            return map.end();
+        }
+
+        match self.precision {
+            Some(precision) => {
+                serializer.collect_seq(data.iter().map(|value| round(*value, precision)))
+            }
+            None => serializer.collect_seq(data),
+        }
+    }
+}
+
+/// Round a number to a number of significant digits.
+///
+/// # Arguments
+///
+/// * `value` - Number.
+/// * `precision` - Number of significant digits (at least 1).
+///
+/// # Returns
+///
+/// Rounded number.
+fn round(value: f64, precision: usize) -> f64 {
+    if !value.is_finite() || value == 0.0 {
+        return value;
+    }
+    format!("{value:.*e}", precision - 1)
+        .parse()
+        .unwrap_or(value)
+}
+
+/// Encode bytes using base64 (with padding).
+///
+/// # Arguments
+///
+/// * `bytes` - Bytes.
+///
+/// # Returns
+///
+/// Base64-encoded bytes.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let triple = (u32::from(chunk[0]) << 16)
+            | (u32::from(*chunk.get(1).unwrap_or(&0)) << 8)
+            | u32::from(*chunk.get(2).unwrap_or(&0));
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(char::from(
+                    ALPHABET[(triple >> (18 - 6 * index)) as usize & 63],
+                ));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+impl Figure {
+    /// Get a standalone HTML document displaying the figure, using custom export options.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - HTML export options.
+    ///
+    /// # Returns
+    ///
+    /// HTML document.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{quick_plot_2d, HtmlOptionsBuilder, PlotlyJs};
+    ///
+    /// let options = HtmlOptionsBuilder::default()
+    ///     .plotly_js(PlotlyJs::Path(String::from("plotly.min.js")))
+    ///     .build()
+    ///     .unwrap();
+    /// let html = quick_plot_2d([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]).to_html_with(&options);
+    /// assert!(html.contains("<script src=\"plotly.min.js\"></script>"));
+    /// ```
+    pub fn to_html_with(&self, options: &HtmlOptions) -> String {
+        options.to_html(&self.plotly_with_encoding(options.encoding()))
+    }
+
+    /// Save the figure to a standalone HTML file, using custom export options (see
+    /// [`HtmlOptions`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the HTML file.
+    /// * `options` - HTML export options.
+    ///
+    /// # Panics
+    ///
+    /// If some error is encountered while creating the file or writing to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{quick_plot_2d, HtmlOptionsBuilder, PlotlyJs};
+    ///
+    /// // Save a compact HTML file that can be viewed offline.
+    /// let options = HtmlOptionsBuilder::default()
+    ///     .plotly_js(PlotlyJs::Inline)
+    ///     .binary_threshold(1000)
+    ///     .build()
+    ///     .unwrap();
+    /// quick_plot_2d([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]).save_html_with("folder/file.html", &options);
+    /// ```
+    pub fn save_html_with<P: AsRef<Path>>(&self, path: P, options: &HtmlOptions) {
+        save_string_to_file(&self.to_html_with(options), path.as_ref());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::Format;
+    use crate::trace::Trace;
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_round() {
+        assert_eq!(round(123456.0, 3), 123000.0);
+        assert_eq!(round(0.000123456, 2), 0.00012);
+        assert_eq!(round(-1.26, 2), -1.3);
+        assert!(round(f64::NAN, 2).is_nan());
+    }
+
+    #[test]
+    fn test_plotly_js() {
+        let fig = Figure::new(vec![Trace::new_2d([1.0], [2.0])], Format::default());
+        let cdn = fig.to_html_with(&HtmlOptions::default());
+        assert!(cdn.contains("https://cdn.plot.ly/plotly-3.0.1.min.js"));
+
+        let options = HtmlOptionsBuilder::default()
+            .plotly_js(PlotlyJs::Inline)
+            .build()
+            .unwrap();
+        let inline = fig.to_html_with(&options);
+        assert!(!inline.contains(&Plot::online_cdn_js()));
+        assert!(inline.contains("plotly.js v3.0.1"));
+
+        let options = HtmlOptionsBuilder::default()
+            .plotly_js(PlotlyJs::Path(String::from("js/plotly.min.js")))
+            .build()
+            .unwrap();
+        let path = fig.to_html_with(&options);
+        assert!(!path.contains("https://cdn.plot.ly"));
+        assert!(path.contains("<script src=\"js/plotly.min.js\"></script>"));
+
+        // The path is escaped in the attribute.
+        let options = HtmlOptionsBuilder::default()
+            .plotly_js(PlotlyJs::Path(String::from("js/a&b\"><x>.js")))
+            .build()
+            .unwrap();
+        let path = fig.to_html_with(&options);
+        assert!(path.contains("<script src=\"js/a&amp;b&quot;&gt;&lt;x&gt;.js\"></script>"));
+    }
+
+    #[test]
+    fn test_encoding() {
+        let fig = Figure::new(
+            vec![Trace::new_2d([1.0, 2.0], [0.123456789, f64::NAN])],
+            Format::default(),
+        );
+        let spec = |options: HtmlOptions| {
+            let plot = fig.plotly_with_encoding(options.encoding());
+            let json: serde_json::Value = serde_json::from_str(&plot.to_json()).unwrap();
+            json["data"][0].clone()
+        };
+
+        // Decimal text with full precision.
+        let trace = spec(HtmlOptions::default());
+        assert_eq!(trace["y"], serde_json::json!([0.123456789, null]));
+
+        // Rounded decimal text.
+        let trace = spec(HtmlOptionsBuilder::default().precision(3).build().unwrap());
+        assert_eq!(trace["y"], serde_json::json!([0.123, null]));
+
+        // Typed arrays.
+        let trace = spec(
+            HtmlOptionsBuilder::default()
+                .binary_threshold(2)
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(trace["x"]["dtype"], "f8");
+        assert_eq!(
+            trace["x"]["bdata"],
+            base64(&[1.0_f64.to_le_bytes(), 2.0_f64.to_le_bytes()].concat())
+        );
+        let trace = spec(
+            HtmlOptionsBuilder::default()
+                .binary_threshold(2)
+                .precision(6)
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(trace["y"]["dtype"], "f4");
+
+        // Values outside the range of 32-bit floats require 64-bit floats.
+        for value in [1e39, -1e39, 1e-40] {
+            let fig = Figure::new(
+                vec![Trace::new_2d([1.0, 2.0], [value, 1.0])],
+                Format::default(),
+            );
+            let plot = fig.plotly_with_encoding(
+                HtmlOptionsBuilder::default()
+                    .binary_threshold(2)
+                    .precision(6)
+                    .build()
+                    .unwrap()
+                    .encoding(),
+            );
+            let json: serde_json::Value = serde_json::from_str(&plot.to_json()).unwrap();
+            assert_eq!(json["data"][0]["y"]["dtype"], "f8");
+            assert_eq!(json["data"][0]["x"]["dtype"], "f4");
+        }
+    }
+}
@@ -11,20 +11,90 @@
 #![warn(missing_docs)]
 
 // Module declarations.
+mod animation;
+mod annotation;
+#[cfg(feature = "arrow")]
+mod arrow_data;
+mod axes;
+mod axis_data;
+mod axis_format;
+mod axis_type;
+mod category_order;
 mod colors;
+mod columns;
+mod coordinates;
+mod csv;
+mod data_source;
+mod downsampling;
+mod error;
 mod figure;
+mod fill;
 mod format;
+mod html;
 mod line_style;
+mod markdown;
+mod menu;
+mod mode;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_data;
+#[cfg(feature = "ndarray")]
+mod ndarray_data;
+mod plot_type;
+#[cfg(feature = "serde")]
+mod plotly_json;
+#[cfg(feature = "png")]
+mod png;
+#[cfg(feature = "polars")]
+mod polars_data;
+mod preview;
 mod quick;
+mod range_break;
+mod report;
+mod scale;
+mod shape;
+mod slider;
+mod streaming;
+mod svg;
+mod sweep;
+mod terminal;
+mod theme;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod timestamp;
 mod trace;
 
 // Re-exports.
+pub use crate::animation::{Animation, Frame};
+pub use crate::annotation::{Alignment, Annotation};
+pub use crate::axis_data::AxisData;
+pub use crate::axis_format::{AxisFormat, AxisFormatBuilder, AxisSide};
+pub use crate::axis_type::AxisType;
+pub use crate::category_order::CategoryOrder;
 pub use crate::colors::{Color, NamedColor};
+pub use crate::columns::{Column, Columns, ColumnsBuilder};
+pub use crate::coordinates::Coordinates;
+pub use crate::csv::{CsvOptions, CsvOptionsBuilder, CsvTable};
+pub use crate::data_source::{DataElement, DataSource};
+pub use crate::downsampling::Downsampling;
+pub use crate::error::Error;
 pub use crate::figure::Figure;
+pub use crate::fill::Fill;
 pub use crate::format::Format;
 pub use crate::format::FormatBuilder;
+pub use crate::html::{HtmlOptions, HtmlOptionsBuilder, PlotlyJs};
 pub use crate::line_style::LineStyle;
+pub use crate::menu::{Menu, MenuButton, MenuStyle, TraceRef};
+pub use crate::mode::Mode;
+pub use crate::plot_type::PlotType;
+pub use crate::preview::PreviewServer;
 pub use crate::quick::{
     quick_plot_2d, quick_plot_2d_with_labels, quick_plot_3d, quick_plot_3d_with_labels,
 };
+pub use crate::range_break::RangeBreak;
+pub use crate::report::Report;
+pub use crate::shape::{Layer, Shape};
+pub use crate::slider::Slider;
+pub use crate::streaming::StreamingFigure;
+pub use crate::theme::Theme;
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub use crate::timestamp::Timestamp;
 pub use crate::trace::Trace;
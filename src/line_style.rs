@@ -13,6 +13,8 @@ use plotly::common::DashType;
 ///
 /// * \[1\] <https://docs.rs/plotly/latest/plotly/common/enum.DashType.html>
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum LineStyle {
     /// Solid line.
     Solid,
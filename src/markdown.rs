@@ -0,0 +1,320 @@
+use crate::svg::escape;
+
+/// Convert Markdown to HTML.
+///
+/// # Arguments
+///
+/// * `markdown` - Markdown text.
+///
+/// # Returns
+///
+/// HTML.
+///
+/// # Note
+///
+/// Only a commonly used subset of Markdown is supported (see [`crate::Report::add_markdown`]).
+pub(crate) fn to_html(markdown: &str) -> String {
+    let mut html = String::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut list: Option<&str> = None;
+    let mut lines = markdown.lines().peekable();
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+
+        // Close the current paragraph or list at block boundaries.
+        let list_item = list_item(trimmed);
+        let is_block = trimmed.is_empty()
+            || heading_level(trimmed).is_some()
+            || trimmed.starts_with("```")
+            || trimmed.starts_with('>')
+            || is_rule(trimmed)
+            || list_item.is_some();
+        if is_block && !paragraph.is_empty() {
+            html.push_str(&format!("<p>{}</p>\n", inline(&paragraph.join(" "))));
+            paragraph.clear();
+        }
+        if let Some(tag) = list
+            && list_item.is_none_or(|(item_tag, _)| item_tag != tag)
+            && !(trimmed.is_empty() && lines.peek().is_some_and(|next| is_item(next, tag)))
+        {
+            html.push_str(&format!("</{tag}>\n"));
+            list = None;
+        }
+
+        if trimmed.is_empty() {
+            continue;
+        } else if let Some(language) = trimmed.strip_prefix("```") {
+            // Fenced code block.
+            let mut code = Vec::new();
+            for line in lines.by_ref() {
+                if line.trim_start().starts_with("```") {
+                    break;
+                }
+                code.push(line);
+            }
+            let class = match language.trim() {
+                "" => String::new(),
+                language => format!(" class=\"language-{}\"", escape(language)),
+            };
+            html.push_str(&format!(
+                "<pre><code{class}>{}</code></pre>\n",
+                escape(&code.join("\n"))
+            ));
+        } else if let Some(level) = heading_level(trimmed) {
+            // Heading.
+            let text = trimmed[level..].trim().trim_end_matches('#').trim();
+            html.push_str(&format!("<h{level}>{}</h{level}>\n", inline(text)));
+        } else if let Some(quote) = trimmed.strip_prefix('>') {
+            // Block quote.
+            html.push_str(&format!(
+                "<blockquote>{}</blockquote>\n",
+                inline(quote.trim())
+            ));
+        } else if is_rule(trimmed) {
+            // Horizontal rule.
+            html.push_str("<hr>\n");
+        } else if let Some((tag, item)) = list_item {
+            // List item.
+            if list.is_none() {
+                html.push_str(&format!("<{tag}>\n"));
+                list = Some(tag);
+            }
+            html.push_str(&format!("<li>{}</li>\n", inline(item)));
+        } else {
+            // Paragraph text.
+            paragraph.push(trimmed);
+        }
+    }
+    if !paragraph.is_empty() {
+        html.push_str(&format!("<p>{}</p>\n", inline(&paragraph.join(" "))));
+    }
+    if let Some(tag) = list {
+        html.push_str(&format!("</{tag}>\n"));
+    }
+    html
+}
+
+/// Level of a heading.
+///
+/// # Arguments
+///
+/// * `line` - Line (with surrounding whitespace removed).
+///
+/// # Returns
+///
+/// Level of the heading (`None` if the line is not a heading).
+fn heading_level(line: &str) -> Option<usize> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    ((1..=6).contains(&level) && line[level..].starts_with(' ')).then_some(level)
+}
+
+/// Determine whether a line is a horizontal rule.
+///
+/// # Arguments
+///
+/// * `line` - Line (with surrounding whitespace removed).
+///
+/// # Returns
+///
+/// `true` if the line is a horizontal rule, `false` otherwise.
+fn is_rule(line: &str) -> bool {
+    let line: Vec<char> = line.chars().filter(|c| !c.is_whitespace()).collect();
+    line.len() >= 3 && ['-', '*', '_'].iter().any(|c| line.iter().all(|d| d == c))
+}
+
+/// Parse a list item.
+///
+/// # Arguments
+///
+/// * `line` - Line (with surrounding whitespace removed).
+///
+/// # Returns
+///
+/// HTML tag of the list (`"ul"` or `"ol"`) and the text of the item (`None` if the line is not a
+/// list item).
+fn list_item(line: &str) -> Option<(&'static str, &str)> {
+    if is_rule(line) {
+        return None;
+    }
+    if let Some(item) = ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|marker| line.strip_prefix(marker))
+    {
+        return Some(("ul", item.trim()));
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits > 0 && line[digits..].starts_with(". ") {
+        return Some(("ol", line[digits + 2..].trim()));
+    }
+    None
+}
+
+/// Determine whether a line is an item of a list.
+///
+/// # Arguments
+///
+/// * `line` - Line.
+/// * `tag` - HTML tag of the list (`"ul"` or `"ol"`).
+///
+/// # Returns
+///
+/// `true` if the line is an item of the list, `false` otherwise.
+fn is_item(line: &str, tag: &str) -> bool {
+    list_item(line.trim()).is_some_and(|(item_tag, _)| item_tag == tag)
+}
+
+/// Convert inline Markdown (code, bold, italic, and links) to HTML.
+///
+/// # Arguments
+///
+/// * `text` - Markdown text.
+///
+/// # Returns
+///
+/// HTML.
+fn inline(text: &str) -> String {
+    let mut html = String::new();
+    let mut rest = text;
+    let mut bold = false;
+    let mut italic = false;
+    while let Some(c) = rest.chars().next() {
+        if c == '`'
+            && let Some(end) = rest[1..].find('`')
+        {
+            html.push_str(&format!("<code>{}</code>", escape(&rest[1..end + 1])));
+            rest = &rest[end + 2..];
+        } else if let Some(after) = rest.strip_prefix("**") {
+            html.push_str(if bold { "</strong>" } else { "<strong>" });
+            bold = !bold;
+            rest = after;
+        } else if (c == '*' || c == '_') && (italic || rest[1..].contains(c)) {
+            html.push_str(if italic { "</em>" } else { "<em>" });
+            italic = !italic;
+            rest = &rest[1..];
+        } else if c == '['
+            && let Some((label, url, length)) = link(rest)
+        {
+            if is_safe_url(url) {
+                html.push_str(&format!(
+                    "<a href=\"{}\">{}</a>",
+                    escape(url),
+                    inline(label)
+                ));
+            } else {
+                html.push_str(&escape(&rest[..length]));
+            }
+            rest = &rest[length..];
+        } else {
+            html.push_str(&escape(&rest[..c.len_utf8()]));
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    if italic {
+        html.push_str("</em>");
+    }
+    if bold {
+        html.push_str("</strong>");
+    }
+    html
+}
+
+/// Parse a link (`[label](url)`) at the start of a text.
+///
+/// # Arguments
+///
+/// * `text` - Text starting with `[`.
+///
+/// # Returns
+///
+/// Label and URL of the link, and the length of the link in the text (`None` if the text does not
+/// start with a link).
+fn link(text: &str) -> Option<(&str, &str, usize)> {
+    let label_end = text.find("](")?;
+    let url_end = label_end + 2 + text[label_end + 2..].find(')')?;
+    Some((
+        &text[1..label_end],
+        &text[label_end + 2..url_end],
+        url_end + 1,
+    ))
+}
+
+/// Check whether the URL of a link is safe to follow (i.e. whether it is relative or uses the
+/// `http`, `https`, or `mailto` scheme, so that e.g. `javascript:` URLs are not rendered as links).
+///
+/// # Arguments
+///
+/// * `url` - URL.
+///
+/// # Returns
+///
+/// Whether the URL is safe.
+fn is_safe_url(url: &str) -> bool {
+    let scheme_end = url.find(['/', '?', '#']).unwrap_or(url.len());
+    match url[..scheme_end].find(':') {
+        Some(colon) => ["http", "https", "mailto"]
+            .iter()
+            .any(|scheme| url[..colon].eq_ignore_ascii_case(scheme)),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks() {
+        let markdown = "# Results\n\nFirst line\nsecond line.\n\n- a\n- b\n\n1. c\n2. d\n\n---\n\n> Note\n\n```rust\nlet x = 1 < 2;\n```";
+        assert_eq!(
+            to_html(markdown),
+            "<h1>Results</h1>\n\
+             <p>First line second line.</p>\n\
+             <ul>\n<li>a</li>\n<li>b</li>\n</ul>\n\
+             <ol>\n<li>c</li>\n<li>d</li>\n</ol>\n\
+             <hr>\n\
+             <blockquote>Note</blockquote>\n\
+             <pre><code class=\"language-rust\">let x = 1 &lt; 2;</code></pre>\n"
+        );
+    }
+
+    #[test]
+    fn test_unsupported() {
+        // Nested lists are flattened, and tables and indented code blocks are plain text.
+        assert_eq!(
+            to_html("- a\n  - b\n\n| x | y |\n\n    code"),
+            "<ul>\n<li>a</li>\n<li>b</li>\n</ul>\n<p>| x | y |</p>\n<p>code</p>\n"
+        );
+
+        // Images are links, and backslash escapes are kept.
+        assert_eq!(
+            inline("![plot](plot.png) \\*"),
+            "!<a href=\"plot.png\">plot</a> \\*"
+        );
+    }
+
+    #[test]
+    fn test_link_schemes() {
+        assert_eq!(
+            inline("[a](mailto:a@example.com) [b](docs/b.html#c) [c](HTTP://example.com)"),
+            "<a href=\"mailto:a@example.com\">a</a> <a href=\"docs/b.html#c\">b</a> \
+             <a href=\"HTTP://example.com\">c</a>"
+        );
+
+        // Links with other schemes are plain text.
+        assert_eq!(
+            inline("[x](javascript:alert(1)) [y](data:text/html,<b>)"),
+            "[x](javascript:alert(1)) [y](data:text/html,&lt;b&gt;)"
+        );
+    }
+
+    #[test]
+    fn test_inline() {
+        assert_eq!(
+            inline("**bold**, *italic*, `a*b`, [link](https://example.com) & <b>"),
+            "<strong>bold</strong>, <em>italic</em>, <code>a*b</code>, \
+             <a href=\"https://example.com\">link</a> &amp; &lt;b&gt;"
+        );
+        assert_eq!(inline("2 * 3"), "2 * 3");
+        assert_eq!(inline("snake_case"), "snake_case");
+    }
+}
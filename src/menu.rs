@@ -0,0 +1,645 @@
+use crate::axis_type::AxisType;
+use crate::trace::Trace;
+use plotly::layout::AxisType as PlotlyAxisType;
+use plotly::layout::update_menu::{Button, ButtonMethod, UpdateMenu, UpdateMenuType};
+use serde_json::{Map, Value};
+
+/// Reference to a trace of a figure, either by name or by (zero-based) index.
+///
+/// A reference by name refers to all traces with that name.
+///
+/// # Example
+///
+/// ```
+/// use plotting::TraceRef;
+///
+/// let by_name = TraceRef::from("Nominal");
+/// let by_index = TraceRef::from(2);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum TraceRef {
+    /// Trace name.
+    Name(String),
+
+    /// Trace index (zero-based).
+    Index(usize),
+}
+
+impl From<&str> for TraceRef {
+    fn from(name: &str) -> Self {
+        TraceRef::Name(String::from(name))
+    }
+}
+
+impl From<String> for TraceRef {
+    fn from(name: String) -> Self {
+        TraceRef::Name(name)
+    }
+}
+
+impl From<usize> for TraceRef {
+    fn from(index: usize) -> Self {
+        TraceRef::Index(index)
+    }
+}
+
+impl TraceRef {
+    /// Determine whether this reference refers to a trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the trace.
+    /// * `trace` - Trace.
+    ///
+    /// # Returns
+    ///
+    /// `true` if this reference refers to the trace, `false` otherwise.
+    fn matches(&self, index: usize, trace: &Trace) -> bool {
+        match self {
+            TraceRef::Name(name) => trace.name.as_ref() == Some(name),
+            TraceRef::Index(trace_index) => *trace_index == index,
+        }
+    }
+}
+
+/// Style of a menu.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum MenuStyle {
+    /// Dropdown listing the buttons of the menu.
+    #[default]
+    Dropdown,
+
+    /// Row of buttons.
+    Buttons,
+}
+
+/// Change applied to the traces of a figure by a menu button.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub(crate) enum Restyle {
+    /// Show the referenced traces and hide all other traces.
+    ShowOnly(Vec<TraceRef>),
+
+    /// Show or hide the referenced traces (other traces are left unchanged).
+    Visible(Vec<TraceRef>, bool),
+
+    /// Set a plotly.js attribute of the referenced traces.
+    Attribute(Vec<TraceRef>, String, Value),
+}
+
+/// Button of a menu (see [`Menu`]).
+///
+/// Clicking a button shows or hides traces, restyles traces, and/or changes the layout of the
+/// figure (e.g. switching between linear and logarithmic axes).
+///
+/// # Example
+///
+/// ```
+/// use plotting::{AxisType, MenuButton};
+///
+/// // Show only the traces of the "hot case" scenario, on a logarithmic y-axis.
+/// let button = MenuButton::new("Hot case")
+///     .show_only(["Hot case (nominal)", "Hot case (worst)"])
+///     .y_axis_type(AxisType::Log);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MenuButton {
+    /// Label of the button.
+    pub(crate) label: String,
+
+    /// Changes applied to the traces of the figure.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) restyles: Vec<Restyle>,
+
+    /// Changes applied to the layout of the figure (plotly.js layout attributes and their values).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) relayouts: Vec<(String, Value)>,
+}
+
+impl MenuButton {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label of the button.
+    ///
+    /// # Returns
+    ///
+    /// Button without any effect.
+    pub fn new(label: impl Into<String>) -> MenuButton {
+        MenuButton {
+            label: label.into(),
+            restyles: Vec::new(),
+            relayouts: Vec::new(),
+        }
+    }
+
+    /// Show only the referenced traces (all other traces are hidden).
+    ///
+    /// # Arguments
+    ///
+    /// * `traces` - References to traces (see [`TraceRef`]).
+    ///
+    /// # Returns
+    ///
+    /// The button showing only the referenced traces.
+    pub fn show_only<T: Into<TraceRef>>(mut self, traces: impl IntoIterator<Item = T>) -> Self {
+        let traces = traces.into_iter().map(Into::into).collect();
+        self.restyles.push(Restyle::ShowOnly(traces));
+        self
+    }
+
+    /// Show the referenced traces (other traces are shown or hidden as initially, see
+    /// [`crate::Trace::visible`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `traces` - References to traces (see [`TraceRef`]).
+    ///
+    /// # Returns
+    ///
+    /// The button showing the referenced traces.
+    pub fn show<T: Into<TraceRef>>(mut self, traces: impl IntoIterator<Item = T>) -> Self {
+        let traces = traces.into_iter().map(Into::into).collect();
+        self.restyles.push(Restyle::Visible(traces, true));
+        self
+    }
+
+    /// Hide the referenced traces (other traces are shown or hidden as initially, see
+    /// [`crate::Trace::visible`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `traces` - References to traces (see [`TraceRef`]).
+    ///
+    /// # Returns
+    ///
+    /// The button hiding the referenced traces.
+    pub fn hide<T: Into<TraceRef>>(mut self, traces: impl IntoIterator<Item = T>) -> Self {
+        let traces = traces.into_iter().map(Into::into).collect();
+        self.restyles.push(Restyle::Visible(traces, false));
+        self
+    }
+
+    /// Set a plotly.js attribute of the referenced traces.
+    ///
+    /// # Arguments
+    ///
+    /// * `traces` - References to traces (see [`TraceRef`]).
+    /// * `attribute` - plotly.js trace attribute, with nested attributes separated by dots (e.g.
+    ///   `"line.color"`, `"line.width"`, or `"mode"`).
+    /// * `value` - Value of the attribute.
+    ///
+    /// # Returns
+    ///
+    /// The button restyling the referenced traces.
+    ///
+    /// # Note
+    ///
+    /// The traces that are not referenced keep (or get back) the value of the attribute that they
+    /// have in the figure, so that the buttons of a menu that restyle the same attribute of
+    /// different traces do not interfere with each other.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::MenuButton;
+    ///
+    /// let button = MenuButton::new("Highlight nominal")
+    ///     .restyle(["Nominal"], "line.width", 4)
+    ///     .restyle(["Nominal"], "line.color", "red");
+    /// ```
+    pub fn restyle<T: Into<TraceRef>>(
+        mut self,
+        traces: impl IntoIterator<Item = T>,
+        attribute: impl Into<String>,
+        value: impl Into<Value>,
+    ) -> Self {
+        let traces = traces.into_iter().map(Into::into).collect();
+        self.restyles
+            .push(Restyle::Attribute(traces, attribute.into(), value.into()));
+        self
+    }
+
+    /// Set the type of the x-axis (e.g. to switch between linear and logarithmic axes).
+    ///
+    /// # Arguments
+    ///
+    /// * `x_axis_type` - Type of the x-axis.
+    ///
+    /// # Returns
+    ///
+    /// The button setting the type of the x-axis.
+    pub fn x_axis_type(self, x_axis_type: AxisType) -> Self {
+        self.relayout("xaxis.type", axis_type(x_axis_type))
+    }
+
+    /// Set the type of the (primary) y-axis (e.g. to switch between linear and logarithmic axes).
+    ///
+    /// # Arguments
+    ///
+    /// * `y_axis_type` - Type of the y-axis.
+    ///
+    /// # Returns
+    ///
+    /// The button setting the type of the y-axis.
+    pub fn y_axis_type(self, y_axis_type: AxisType) -> Self {
+        self.relayout("yaxis.type", axis_type(y_axis_type))
+    }
+
+    /// Set the title of the figure.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Title of the figure.
+    ///
+    /// # Returns
+    ///
+    /// The button setting the title of the figure.
+    pub fn title(self, title: impl Into<String>) -> Self {
+        self.relayout("title.text", title.into())
+    }
+
+    /// Set a plotly.js layout attribute of the figure.
+    ///
+    /// # Arguments
+    ///
+    /// * `attribute` - plotly.js layout attribute, with nested attributes separated by dots (e.g.
+    ///   `"yaxis.range"`).
+    /// * `value` - Value of the attribute.
+    ///
+    /// # Returns
+    ///
+    /// The button setting the layout attribute.
+    pub fn relayout(mut self, attribute: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.relayouts.push((attribute.into(), value.into()));
+        self
+    }
+
+    /// Create a plotly button from this button.
+    ///
+    /// # Arguments
+    ///
+    /// * `traces` - Traces of the figure that the menu of the button is placed on.
+    ///
+    /// # Returns
+    ///
+    /// Plotly button.
+    ///
+    /// # Panics
+    ///
+    /// If the button references a trace that the figure does not have.
+    fn plotly(&self, traces: &[Trace]) -> Button {
+        let (method, args) = self.plotly_method(traces);
+        Button::new()
+            .label(self.label.as_str())
+            .method(method)
+            .args(args)
+    }
+
+    /// Get the plotly.js method called by this button, and its arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `traces` - Traces of the figure that the button is placed on.
+    ///
+    /// # Returns
+    ///
+    /// plotly.js method and its arguments.
+    ///
+    /// # Panics
+    ///
+    /// If the button references a trace that the figure does not have.
+    pub(crate) fn plotly_method(&self, traces: &[Trace]) -> (ButtonMethod, Value) {
+        // Trace attributes are set for all traces of the figure (so that no trace indices have to
+        // be passed to plotly.js).
+        let mut restyle = Map::new();
+        for change in &self.restyles {
+            let (references, attribute) = match change {
+                Restyle::ShowOnly(references) | Restyle::Visible(references, _) => {
+                    (references, "visible")
+                }
+                Restyle::Attribute(references, attribute, _) => (references, attribute.as_str()),
+            };
+            for reference in references {
+                assert!(
+                    traces
+                        .iter()
+                        .enumerate()
+                        .any(|(index, trace)| reference.matches(index, trace)),
+                    "Menu button '{}' references trace {reference:?}, which the figure does not \
+                     have.",
+                    self.label
+                );
+            }
+            let values = restyle.entry(attribute).or_insert_with(|| {
+                Value::Array(
+                    traces
+                        .iter()
+                        .map(|trace| match attribute {
+                            "visible" => Value::Bool(trace.visible.unwrap_or(true)),
+                            _ => trace.plotly_attribute(attribute),
+                        })
+                        .collect(),
+                )
+            });
+            let Value::Array(values) = values else {
+                unreachable!();
+            };
+            for (index, (trace, value)) in traces.iter().zip(values.iter_mut()).enumerate() {
+                let referenced = references
+                    .iter()
+                    .any(|reference| reference.matches(index, trace));
+                match change {
+                    Restyle::ShowOnly(_) => *value = Value::Bool(referenced),
+                    Restyle::Visible(_, visible) if referenced => *value = Value::Bool(*visible),
+                    Restyle::Attribute(_, _, new_value) if referenced => *value = new_value.clone(),
+                    _ => {}
+                }
+            }
+        }
+        let relayout: Map<String, Value> = self.relayouts.iter().cloned().collect();
+
+        match (restyle.is_empty(), relayout.is_empty()) {
+            (true, true) => (ButtonMethod::Skip, Value::Array(Vec::new())),
+            (false, true) => (ButtonMethod::Restyle, Value::from(vec![restyle])),
+            (true, false) => (ButtonMethod::Relayout, Value::from(vec![relayout])),
+            (false, false) => (ButtonMethod::Update, Value::from(vec![restyle, relayout])),
+        }
+    }
+}
+
+/// plotly.js value of an axis type.
+///
+/// # Arguments
+///
+/// * `axis_type` - Axis type.
+///
+/// # Returns
+///
+/// plotly.js value of the axis type.
+fn axis_type(axis_type: AxisType) -> Value {
+    serde_json::to_value(PlotlyAxisType::from(axis_type))
+        .expect("Serializing an axis type should not fail.")
+}
+
+/// Interactive menu (a dropdown or a row of buttons) placed on a figure.
+///
+/// Menus are only shown in HTML output (e.g. [`crate::Figure::show`] or
+/// [`crate::Figure::save_html`]), where they make it possible to explore several scenarios in a
+/// single figure.
+///
+/// # Example
+///
+/// ```
+/// use plotting::{AxisType, Figure, Format, Menu, MenuButton, MenuStyle, Trace};
+///
+/// let time = [0.0, 1.0, 2.0, 3.0];
+/// let figure = Figure::new(
+///     vec![
+///         Trace::new_2d(time, [1.0, 10.0, 100.0, 1000.0]).name("Hot"),
+///         Trace::new_2d(time, [1.0, 2.0, 4.0, 8.0]).name("Cold"),
+///     ],
+///     Format::default(),
+/// )
+/// .add_menu(
+///     Menu::new()
+///         .add_button(MenuButton::new("All").show_only([0, 1]))
+///         .add_button(MenuButton::new("Hot").show_only(["Hot"]))
+///         .add_button(MenuButton::new("Cold").show_only(["Cold"])),
+/// )
+/// .add_menu(
+///     Menu::new()
+///         .style(MenuStyle::Buttons)
+///         .position(0.5, 1.15)
+///         .add_button(MenuButton::new("Linear").y_axis_type(AxisType::Linear))
+///         .add_button(MenuButton::new("Log").y_axis_type(AxisType::Log)),
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Menu {
+    /// Style of the menu.
+    pub(crate) style: MenuStyle,
+
+    /// Buttons.
+    pub(crate) buttons: Vec<MenuButton>,
+
+    /// Index of the button that is initially active.
+    pub(crate) active: usize,
+
+    /// Position of the menu (in normalized coordinates of the plotting area).
+    pub(crate) position: Option<(f64, f64)>,
+}
+
+impl Menu {
+    /// Constructor.
+    ///
+    /// # Returns
+    ///
+    /// Dropdown menu without buttons.
+    pub fn new() -> Menu {
+        Menu::default()
+    }
+
+    /// Set the style of the menu.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - Style of the menu (defaults to [`MenuStyle::Dropdown`]).
+    ///
+    /// # Returns
+    ///
+    /// The menu with the style set.
+    pub fn style(mut self, style: MenuStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Add a button to the menu.
+    ///
+    /// # Arguments
+    ///
+    /// * `button` - Button.
+    ///
+    /// # Returns
+    ///
+    /// The menu with the button added.
+    pub fn add_button(mut self, button: MenuButton) -> Self {
+        self.buttons.push(button);
+        self
+    }
+
+    /// Set the button that is initially active.
+    ///
+    /// # Arguments
+    ///
+    /// * `active` - Index of the button that is initially active (defaults to `0`).
+    ///
+    /// # Returns
+    ///
+    /// The menu with the active button set.
+    ///
+    /// # Note
+    ///
+    /// Setting the active button only highlights it; it does not apply its changes to the figure.
+    pub fn active(mut self, active: usize) -> Self {
+        self.active = active;
+        self
+    }
+
+    /// Set the position of the menu.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Horizontal position of the left edge of the menu (in normalized coordinates of the
+    ///   plotting area, i.e. `0` is the left edge and `1` is the right edge).
+    /// * `y` - Vertical position of the top edge of the menu (in normalized coordinates of the
+    ///   plotting area, i.e. `0` is the bottom edge and `1` is the top edge).
+    ///
+    /// # Returns
+    ///
+    /// The menu with the position set.
+    pub fn position(mut self, x: f64, y: f64) -> Self {
+        self.position = Some((x, y));
+        self
+    }
+
+    /// Create a plotly menu from this menu.
+    ///
+    /// # Arguments
+    ///
+    /// * `traces` - Traces of the figure that the menu is placed on.
+    ///
+    /// # Returns
+    ///
+    /// Plotly menu.
+    ///
+    /// # Panics
+    ///
+    /// If a button of the menu references a trace that the figure does not have.
+    pub(crate) fn plotly(&self, traces: &[Trace]) -> UpdateMenu {
+        let mut menu = UpdateMenu::new()
+            .ty(match self.style {
+                MenuStyle::Dropdown => UpdateMenuType::Dropdown,
+                MenuStyle::Buttons => UpdateMenuType::Buttons,
+            })
+            .active(self.active as i32)
+            .buttons(
+                self.buttons
+                    .iter()
+                    .map(|button| button.plotly(traces))
+                    .collect(),
+            );
+        if let Some((x, y)) = self.position {
+            menu = menu
+                .x(x)
+                .y(y)
+                .x_anchor(plotly::common::Anchor::Left)
+                .y_anchor(plotly::common::Anchor::Top);
+        }
+        menu
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colors::{Color, NamedColor};
+
+    /// Traces of a figure.
+    ///
+    /// # Returns
+    ///
+    /// Traces.
+    fn traces() -> Vec<Trace> {
+        vec![
+            Trace::new_2d([0.0], [1.0]).name("a"),
+            Trace::new_2d([0.0], [2.0])
+                .name("b")
+                .line_color(Color::named(NamedColor::Red)),
+            Trace::new_2d([0.0], [3.0]).name("a"),
+        ]
+    }
+
+    /// Serialize a plotly button to JSON.
+    ///
+    /// # Arguments
+    ///
+    /// * `button` - Button.
+    ///
+    /// # Returns
+    ///
+    /// JSON value of the plotly button.
+    fn json(button: &MenuButton) -> Value {
+        serde_json::to_value(button.plotly(&traces())).unwrap()
+    }
+
+    #[test]
+    fn test_visibility() {
+        let button = json(&MenuButton::new("A").show_only(["a"]));
+        assert_eq!(button["method"], "restyle");
+        assert_eq!(button["label"], "A");
+        assert_eq!(
+            button["args"],
+            serde_json::json!([{"visible": [true, false, true]}])
+        );
+        let button = json(&MenuButton::new("Hide").hide([2]).show(["b"]));
+        assert_eq!(
+            button["args"],
+            serde_json::json!([{"visible": [true, true, false]}])
+        );
+    }
+
+    #[test]
+    fn test_restyle_and_relayout() {
+        let button = json(
+            &MenuButton::new("Log")
+                .restyle([0], "line.color", "blue")
+                .y_axis_type(AxisType::Log)
+                .title("Log scale"),
+        );
+        assert_eq!(button["method"], "update");
+        assert_eq!(
+            button["args"][0]["line.color"],
+            serde_json::json!(["blue", "rgba(255, 0, 0, 1)", null])
+        );
+        assert_eq!(button["args"][1]["yaxis.type"], "log");
+        assert_eq!(button["args"][1]["title.text"], "Log scale");
+
+        let button = json(&MenuButton::new("Linear").x_axis_type(AxisType::Linear));
+        assert_eq!(button["method"], "relayout");
+        assert_eq!(
+            button["args"],
+            serde_json::json!([{"xaxis.type": "linear"}])
+        );
+    }
+
+    #[test]
+    fn test_menu() {
+        let menu = Menu::new()
+            .style(MenuStyle::Buttons)
+            .active(1)
+            .position(0.1, 1.1)
+            .add_button(MenuButton::new("A").show_only(["a"]))
+            .add_button(MenuButton::new("B").show_only(["b"]));
+        let json = serde_json::to_value(menu.plotly(&traces())).unwrap();
+        assert_eq!(json["type"], "buttons");
+        assert_eq!(json["active"], 1);
+        assert_eq!(json["x"], 0.1);
+        assert_eq!(json["buttons"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unknown_trace() {
+        json(&MenuButton::new("C").show_only(["c"]));
+    }
+}
@@ -0,0 +1,37 @@
+use plotly::common::Mode as PlotlyMode;
+
+/// Drawing mode of a scatter plot.
+///
+/// # Note
+///
+/// This enum is a partial re-implementation of the [`plotly::common::Mode`] enum from the
+/// [`plotly`] crate (Ref. \[1\]). As such, we have included the license of the [`plotly`] crate in
+/// the [`src/plotly_licenses`](https://github.com/tamaskis/plotting/tree/main/src/plotly_licenses/LICENSE)
+/// folder.
+///
+/// # References
+///
+/// * \[1\] <https://docs.rs/plotly/latest/plotly/common/enum.Mode.html>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Mode {
+    /// Lines connecting the data points.
+    Lines,
+
+    /// Markers at the data points (e.g. for dot plots).
+    Markers,
+
+    /// Lines connecting the data points, with markers at the data points.
+    LinesMarkers,
+}
+
+impl From<Mode> for PlotlyMode {
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::Lines => PlotlyMode::Lines,
+            Mode::Markers => PlotlyMode::Markers,
+            Mode::LinesMarkers => PlotlyMode::LinesMarkers,
+        }
+    }
+}
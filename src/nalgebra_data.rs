@@ -0,0 +1,67 @@
+use crate::data_source::{DataElement, DataSource};
+use crate::trace::Trace;
+use nalgebra::{Dim, Matrix, RawStorage};
+
+impl Trace {
+    /// Constructor for a set of 2D traces from the columns of a [`nalgebra`] matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - x-axis data (shared by all of the traces, see [`DataSource`]).
+    /// * `y` - Matrix whose columns are the y-axis data of each trace.
+    ///
+    /// # Returns
+    ///
+    /// Traces (one per column of `y`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nalgebra::DMatrix;
+    /// use plotting::Trace;
+    ///
+    /// let y = DMatrix::from_column_slice(3, 2, &[1.0, 2.0, 3.0, 2.0, 4.0, 6.0]);
+    /// let traces: Vec<Trace> = Trace::from_nalgebra_columns([0.0, 1.0, 2.0], &y);
+    /// ```
+    pub fn from_nalgebra_columns<T, R, C, S>(
+        x: impl DataSource,
+        y: &Matrix<T, R, C, S>,
+    ) -> Vec<Trace>
+    where
+        R: Dim,
+        C: Dim,
+        S: RawStorage<T, R, C>,
+        for<'a> &'a T: DataElement,
+    {
+        let x = x.into_axis_data();
+        y.column_iter()
+            .map(|column| Trace::new_2d(x.clone(), column.iter()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{DMatrix, DVector};
+
+    #[test]
+    fn test_data_source() {
+        let x = DVector::from_vec(vec![1.0, 2.0, 3.0]);
+        let y = DVector::from_vec(vec![4_i32, 5, 6]);
+        let trace = Trace::new_2d(&x, &y);
+        assert_eq!(trace.x, [1.0, 2.0, 3.0]);
+        assert_eq!(trace.y, [4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn test_from_nalgebra_columns() {
+        let y = DMatrix::from_column_slice(3, 2, &[1.0, 2.0, 3.0, 2.0, 4.0, 6.0]);
+        let traces = Trace::from_nalgebra_columns([0.0, 1.0, 2.0], &y);
+        assert_eq!(traces.len(), 2);
+        assert_eq!(traces[0].x, [0.0, 1.0, 2.0]);
+        assert_eq!(traces[0].y, [1.0, 2.0, 3.0]);
+        assert_eq!(traces[1].x, [0.0, 1.0, 2.0]);
+        assert_eq!(traces[1].y, [2.0, 4.0, 6.0]);
+    }
+}
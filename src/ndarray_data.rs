@@ -0,0 +1,66 @@
+use crate::data_source::{DataElement, DataSource};
+use crate::trace::Trace;
+use ndarray::{ArrayBase, Data, Ix2};
+
+impl Trace {
+    /// Constructor for a set of 2D traces from the columns of a 2D [`ndarray`] array.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - x-axis data (shared by all of the traces, see [`DataSource`]).
+    /// * `y` - 2D array whose columns are the y-axis data of each trace.
+    ///
+    /// # Returns
+    ///
+    /// Traces (one per column of `y`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use plotting::Trace;
+    ///
+    /// let y = array![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+    /// let traces: Vec<Trace> = Trace::from_ndarray_columns([0.0, 1.0, 2.0], &y);
+    /// ```
+    pub fn from_ndarray_columns<S>(x: impl DataSource, y: &ArrayBase<S, Ix2>) -> Vec<Trace>
+    where
+        S: Data,
+        for<'a> &'a S::Elem: DataElement,
+    {
+        let x = x.into_axis_data();
+        y.columns()
+            .into_iter()
+            .map(|column| Trace::new_2d(x.clone(), column))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{Array1, array};
+
+    #[test]
+    fn test_data_source() {
+        let x: Array1<f64> = array![1.0, 2.0, 3.0];
+        let y: Array1<f32> = array![4.0, 5.0, 6.0];
+        let trace = Trace::new_2d(&x, y.view());
+        assert_eq!(trace.x, [1.0, 2.0, 3.0]);
+        assert_eq!(trace.y, [4.0, 5.0, 6.0]);
+        let trace = Trace::new_2d(x, y);
+        assert_eq!(trace.x, [1.0, 2.0, 3.0]);
+        assert_eq!(trace.y, [4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn test_from_ndarray_columns() {
+        let y = array![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+        let traces = Trace::from_ndarray_columns([0.0, 1.0, 2.0], &y);
+        assert_eq!(traces.len(), 2);
+        assert_eq!(traces[0].x, [0.0, 1.0, 2.0]);
+        assert_eq!(traces[0].y, [1.0, 2.0, 3.0]);
+        assert_eq!(traces[1].x, [0.0, 1.0, 2.0]);
+        assert_eq!(traces[1].y, [2.0, 4.0, 6.0]);
+    }
+}
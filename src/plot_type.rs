@@ -0,0 +1,15 @@
+/// Type of plot used to draw a trace.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PlotType {
+    /// Scatter plot (i.e. lines and/or markers, see [`crate::Mode`]).
+    #[default]
+    Scatter,
+
+    /// Bar chart (one bar per data point).
+    Bar,
+
+    /// Box plot (one box per distinct x-axis value, summarizing the corresponding y-axis values).
+    Box,
+}
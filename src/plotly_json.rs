@@ -0,0 +1,728 @@
+use crate::annotation::{Alignment, Annotation};
+use crate::axis_data::AxisData;
+use crate::axis_format::{AxisFormat, AxisSide};
+use crate::axis_type::AxisType;
+use crate::category_order::CategoryOrder;
+use crate::colors::Color;
+use crate::coordinates::Coordinates;
+use crate::error::Error;
+use crate::figure::Figure;
+use crate::fill::Fill;
+use crate::format::Format;
+use crate::line_style::LineStyle;
+use crate::mode::Mode;
+use crate::plot_type::PlotType;
+use crate::range_break::RangeBreak;
+use crate::shape::{Layer, Shape, ShapeKind};
+use crate::trace::Trace;
+use serde_json::{Map, Value};
+
+impl Figure {
+    /// Create a figure from a plotly.js figure specification in JSON format.
+    ///
+    /// Only the subset of the plotly.js figure specification that can be represented by a
+    /// [`Figure`] is supported (i.e. the subset produced by [`Figure::to_plotly_json`]). Attributes
+    /// outside of this subset are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - plotly.js figure specification (an object with `data` and `layout` fields).
+    ///
+    /// # Returns
+    ///
+    /// Figure.
+    ///
+    /// # Errors
+    ///
+    /// If the JSON is malformed, or if it contains traces of an unsupported type (supported types
+    /// are `scatter`, `scattergl`, `scatter3d`, `bar`, and `box`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{Figure, quick_plot_2d};
+    ///
+    /// let json = quick_plot_2d([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]).to_plotly_json();
+    /// let fig = Figure::from_plotly_json(&json).unwrap();
+    /// ```
+    pub fn from_plotly_json(json: &str) -> Result<Figure, Error> {
+        let spec: Value = serde_json::from_str(json)?;
+        let spec = spec
+            .as_object()
+            .ok_or_else(|| invalid("the figure specification must be an object"))?;
+        let empty = Map::new();
+        let layout = spec
+            .get("layout")
+            .and_then(Value::as_object)
+            .unwrap_or(&empty);
+
+        // Traces.
+        let traces = match spec.get("data") {
+            Some(Value::Array(data)) => data
+                .iter()
+                .map(parse_trace)
+                .collect::<Result<Vec<Trace>, Error>>()?,
+            Some(Value::Null) | None => Vec::new(),
+            Some(_) => return Err(invalid("\"data\" must be an array of traces")),
+        };
+
+        // Figure.
+        let mut fig = Figure::new(traces, parse_format(layout));
+        for annotation in array(layout, "annotations") {
+            if let Some(annotation) = annotation.as_object().and_then(parse_annotation) {
+                fig = fig.add_annotation(annotation);
+            }
+        }
+        for shape in array(layout, "shapes") {
+            if let Some(shape) = shape.as_object().and_then(parse_shape) {
+                fig = fig.add_shape(shape);
+            }
+        }
+        Ok(fig)
+    }
+}
+
+/// Create an error for an invalid or unsupported plotly.js figure specification.
+///
+/// # Arguments
+///
+/// * `message` - Description of the error.
+///
+/// # Returns
+///
+/// Error.
+fn invalid(message: impl Into<String>) -> Error {
+    Error::PlotlyJson(message.into())
+}
+
+/// Get an array-valued attribute.
+///
+/// # Arguments
+///
+/// * `object` - JSON object.
+/// * `key` - Attribute name.
+///
+/// # Returns
+///
+/// Elements of the array (empty if the attribute is missing or not an array).
+fn array<'a>(object: &'a Map<String, Value>, key: &str) -> &'a [Value] {
+    object
+        .get(key)
+        .and_then(Value::as_array)
+        .map_or(&[], Vec::as_slice)
+}
+
+/// Get a number-valued attribute.
+///
+/// # Arguments
+///
+/// * `object` - JSON object.
+/// * `key` - Attribute name.
+///
+/// # Returns
+///
+/// Number (`None` if the attribute is missing or not a number).
+fn number(object: &Map<String, Value>, key: &str) -> Option<f64> {
+    object.get(key).and_then(Value::as_f64)
+}
+
+/// Get a string-valued attribute.
+///
+/// # Arguments
+///
+/// * `object` - JSON object.
+/// * `key` - Attribute name.
+///
+/// # Returns
+///
+/// String (`None` if the attribute is missing or not a string).
+fn string<'a>(object: &'a Map<String, Value>, key: &str) -> Option<&'a str> {
+    object.get(key).and_then(Value::as_str)
+}
+
+/// Get an object-valued attribute.
+///
+/// # Arguments
+///
+/// * `object` - JSON object.
+/// * `key` - Attribute name.
+///
+/// # Returns
+///
+/// Object (`None` if the attribute is missing or not an object).
+fn object<'a>(object: &'a Map<String, Value>, key: &str) -> Option<&'a Map<String, Value>> {
+    object.get(key).and_then(Value::as_object)
+}
+
+/// Get a color-valued attribute.
+///
+/// # Arguments
+///
+/// * `object` - JSON object.
+/// * `key` - Attribute name.
+///
+/// # Returns
+///
+/// Color (`None` if the attribute is missing or not a CSS color string).
+fn color(object: &Map<String, Value>, key: &str) -> Option<Color> {
+    string(object, key).and_then(Color::from_css)
+}
+
+/// Get a pair of numbers (e.g. an axis range).
+///
+/// # Arguments
+///
+/// * `object` - JSON object.
+/// * `key` - Attribute name.
+///
+/// # Returns
+///
+/// Pair of numbers (`None` if the attribute is missing or not an array of two numbers).
+fn pair(object: &Map<String, Value>, key: &str) -> Option<(f64, f64)> {
+    match array(object, key) {
+        [first, second] => Some((first.as_f64()?, second.as_f64()?)),
+        _ => None,
+    }
+}
+
+/// Get a title (which may either be a string or an object with a `text` attribute).
+///
+/// # Arguments
+///
+/// * `object` - JSON object.
+///
+/// # Returns
+///
+/// Title (`None` if there is no title).
+fn title(object: &Map<String, Value>) -> Option<String> {
+    match object.get("title")? {
+        Value::String(title) => Some(title.clone()),
+        Value::Object(title) => string(title, "text").map(String::from),
+        _ => None,
+    }
+}
+
+/// Parse axis data.
+///
+/// # Arguments
+///
+/// * `values` - JSON array of values.
+///
+/// # Returns
+///
+/// Axis data (categorical if any of the values are strings, numeric otherwise, with `null` values
+/// treated as gaps).
+fn parse_axis_data(values: &[Value]) -> AxisData {
+    if values.iter().any(Value::is_string) {
+        AxisData::Categorical(
+            values
+                .iter()
+                .map(|value| match value {
+                    Value::String(value) => value.clone(),
+                    Value::Null => String::new(),
+                    value => value.to_string(),
+                })
+                .collect(),
+        )
+    } else {
+        AxisData::Numeric(
+            values
+                .iter()
+                .map(|value| value.as_f64().unwrap_or(f64::NAN))
+                .collect(),
+        )
+    }
+}
+
+/// Parse a line style.
+///
+/// # Arguments
+///
+/// * `dash` - plotly.js dash style.
+///
+/// # Returns
+///
+/// Line style (`None` if the dash style is not supported).
+fn parse_line_style(dash: &str) -> Option<LineStyle> {
+    match dash {
+        "solid" => Some(LineStyle::Solid),
+        "dot" => Some(LineStyle::Dot),
+        "dash" => Some(LineStyle::Dash),
+        "longdash" => Some(LineStyle::LongDash),
+        "dashdot" => Some(LineStyle::DashDot),
+        "longdashdot" => Some(LineStyle::LongDashDot),
+        _ => None,
+    }
+}
+
+/// Parse coordinates from an axis reference.
+///
+/// # Arguments
+///
+/// * `object` - JSON object.
+/// * `key` - Attribute name of the axis reference (e.g. `"xref"`).
+///
+/// # Returns
+///
+/// Coordinates.
+fn parse_coordinates(object: &Map<String, Value>, key: &str) -> Coordinates {
+    match string(object, key) {
+        Some(axis_ref) if axis_ref.starts_with("paper") => Coordinates::Paper,
+        _ => Coordinates::Data,
+    }
+}
+
+/// Parse a trace.
+///
+/// # Arguments
+///
+/// * `spec` - plotly.js trace specification.
+///
+/// # Returns
+///
+/// Trace.
+///
+/// # Errors
+///
+/// If the trace is not an object or is of an unsupported type.
+fn parse_trace(spec: &Value) -> Result<Trace, Error> {
+    let spec = spec
+        .as_object()
+        .ok_or_else(|| invalid("each trace must be an object"))?;
+    let trace_type = string(spec, "type").unwrap_or("scatter");
+
+    // Data (plotly.js defaults to the indices of the data points if the x-axis data is omitted,
+    // and draws a single box if the x-axis data of a box plot is omitted).
+    let y = parse_axis_data(array(spec, "y"));
+    let x = match spec.get("x").and_then(Value::as_array) {
+        Some(x) => parse_axis_data(x),
+        None if trace_type == "box" => {
+            AxisData::from(vec![
+                String::from(string(spec, "name").unwrap_or_default());
+                y.len()
+            ])
+        }
+        None => AxisData::Numeric((0..y.len()).map(|index| index as f64).collect()),
+    };
+    let mut trace = match trace_type {
+        "scatter" => Trace::new_2d(x, y),
+        "scattergl" => Trace::new_2d(x, y).web_gl(true),
+        "scatter3d" => Trace::new_3d(x, y, parse_axis_data(array(spec, "z"))),
+        "bar" => Trace::new_2d(x, y).plot_type(PlotType::Bar),
+        "box" => Trace::new_2d(x, y).plot_type(PlotType::Box),
+        trace_type => {
+            return Err(invalid(format!("unsupported trace type \"{trace_type}\"")));
+        }
+    };
+
+    // Trace settings.
+    if let Some(name) = string(spec, "name") {
+        trace = trace.name(name);
+    }
+    if let Some(visible) = spec.get("visible").and_then(Value::as_bool) {
+        trace = trace.visible(visible);
+    }
+    match string(spec, "mode") {
+        Some("lines") => trace = trace.mode(Mode::Lines),
+        Some("markers") => trace = trace.mode(Mode::Markers),
+        Some("lines+markers") => trace = trace.mode(Mode::LinesMarkers),
+        _ => {}
+    }
+    if let Some(marker) = object(spec, "marker") {
+        if let Some(marker_color) = color(marker, "color") {
+            trace = trace.marker_color(marker_color);
+        }
+        if let Some(marker_size) = number(marker, "size") {
+            trace = trace.marker_size(marker_size as usize);
+        }
+    }
+    if let Some(line) = object(spec, "line") {
+        if let Some(line_color) = color(line, "color") {
+            trace = trace.line_color(line_color);
+        }
+        if let Some(line_width) = number(line, "width") {
+            trace = trace.line_width(line_width);
+        }
+        if let Some(line_style) = string(line, "dash").and_then(parse_line_style) {
+            trace = trace.line_style(line_style);
+        }
+    }
+    let fill = match string(spec, "fill") {
+        Some("tozeroy") => Some(Fill::ToZeroY),
+        Some("tozerox") => Some(Fill::ToZeroX),
+        Some("tonexty") => Some(Fill::ToNextY),
+        Some("tonextx") => Some(Fill::ToNextX),
+        Some("toself") => Some(Fill::ToSelf),
+        Some("tonext") => Some(Fill::ToNext),
+        Some("none") => Some(Fill::None),
+        _ => None,
+    };
+    if let Some(fill) = fill {
+        trace = trace.fill(fill);
+    }
+    if let Some(fill_color) = color(spec, "fillcolor") {
+        trace = trace.fill_color(fill_color);
+    }
+    if let Some(stack_group) = string(spec, "stackgroup") {
+        trace = trace.stack_group(stack_group);
+    }
+    if let Some(y_axis) = string(spec, "yaxis")
+        .and_then(|y_axis| y_axis.strip_prefix('y'))
+        .and_then(|y_axis| y_axis.parse().ok())
+        .filter(|y_axis| *y_axis >= 1)
+    {
+        trace = trace.y_axis(y_axis);
+    }
+
+    Ok(trace)
+}
+
+/// Parse the figure format.
+///
+/// # Arguments
+///
+/// * `layout` - plotly.js layout specification.
+///
+/// # Returns
+///
+/// Figure format.
+fn parse_format(layout: &Map<String, Value>) -> Format {
+    let mut format = Format {
+        title: title(layout),
+        width: number(layout, "width").map(|width| width as usize),
+        height: number(layout, "height").map(|height| height as usize),
+        ..Format::default()
+    };
+
+    // x-axis settings.
+    if let Some(x_axis) = object(layout, "xaxis") {
+        format.x_label = title(x_axis);
+        format.x_range = pair(x_axis, "range");
+        format.x_axis_type = parse_axis_type(x_axis);
+        format.x_tick_format = string(x_axis, "tickformat").map(String::from);
+        format.x_category_order = parse_category_order(x_axis);
+        format.x_range_breaks = array(x_axis, "rangebreaks")
+            .iter()
+            .filter_map(|range_break| range_break.as_object().and_then(parse_range_break))
+            .collect();
+        format.x_domain = pair(x_axis, "domain");
+    }
+
+    // y-axis settings.
+    if let Some(y_axis) = object(layout, "yaxis") {
+        format.y_label = title(y_axis);
+        format.y_range = pair(y_axis, "range");
+        format.y_axis_type = parse_axis_type(y_axis);
+        format.y_tick_format = string(y_axis, "tickformat").map(String::from);
+        format.y_category_order = parse_category_order(y_axis);
+    }
+
+    // z-axis settings.
+    let z_axis = object(layout, "scene").and_then(|scene| object(scene, "zaxis"));
+    format.z_label = z_axis.and_then(title);
+    format.z_range = z_axis.and_then(|z_axis| pair(z_axis, "range"));
+
+    // Secondary y-axes (which must be consecutive, starting from `yaxis2`).
+    format.secondary_y_axes = (2..)
+        .map_while(|index| object(layout, &format!("yaxis{index}")))
+        .map(|y_axis| AxisFormat {
+            label: title(y_axis),
+            range: pair(y_axis, "range"),
+            side: match string(y_axis, "side") {
+                Some("left") => AxisSide::Left,
+                _ => AxisSide::Right,
+            },
+            position: (string(y_axis, "anchor") == Some("free"))
+                .then(|| number(y_axis, "position"))
+                .flatten(),
+        })
+        .collect();
+
+    format
+}
+
+/// Parse an axis type.
+///
+/// # Arguments
+///
+/// * `axis` - plotly.js axis specification.
+///
+/// # Returns
+///
+/// Axis type (`None` if not specified or not supported).
+fn parse_axis_type(axis: &Map<String, Value>) -> Option<AxisType> {
+    match string(axis, "type")? {
+        "linear" => Some(AxisType::Linear),
+        "log" => Some(AxisType::Log),
+        "date" => Some(AxisType::Date),
+        "category" => Some(AxisType::Category),
+        _ => None,
+    }
+}
+
+/// Parse a category order.
+///
+/// # Arguments
+///
+/// * `axis` - plotly.js axis specification.
+///
+/// # Returns
+///
+/// Category order (`None` if not specified or not supported).
+fn parse_category_order(axis: &Map<String, Value>) -> Option<CategoryOrder> {
+    match string(axis, "categoryorder")? {
+        "trace" => Some(CategoryOrder::AsGiven),
+        "category ascending" => Some(CategoryOrder::Alphabetical),
+        "total ascending" => Some(CategoryOrder::TotalAscending),
+        "total descending" => Some(CategoryOrder::TotalDescending),
+        "array" => Some(CategoryOrder::Explicit(
+            array(axis, "categoryarray")
+                .iter()
+                .map(|category| match category {
+                    Value::String(category) => category.clone(),
+                    category => category.to_string(),
+                })
+                .collect(),
+        )),
+        _ => None,
+    }
+}
+
+/// Parse a range break.
+///
+/// # Arguments
+///
+/// * `spec` - plotly.js range break specification.
+///
+/// # Returns
+///
+/// Range break (`None` if not supported).
+fn parse_range_break(spec: &Map<String, Value>) -> Option<RangeBreak> {
+    let bounds = array(spec, "bounds");
+    match (string(spec, "pattern"), bounds) {
+        (None, [Value::String(start), Value::String(end)]) if start == "sat" && end == "mon" => {
+            Some(RangeBreak::Weekends)
+        }
+        (Some("hour"), [start, end]) => Some(RangeBreak::Hours(start.as_f64()?, end.as_f64()?)),
+        (None, [start, end]) => Some(RangeBreak::Between(start.as_f64()?, end.as_f64()?)),
+        _ => None,
+    }
+}
+
+/// Parse an annotation.
+///
+/// # Arguments
+///
+/// * `spec` - plotly.js annotation specification.
+///
+/// # Returns
+///
+/// Annotation (`None` if it does not have text or a numeric position).
+fn parse_annotation(spec: &Map<String, Value>) -> Option<Annotation> {
+    let mut annotation = Annotation::new(
+        string(spec, "text")?,
+        number(spec, "x")?,
+        number(spec, "y")?,
+    )
+    .coordinates(parse_coordinates(spec, "xref"));
+
+    // Arrow settings (plotly.js draws an arrow unless it is explicitly disabled).
+    if spec.get("showarrow").and_then(Value::as_bool) != Some(false) {
+        annotation = annotation.arrow(
+            number(spec, "ax").unwrap_or(-10.0),
+            number(spec, "ay").unwrap_or(-30.0),
+        );
+    }
+    if let Some(arrow_color) = color(spec, "arrowcolor") {
+        annotation = annotation.arrow_color(arrow_color);
+    }
+
+    // Font settings.
+    if let Some(font) = object(spec, "font") {
+        if let Some(font_family) = string(font, "family") {
+            annotation = annotation.font_family(font_family);
+        }
+        if let Some(font_size) = number(font, "size") {
+            annotation = annotation.font_size(font_size as usize);
+        }
+        if let Some(font_color) = color(font, "color") {
+            annotation = annotation.font_color(font_color);
+        }
+    }
+
+    // Text box settings.
+    if let Some(background_color) = color(spec, "bgcolor") {
+        annotation = annotation.background_color(background_color);
+    }
+    if let Some(border_color) = color(spec, "bordercolor") {
+        annotation = annotation.border_color(border_color);
+    }
+    match string(spec, "align") {
+        Some("left") => annotation = annotation.alignment(Alignment::Left),
+        Some("center") => annotation = annotation.alignment(Alignment::Center),
+        Some("right") => annotation = annotation.alignment(Alignment::Right),
+        _ => {}
+    }
+
+    Some(annotation)
+}
+
+/// Parse a shape.
+///
+/// # Arguments
+///
+/// * `spec` - plotly.js shape specification.
+///
+/// # Returns
+///
+/// Shape (`None` if it is of an unsupported type or does not have a numeric position).
+fn parse_shape(spec: &Map<String, Value>) -> Option<Shape> {
+    let kind = match string(spec, "type").unwrap_or("circle") {
+        "line" => ShapeKind::Line,
+        "rect" => ShapeKind::Rect,
+        "circle" => ShapeKind::Circle,
+        "path" => ShapeKind::Path,
+        _ => return None,
+    };
+    let mut shape = match kind {
+        ShapeKind::Path => Shape::path(string(spec, "path")?),
+        kind => Shape::new(
+            kind,
+            number(spec, "x0")?,
+            number(spec, "y0")?,
+            number(spec, "x1")?,
+            number(spec, "y1")?,
+        ),
+    };
+    shape.x_coordinates = parse_coordinates(spec, "xref");
+    shape.y_coordinates = parse_coordinates(spec, "yref");
+
+    // Line settings.
+    if let Some(line) = object(spec, "line") {
+        shape.line_color = color(line, "color");
+        shape.line_width = number(line, "width");
+        shape.line_style = string(line, "dash").and_then(parse_line_style);
+    }
+
+    // Fill and layer settings.
+    shape.fill_color = color(spec, "fillcolor");
+    if string(spec, "layer") == Some("below") {
+        shape.layer = Layer::Below;
+    }
+
+    Some(shape)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::axis_format::AxisFormatBuilder;
+    use crate::colors::NamedColor;
+    use crate::format::FormatBuilder;
+
+    #[test]
+    fn test_round_trip() {
+        let format = FormatBuilder::default()
+            .title("Speed")
+            .x_label("Time")
+            .x_range((0.0, 10.0))
+            .x_axis_type(AxisType::Date)
+            .x_range_breaks(vec![RangeBreak::weekends(), RangeBreak::hours(17.0, 9.0)])
+            .y_category_order(CategoryOrder::TotalDescending)
+            .secondary_y_axes(vec![
+                AxisFormatBuilder::default()
+                    .label("Mass")
+                    .side(AxisSide::Left)
+                    .position(0.1)
+                    .build()
+                    .unwrap(),
+            ])
+            .width(800)
+            .build()
+            .unwrap();
+        let fig = Figure::new(
+            vec![
+                Trace::new_2d([1.0, f64::NAN], ["a", "b"])
+                    .name("Run 1")
+                    .mode(Mode::LinesMarkers)
+                    .line_color(Color::rgba(1, 2, 3, 0.5))
+                    .line_style(LineStyle::DashDot)
+                    .fill(Fill::ToZeroY)
+                    .y_axis(2),
+                Trace::new_2d(["a"], [1.0]).plot_type(PlotType::Bar),
+                Trace::new_3d([1.0], [2.0], [3.0]),
+                Trace::new_2d([1.0], [2.0]).web_gl(true),
+            ],
+            format,
+        )
+        .add_annotation(
+            Annotation::new("Peak", 1.0, 2.0)
+                .coordinates(Coordinates::Paper)
+                .font_size(12)
+                .alignment(Alignment::Left),
+        )
+        .add_shape(Shape::hline(5.0).line_color(Color::named(NamedColor::Red)))
+        .add_shape(Shape::path("M 0 0 L 1 1").layer(Layer::Below));
+
+        let json = fig.to_plotly_json();
+        let round_trip = Figure::from_plotly_json(&json).unwrap();
+        assert_eq!(round_trip.to_plotly_json(), json);
+
+        let trace = &round_trip.traces[0];
+        assert!(trace.x.as_numeric().unwrap()[1].is_nan());
+        assert_eq!(trace.y, ["a", "b"]);
+        assert_eq!(trace.name.as_deref(), Some("Run 1"));
+        assert_eq!(trace.y_axis, Some(2));
+        assert_eq!(round_trip.traces[1].plot_type, PlotType::Bar);
+        assert_eq!(round_trip.traces[2].z, Some(AxisData::from([3.0])));
+        assert_eq!(round_trip.traces[3].web_gl, Some(true));
+        assert_eq!(round_trip.format.secondary_y_axes[0].position, Some(0.1));
+        assert_eq!(round_trip.annotations[0].coordinates, Coordinates::Paper);
+        assert!(round_trip.annotations[0].arrow.is_none());
+        assert_eq!(round_trip.shapes[0].x_coordinates, Coordinates::Paper);
+    }
+
+    #[test]
+    fn test_round_trip_z_axis() {
+        let format = FormatBuilder::default()
+            .z_label("Altitude")
+            .z_range((0.0, 5.0))
+            .build()
+            .unwrap();
+        let fig = Figure::new(vec![Trace::new_3d([1.0], [2.0], [3.0])], format);
+        let json = fig.to_plotly_json();
+        let round_trip = Figure::from_plotly_json(&json).unwrap();
+        assert_eq!(round_trip.format.z_label.as_deref(), Some("Altitude"));
+        assert_eq!(round_trip.format.z_range, Some((0.0, 5.0)));
+        assert_eq!(round_trip.to_plotly_json(), json);
+    }
+
+    #[test]
+    fn test_plotly_defaults() {
+        let fig = Figure::from_plotly_json(
+            r##"{
+                "data": [{"y": [3, 1, 2], "line": {"color": "#ff0000"}}, {"type": "box", "y": [1, 2]}],
+                "layout": {"title": "Title", "annotations": [{"text": "Note", "x": 1, "y": 2}]}
+            }"##,
+        )
+        .unwrap();
+        assert_eq!(fig.traces[0].x, [0.0, 1.0, 2.0]);
+        assert_eq!(fig.traces[0].line_color, Some(Color::rgb(255, 0, 0)));
+        assert_eq!(fig.traces[1].x, ["", ""]);
+        assert_eq!(fig.format.title.as_deref(), Some("Title"));
+        assert_eq!(fig.annotations[0].arrow, Some((-10.0, -30.0)));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(Figure::from_plotly_json("{"), Err(Error::Json(_))));
+        assert!(matches!(
+            Figure::from_plotly_json("[]"),
+            Err(Error::PlotlyJson(_))
+        ));
+        assert!(matches!(
+            Figure::from_plotly_json(r#"{"data": [{"type": "pie", "values": [1, 2]}]}"#),
+            Err(Error::PlotlyJson(message)) if message.contains("pie")
+        ));
+    }
+}
@@ -0,0 +1,564 @@
+use crate::animation::Animation;
+use crate::axes::Axes;
+use crate::axis_type::AxisType;
+use crate::figure::Figure;
+use ::png::{BitDepth, ColorType, Encoder};
+use gif::{Encoder as GifEncoder, Frame as GifFrame, Repeat};
+use resvg::tiny_skia::Pixmap;
+use resvg::usvg::{Options, Tree, fontdb};
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+
+/// Fonts installed on the system (loaded once, when the first figure is rasterized).
+static FONTS: OnceLock<Arc<fontdb::Database>> = OnceLock::new();
+
+/// Rasterize an SVG document.
+///
+/// # Arguments
+///
+/// * `svg` - SVG document.
+///
+/// # Returns
+///
+/// Rasterized image.
+///
+/// # Panics
+///
+/// If the SVG document cannot be parsed or the image has zero width or height (figures are at
+/// least one pixel wide and high, so this does not happen for rendered figures).
+pub(crate) fn rasterize(svg: &str) -> Pixmap {
+    let fonts = FONTS.get_or_init(|| {
+        let mut fonts = fontdb::Database::new();
+        fonts.load_system_fonts();
+
+        // Fall back to any installed font if the default sans-serif font (Arial) is missing.
+        let query = fontdb::Query {
+            families: &[fontdb::Family::SansSerif],
+            ..fontdb::Query::default()
+        };
+        let fallback = fonts
+            .faces()
+            .next()
+            .and_then(|face| face.families.first())
+            .map(|(family, _)| family.clone());
+        if fonts.query(&query).is_none()
+            && let Some(family) = fallback
+        {
+            fonts.set_sans_serif_family(family);
+        }
+        Arc::new(fonts)
+    });
+    let options = Options {
+        fontdb: fonts.clone(),
+        ..Options::default()
+    };
+    let tree = Tree::from_str(svg, &options).expect("The rendered SVG document should be valid.");
+    let size = tree.size().to_int_size();
+    let mut pixmap =
+        Pixmap::new(size.width(), size.height()).expect("The figure must not have a zero size.");
+    resvg::render(&tree, Default::default(), &mut pixmap.as_mut());
+    pixmap
+}
+
+impl Figure {
+    /// Render the figure as a PNG image.
+    ///
+    /// The figure is rasterized from its native SVG rendering (see [`Figure::to_svg`]), using the
+    /// fonts installed on the system.
+    ///
+    /// # Returns
+    ///
+    /// PNG-encoded image.
+    ///
+    /// # Note
+    ///
+    /// A zero width or height is rendered as one pixel, and line widths and marker sizes are
+    /// clamped to 10,000 pixels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::quick_plot_2d;
+    ///
+    /// let png: Vec<u8> = quick_plot_2d([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]).to_png();
+    /// ```
+    pub fn to_png(&self) -> Vec<u8> {
+        rasterize(&self.to_svg())
+            .encode_png()
+            .expect("Encoding a rasterized figure should not fail.")
+    }
+
+    /// Save the figure to a PNG file (see [`Figure::to_png`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the PNG file.
+    ///
+    /// # Panics
+    ///
+    /// If some error is encountered while creating the file or writing to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{quick_plot_2d, Figure};
+    ///
+    /// let fig: Figure = quick_plot_2d([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]);
+    ///
+    /// // Save the figure to a PNG file.
+    /// fig.save_png("folder/file.png");
+    /// ```
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) {
+        save_bytes(path.as_ref(), &self.to_png());
+    }
+}
+
+impl Animation {
+    /// Render the animated figure as an animated GIF image.
+    ///
+    /// Each frame is rasterized natively (see [`Figure::to_png`]) and shown for the frame duration
+    /// of the animation (see [`Animation::frame_duration`]), without transitions. The image loops
+    /// forever. As in HTML output, the axes are the same in every frame: the axis ranges that are
+    /// not set in the format of the figure span the data of all frames.
+    ///
+    /// # Returns
+    ///
+    /// GIF-encoded image.
+    ///
+    /// # Panics
+    ///
+    /// * If the figure is wider or taller than 65,535 pixels.
+    /// * If the frames do not all have the same size.
+    ///
+    /// # Note
+    ///
+    /// GIF images have at most 256 colors per frame, so the colors of each frame are quantized
+    /// (e.g. the colors due to anti-aliasing are approximated). Use [`Animation::to_apng`] for
+    /// lossless animated images.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{Animation, quick_plot_2d};
+    ///
+    /// let animation = Animation::from_figures(
+    ///     (1..=3)
+    ///         .map(|k| quick_plot_2d([0.0, 1.0, 2.0], [0.0, k as f64, 0.0]))
+    ///         .collect(),
+    /// );
+    /// let gif: Vec<u8> = animation.to_gif();
+    /// ```
+    pub fn to_gif(&self) -> Vec<u8> {
+        // GIF frame delays are specified in hundredths of a second.
+        let delay = u16::try_from(self.frame_duration.div_ceil(10)).unwrap_or(u16::MAX);
+        let mut bytes = Vec::new();
+        let mut output = Some(&mut bytes);
+        let mut encoder = None;
+        let mut size = None;
+        self.for_each_figure(|_, figure| {
+            let pixmap = rasterize(&figure.to_svg());
+            let width = u16::try_from(pixmap.width())
+                .expect("GIF frames must be at most 65,535 pixels wide.");
+            let height = u16::try_from(pixmap.height())
+                .expect("GIF frames must be at most 65,535 pixels tall.");
+            assert_eq!(
+                *size.get_or_insert((width, height)),
+                (width, height),
+                "All frames must have the same size."
+            );
+            let encoder = encoder.get_or_insert_with(|| {
+                let mut encoder = GifEncoder::new(
+                    output
+                        .take()
+                        .expect("The encoder should only be created once."),
+                    width,
+                    height,
+                    &[],
+                )
+                .expect("Writing to memory should not fail.");
+                encoder
+                    .set_repeat(Repeat::Infinite)
+                    .expect("Writing to memory should not fail.");
+                encoder
+            });
+            let mut frame = GifFrame::from_rgba_speed(width, height, &mut rgba(&pixmap), 10);
+            frame.delay = delay;
+            encoder
+                .write_frame(&frame)
+                .expect("Writing to memory should not fail.");
+        });
+        encoder
+            .expect("An animation has at least one frame.")
+            .into_inner()
+            .expect("Writing to memory should not fail.");
+        bytes
+    }
+
+    /// Save the animated figure to an animated GIF file (see [`Animation::to_gif`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the GIF file.
+    ///
+    /// # Panics
+    ///
+    /// If some error is encountered while creating the file or writing to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{Animation, quick_plot_2d};
+    ///
+    /// let animation = Animation::from_figures(
+    ///     (1..=3)
+    ///         .map(|k| quick_plot_2d([0.0, 1.0, 2.0], [0.0, k as f64, 0.0]))
+    ///         .collect(),
+    /// );
+    /// animation.save_gif("folder/animation.gif");
+    /// ```
+    pub fn save_gif<P: AsRef<Path>>(&self, path: P) {
+        save_bytes(path.as_ref(), &self.to_gif());
+    }
+
+    /// Render the animated figure as an animated PNG (APNG) image.
+    ///
+    /// Each frame is rasterized natively (see [`Figure::to_png`]) and shown for the frame duration
+    /// of the animation (see [`Animation::frame_duration`]), without transitions. The image loops
+    /// forever.
+    ///
+    /// # Returns
+    ///
+    /// APNG-encoded image.
+    ///
+    /// # Panics
+    ///
+    /// If the frames do not all have the same size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{Animation, quick_plot_2d};
+    ///
+    /// let animation = Animation::from_figures(
+    ///     (1..=3)
+    ///         .map(|k| quick_plot_2d([0.0, 1.0, 2.0], [0.0, k as f64, 0.0]))
+    ///         .collect(),
+    /// );
+    /// let apng: Vec<u8> = animation.to_apng();
+    /// ```
+    pub fn to_apng(&self) -> Vec<u8> {
+        let frames = self.frames.len().max(1) as u32;
+        let delay = u16::try_from(self.frame_duration).unwrap_or(u16::MAX);
+        let mut bytes = Vec::new();
+        let mut output = Some(&mut bytes);
+        let mut writer = None;
+        self.for_each_figure(|_, figure| {
+            let pixmap = rasterize(&figure.to_svg());
+            let writer = writer.get_or_insert_with(|| {
+                let mut encoder = Encoder::new(
+                    output
+                        .take()
+                        .expect("The encoder should only be created once."),
+                    pixmap.width(),
+                    pixmap.height(),
+                );
+                encoder.set_color(ColorType::Rgba);
+                encoder.set_depth(BitDepth::Eight);
+                encoder
+                    .set_animated(frames, 0)
+                    .expect("The number of frames should be nonzero.");
+                encoder
+                    .write_header()
+                    .expect("Writing to memory should not fail.")
+            });
+            writer
+                .set_frame_delay(delay, 1000)
+                .expect("Setting the frame delay should not fail.");
+            writer
+                .write_image_data(&rgba(&pixmap))
+                .expect("All frames must have the same size.");
+        });
+        writer
+            .expect("An animation has at least one frame.")
+            .finish()
+            .expect("Writing to memory should not fail.");
+        bytes
+    }
+
+    /// Save the animated figure to an animated PNG (APNG) file (see [`Animation::to_apng`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the APNG file (usually with a `.png` extension).
+    ///
+    /// # Panics
+    ///
+    /// If some error is encountered while creating the file or writing to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{Animation, quick_plot_2d};
+    ///
+    /// let animation = Animation::from_figures(
+    ///     (1..=3)
+    ///         .map(|k| quick_plot_2d([0.0, 1.0, 2.0], [0.0, k as f64, 0.0]))
+    ///         .collect(),
+    /// );
+    /// animation.save_apng("folder/animation.png");
+    /// ```
+    pub fn save_apng<P: AsRef<Path>>(&self, path: P) {
+        save_bytes(path.as_ref(), &self.to_apng());
+    }
+
+    /// Save each frame of the animated figure to a numbered PNG file (`frame_0000.png`,
+    /// `frame_0001.png`, and so on) in a directory.
+    ///
+    /// The frames can be assembled into a video using e.g. `ffmpeg`:
+    ///
+    /// ```text
+    /// ffmpeg -framerate 10 -i frame_%04d.png -pix_fmt yuv420p animation.mp4
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - Directory to save the PNG files to (created if it does not exist).
+    ///
+    /// # Panics
+    ///
+    /// If some error is encountered while creating the files or writing to them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{Animation, quick_plot_2d};
+    ///
+    /// let animation = Animation::from_figures(
+    ///     (1..=3)
+    ///         .map(|k| quick_plot_2d([0.0, 1.0, 2.0], [0.0, k as f64, 0.0]))
+    ///         .collect(),
+    /// );
+    /// animation.save_png_frames("folder/frames");
+    /// ```
+    pub fn save_png_frames<P: AsRef<Path>>(&self, directory: P) {
+        let directory = directory.as_ref();
+        self.for_each_figure(|index, figure| {
+            figure.save_png(directory.join(format!("frame_{index:04}.png")));
+        });
+    }
+
+    /// Call a function on the figure shown by each frame of the animation (or on the figure
+    /// itself if the animation has no frames).
+    ///
+    /// The traces replaced by a frame keep their replacement in later frames (as when the
+    /// animation plays in a web browser). The axis ranges that are not set in the format of the
+    /// figure are fixed to the union of the ranges of all frames, so that the axes do not change
+    /// from one frame to the next (categorical axes are not fixed).
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Function called on the index of each frame and the figure it shows.
+    fn for_each_figure(&self, mut f: impl FnMut(usize, &Figure)) {
+        // Figures shown by the frames.
+        let mut figure = self.figure.clone();
+        let mut figures = Vec::with_capacity(self.frames.len().max(1));
+        for frame in &self.frames {
+            for (trace_index, trace) in frame.indices().into_iter().zip(&frame.traces) {
+                figure.traces[trace_index] = trace.clone();
+            }
+            if let Some(title) = &frame.title {
+                figure.format.title = Some(title.clone());
+            }
+            figures.push(figure.clone());
+        }
+        if figures.is_empty() {
+            figures.push(figure);
+        }
+
+        // Union of the axis ranges of all frames.
+        let mut ranges: Vec<Option<(f64, f64)>> = Vec::new();
+        for figure in &figures {
+            let axes = Axes::new(figure);
+            let scales = std::iter::once(&axes.x).chain(&axes.y).chain(&axes.z);
+            ranges.resize(
+                ranges.len().max(axes.y.len() + 1 + axes.z.iter().len()),
+                None,
+            );
+            for (range, scale) in ranges.iter_mut().zip(scales) {
+                if scale.axis_type == AxisType::Category {
+                    continue;
+                }
+                let (lower, upper) = scale.range();
+                *range = Some(range.map_or((lower, upper), |(range_lower, range_upper)| {
+                    (range_lower.min(lower), range_upper.max(upper))
+                }));
+            }
+        }
+
+        for (index, mut figure) in figures.into_iter().enumerate() {
+            let format = &mut figure.format;
+            let is_3d = figure.traces.iter().any(|trace| trace.z.is_some());
+            let mut ranges = ranges.iter().copied();
+            format.x_range = format.x_range.or(ranges.next().flatten());
+            format.y_range = format.y_range.or(ranges.next().flatten());
+            if is_3d {
+                format.z_range = format.z_range.or(ranges.next().flatten());
+            } else {
+                for (axis_format, range) in format.secondary_y_axes.iter_mut().zip(ranges) {
+                    axis_format.range = axis_format.range.or(range);
+                }
+            }
+            f(index, &figure);
+        }
+    }
+}
+
+/// Get the pixels of a rasterized image as (non-premultiplied) RGBA values.
+///
+/// # Arguments
+///
+/// * `pixmap` - Rasterized image.
+///
+/// # Returns
+///
+/// Red, green, blue, and alpha values of each pixel (row by row).
+fn rgba(pixmap: &Pixmap) -> Vec<u8> {
+    pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let pixel = pixel.demultiply();
+            [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()]
+        })
+        .collect()
+}
+
+/// Save bytes to a file, creating its parent directory if needed.
+///
+/// # Arguments
+///
+/// * `path` - Path to the file.
+/// * `bytes` - Bytes.
+///
+/// # Panics
+///
+/// If some error is encountered while creating the file or writing to it.
+fn save_bytes(path: &Path, bytes: &[u8]) {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).expect("Failed to create the parent directory.");
+    }
+    std::fs::write(path, bytes).expect("Failed to write the file.");
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::animation::{Animation, Frame};
+    use crate::axes::Axes;
+    use crate::axis_data::AxisData;
+    use crate::figure::Figure;
+    use crate::format::FormatBuilder;
+    use crate::mode::Mode;
+    use crate::quick::quick_plot_2d;
+    use crate::trace::Trace;
+
+    #[test]
+    fn test_to_png() {
+        let png = quick_plot_2d([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]).to_png();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    }
+
+    #[test]
+    fn test_extreme_ranges() {
+        let png = quick_plot_2d([-1e308, 1e308], [1e15, 1e15 + 1.0]).to_png();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    }
+
+    #[test]
+    fn test_sizes() {
+        let format = FormatBuilder::default().width(0).height(0).build().unwrap();
+        let png = Figure::new(vec![Trace::new_2d([1.0], [2.0])], format).to_png();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+
+        let fig = Figure::new(
+            vec![
+                Trace::new_2d([1.0, 2.0], [1.0, 2.0]).line_width(1e30),
+                Trace::new_2d([1.0, 2.0], [1.0, 2.0])
+                    .mode(Mode::Markers)
+                    .marker_size(usize::MAX),
+            ],
+            Default::default(),
+        );
+        let png = fig.to_png();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    }
+
+    #[test]
+    fn test_animated_images() {
+        let animation = Animation::from_figures(
+            (1..=2)
+                .map(|k| quick_plot_2d([0.0, 1.0, 2.0], [0.0, k as f64, 0.0]))
+                .collect(),
+        );
+        let gif = animation.to_gif();
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!(gif.last(), Some(&0x3b));
+        let mut decoder = gif::DecodeOptions::new().read_info(&gif[..]).unwrap();
+        let size = (decoder.width(), decoder.height());
+        let mut delays = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!((frame.width, frame.height), size);
+            delays.push(frame.delay);
+        }
+        assert_eq!(delays, [50, 50]);
+        let apng = animation.to_apng();
+        assert_eq!(&apng[..8], b"\x89PNG\r\n\x1a\n");
+        assert!(apng.windows(4).any(|chunk| chunk == b"acTL"));
+    }
+
+    #[test]
+    fn test_for_each_figure() {
+        let animation = Animation::new(quick_plot_2d([0.0, 1.0], [0.0, 1.0])).add_frames([
+            Frame::new("a", vec![Trace::new_2d([0.0], [1.0])]).title("a"),
+            Frame::new("b", vec![Trace::new_2d([0.0], [2.0])]),
+        ]);
+        let mut frames = Vec::new();
+        animation.for_each_figure(|index, figure| {
+            frames.push((
+                index,
+                figure.format.title.clone(),
+                figure.traces[0].y.clone(),
+            ))
+        });
+        assert_eq!(
+            frames,
+            [
+                (0, Some(String::from("a")), AxisData::from([1.0])),
+                (1, Some(String::from("a")), AxisData::from([2.0])),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_for_each_figure_fixed_axes() {
+        let animation = Animation::from_figures(vec![
+            quick_plot_2d([0.0, 1.0, 2.0], [0.0, 1.0, 0.0]),
+            quick_plot_2d([0.0, 1.0, 2.0, 3.0], [0.0, 10.0, 0.0, 0.0]),
+        ]);
+        let mut ticks = Vec::new();
+        animation.for_each_figure(|_, figure| {
+            let axes = Axes::new(figure);
+            let labels = |scale: &crate::scale::Scale| {
+                scale
+                    .ticks(6)
+                    .into_iter()
+                    .map(|tick| tick.label)
+                    .collect::<Vec<String>>()
+            };
+            ticks.push((labels(&axes.x), labels(&axes.y[0])));
+        });
+        assert_eq!(ticks.len(), 2);
+        assert_eq!(ticks[0], ticks[1]);
+        assert!(ticks[0].1.contains(&String::from("10")));
+        assert!(ticks[0].0.contains(&String::from("3")));
+    }
+}
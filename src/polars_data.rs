@@ -0,0 +1,118 @@
+use crate::axis_data::AxisData;
+use crate::columns::{Column, ColumnSource, Columns};
+use crate::error::Error;
+use crate::trace::Trace;
+use polars::prelude::{Column as PolarsColumn, DataFrame, DataType};
+
+impl ColumnSource for DataFrame {
+    fn axis_data(&self, column: &Column) -> Result<AxisData, Error> {
+        let polars_column: &PolarsColumn = match column {
+            Column::Name(name) => self.column(name).ok(),
+            Column::Index(index) => self.select_at_idx(*index),
+        }
+        .ok_or_else(|| Error::MissingColumn(column.clone()))?;
+
+        // Numeric columns produce numeric data (with missing values plotted as gaps).
+        if polars_column.dtype().is_primitive_numeric() {
+            let data = polars_column.cast(&DataType::Float64)?;
+            return Ok(AxisData::Numeric(
+                data.f64()?
+                    .iter()
+                    .map(|value| value.unwrap_or(f64::NAN))
+                    .collect(),
+            ));
+        }
+
+        // All other columns (e.g. strings, booleans, or dates) produce categorical data.
+        let data = polars_column.cast(&DataType::String)?;
+        Ok(AxisData::Categorical(
+            data.str()?
+                .iter()
+                .map(|value| String::from(value.unwrap_or_default()))
+                .collect(),
+        ))
+    }
+}
+
+impl Trace {
+    /// Constructor for a set of traces from the columns of a [`polars`] data frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_frame` - Data frame.
+    /// * `columns` - Columns to build the traces from (see [`Columns`]).
+    ///
+    /// # Returns
+    ///
+    /// Traces (one per group if a color-by and/or group-by column is specified, otherwise a single
+    /// trace).
+    ///
+    /// # Errors
+    ///
+    /// If one of the columns does not exist or cannot be converted to axis data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{ColumnsBuilder, Trace};
+    /// use polars::prelude::*;
+    ///
+    /// let data_frame = df!(
+    ///     "time" => [0.0, 0.0, 1.0, 1.0],
+    ///     "speed" => [10.0, 12.0, 11.0, 15.0],
+    ///     "vehicle" => ["car", "truck", "car", "truck"],
+    /// )
+    /// .unwrap();
+    /// let columns = ColumnsBuilder::default()
+    ///     .x("time")
+    ///     .y("speed")
+    ///     .group_by("vehicle")
+    ///     .build()
+    ///     .unwrap();
+    /// let traces = Trace::from_polars(&data_frame, &columns).unwrap();
+    /// ```
+    pub fn from_polars(data_frame: &DataFrame, columns: &Columns) -> Result<Vec<Trace>, Error> {
+        columns.traces(data_frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::columns::ColumnsBuilder;
+    use polars::prelude::{NamedFrom, Series};
+
+    #[test]
+    fn test_from_polars() {
+        let data_frame = DataFrame::new(vec![
+            Series::new("t".into(), [0_i32, 0, 1, 1]).into(),
+            Series::new("v".into(), [Some(1.0_f32), Some(2.0), None, Some(4.0)]).into(),
+            Series::new("car".into(), ["a", "b", "a", "b"]).into(),
+        ])
+        .unwrap();
+        let columns = ColumnsBuilder::default()
+            .x("t")
+            .y(1)
+            .group_by("car")
+            .build()
+            .unwrap();
+        let traces = Trace::from_polars(&data_frame, &columns).unwrap();
+        assert_eq!(traces.len(), 2);
+        assert_eq!(traces[0].name.as_deref(), Some("a"));
+        assert_eq!(traces[0].x, [0.0, 1.0]);
+        assert_eq!(traces[0].y.as_numeric().unwrap()[0], 1.0);
+        assert!(traces[0].y.as_numeric().unwrap()[1].is_nan());
+        assert_eq!(traces[1].name.as_deref(), Some("b"));
+        assert_eq!(traces[1].y, [2.0, 4.0]);
+    }
+
+    #[test]
+    fn test_missing_column() {
+        let data_frame = DataFrame::new(vec![Series::new("t".into(), [0.0, 1.0]).into()]).unwrap();
+        let columns = ColumnsBuilder::default().x("t").y("v").build().unwrap();
+        assert!(matches!(
+            Trace::from_polars(&data_frame, &columns),
+            Err(Error::MissingColumn(_))
+        ));
+    }
+}
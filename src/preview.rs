@@ -0,0 +1,672 @@
+use crate::figure::Figure;
+use crate::html::HtmlOptions;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, OnceLock};
+use std::time::Duration;
+
+/// Port of the preview server used by [`Figure::show_live`]. A fixed port lets a browser tab
+/// opened by a previous run of a program reconnect to the preview server of the next run.
+const DEFAULT_PORT: u16 = 7878;
+
+/// Interval at which disconnected browser tabs try to reconnect to the preview server.
+const RECONNECT_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Interval at which idle event streams are checked for disconnected browser tabs.
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// How long [`Figure::show_live`] waits (in the background) for a browser tab to (re)connect
+/// before opening a new one.
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// How long the preview server waits for a client to send a request (so that idle connections do
+/// not hold on to a thread forever).
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Preview page, which shows the figures pushed by the preview server (`{scripts}` is replaced by
+/// the script tags loading plotly.js).
+const PAGE: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="utf-8" />
+    <title>plotting preview</title>
+    {scripts}
+    <style>html, body, #plot { height: 100%; margin: 0; }</style>
+</head>
+<body>
+    <div id="plot"></div>
+    <script>
+        const events = new EventSource("/events");
+        events.onmessage = (event) => {
+            const figure = JSON.parse(event.data);
+            if (!figure.frames) {
+                delete figure.frames;
+            }
+            Plotly.react("plot", figure);
+        };
+    </script>
+</body>
+</html>
+"#;
+
+/// Preview server used by [`Figure::show_live`] (`None` if it could not be started).
+static LIVE_SERVER: OnceLock<Option<PreviewServer>> = OnceLock::new();
+
+/// Whether [`Figure::show_live`] is waiting for a browser tab to connect to the live server.
+static CONNECTING: AtomicBool = AtomicBool::new(false);
+
+/// State shared between the preview server and its connections.
+#[derive(Default)]
+struct Shared {
+    /// Version of the current figure (incremented on every update, `0` if there is no figure yet).
+    version: u64,
+
+    /// plotly.js figure specification of the current figure (in JSON format).
+    json: String,
+
+    /// Number of connected browser tabs.
+    clients: usize,
+
+    /// Latest version of the figure delivered to a browser tab.
+    delivered: u64,
+
+    /// Whether the preview server was stopped.
+    stopped: bool,
+
+    /// Options for the preview page and the figures pushed to it.
+    html_options: HtmlOptions,
+}
+
+/// Local HTTP server previewing a figure in a web browser, and pushing updates of the figure to
+/// the browser (using server-sent events) so that a single browser tab live-updates.
+///
+/// The server only listens on the loopback interface (`127.0.0.1`), and stops when it is dropped
+/// (browser tabs keep trying to reconnect, so they pick up a server started on the same port
+/// later, e.g. by the next run of a program). [`Figure::show_live`] uses a preview server shared
+/// by the whole program.
+///
+/// # Example
+///
+/// ```
+/// use plotting::{quick_plot_2d, PreviewServer};
+///
+/// // Start a preview server on any free port.
+/// let server = PreviewServer::start(0).unwrap();
+///
+/// // Show a figure, then update it (the browser tab at `server.url()` live-updates).
+/// server.update(&quick_plot_2d([1.0, 2.0, 3.0], [1.0, 4.0, 9.0]));
+/// server.update(&quick_plot_2d([1.0, 2.0, 3.0], [1.0, 8.0, 27.0]));
+/// ```
+pub struct PreviewServer {
+    /// Address the server listens on.
+    address: SocketAddr,
+
+    /// State shared with the connections, and the condition variable signalling its changes.
+    shared: Arc<(Mutex<Shared>, Condvar)>,
+}
+
+impl PreviewServer {
+    /// Start a preview server.
+    ///
+    /// The server runs in background threads, so this function returns immediately.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port to listen on (`0` to pick any free port).
+    ///
+    /// # Returns
+    ///
+    /// Preview server.
+    ///
+    /// # Errors
+    ///
+    /// If the server cannot listen on the port (e.g. because it is already in use).
+    pub fn start(port: u16) -> io::Result<PreviewServer> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+        let address = listener.local_addr()?;
+        let shared: Arc<(Mutex<Shared>, Condvar)> = Arc::default();
+        let server_shared = shared.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                // Stop accepting connections once the server is dropped.
+                if lock(&server_shared.0).stopped {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    let shared = server_shared.clone();
+                    std::thread::spawn(move || {
+                        let _ = handle_connection(stream, address.port(), &shared);
+                    });
+                }
+            }
+        });
+        Ok(PreviewServer { address, shared })
+    }
+
+    /// Set the options for the preview page and the figures pushed to it (the source of plotly.js,
+    /// and the encoding of numeric data arrays).
+    ///
+    /// # Arguments
+    ///
+    /// * `html_options` - HTML export options (a [`crate::PlotlyJs::Path`] should be an absolute
+    ///   URL, since relative paths are resolved against the preview server).
+    ///
+    /// # Returns
+    ///
+    /// The preview server with the options set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{HtmlOptionsBuilder, PlotlyJs, PreviewServer};
+    ///
+    /// // Preview figures offline.
+    /// let options = HtmlOptionsBuilder::default()
+    ///     .plotly_js(PlotlyJs::Inline)
+    ///     .build()
+    ///     .unwrap();
+    /// let server = PreviewServer::start(0).unwrap().html_options(options);
+    /// ```
+    pub fn html_options(self, html_options: HtmlOptions) -> Self {
+        self.lock().html_options = html_options;
+        self
+    }
+
+    /// Get the preview server shared by the whole program (used by [`Figure::show_live`]),
+    /// starting it if necessary.
+    ///
+    /// # Returns
+    ///
+    /// Preview server.
+    ///
+    /// # Panics
+    ///
+    /// If the preview server cannot be started.
+    pub fn live() -> &'static PreviewServer {
+        LIVE_SERVER
+            .get_or_init(|| {
+                // Fall back to any free port if another program is already using the default port.
+                PreviewServer::start(DEFAULT_PORT)
+                    .or_else(|_| PreviewServer::start(0))
+                    .ok()
+            })
+            .as_ref()
+            .expect("Failed to start the preview server.")
+    }
+
+    /// URL of the preview page.
+    ///
+    /// # Returns
+    ///
+    /// URL of the preview page (e.g. `http://127.0.0.1:7878/`).
+    pub fn url(&self) -> String {
+        format!("http://{}/", self.address)
+    }
+
+    /// Show a figure, replacing the current figure in all connected browser tabs.
+    ///
+    /// # Arguments
+    ///
+    /// * `figure` - Figure.
+    pub fn update(&self, figure: &Figure) {
+        let encoding = self.lock().html_options.encoding();
+        let json = figure.plotly_with_encoding(encoding).to_json();
+        let mut shared = self.lock();
+        shared.version += 1;
+        shared.json = json;
+        self.shared.1.notify_all();
+    }
+
+    /// Number of connected browser tabs.
+    ///
+    /// # Returns
+    ///
+    /// Number of browser tabs showing the preview page.
+    pub fn clients(&self) -> usize {
+        self.lock().clients
+    }
+
+    /// Wait until a browser tab is connected.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Maximum time to wait.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a browser tab is connected, `false` if the wait timed out.
+    pub fn wait_for_client(&self, timeout: Duration) -> bool {
+        self.wait(timeout, |shared| shared.clients > 0)
+    }
+
+    /// Wait until the current figure has been delivered to a browser tab.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Maximum time to wait.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the current figure has been delivered, `false` if the wait timed out.
+    pub fn wait_until_delivered(&self, timeout: Duration) -> bool {
+        self.wait(timeout, |shared| shared.delivered >= shared.version)
+    }
+
+    /// Wait until a condition on the shared state holds.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Maximum time to wait.
+    /// * `condition` - Condition.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the condition holds, `false` if the wait timed out.
+    fn wait(&self, timeout: Duration, condition: impl Fn(&Shared) -> bool) -> bool {
+        let (_, changed) = &*self.shared;
+        let shared = self.lock();
+        let (shared, _) = changed
+            .wait_timeout_while(shared, timeout, |shared| !condition(shared))
+            .unwrap_or_else(|error| error.into_inner());
+        condition(&shared)
+    }
+
+    /// Lock the shared state.
+    ///
+    /// # Returns
+    ///
+    /// Guard of the shared state.
+    fn lock(&self) -> MutexGuard<'_, Shared> {
+        lock(&self.shared.0)
+    }
+}
+
+impl Drop for PreviewServer {
+    fn drop(&mut self) {
+        self.lock().stopped = true;
+        self.shared.1.notify_all();
+
+        // Wake up the thread accepting connections so that it stops listening.
+        let _ = TcpStream::connect(self.address);
+    }
+}
+
+/// Lock the shared state of a preview server (ignoring poisoning, since the state stays
+/// consistent if a connection thread panics).
+///
+/// # Arguments
+///
+/// * `mutex` - Mutex of the shared state.
+///
+/// # Returns
+///
+/// Guard of the shared state.
+fn lock(mutex: &Mutex<Shared>) -> MutexGuard<'_, Shared> {
+    mutex.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+/// Handle a connection to the preview server.
+///
+/// # Arguments
+///
+/// * `stream` - Connection.
+/// * `port` - Port the preview server listens on.
+/// * `shared` - State shared with the preview server.
+///
+/// # Errors
+///
+/// If reading the request or writing the response fails (e.g. because the browser tab was
+/// closed, or because the request was not received within [`REQUEST_TIMEOUT`]).
+///
+/// # Note
+///
+/// Requests whose `Host` header is not the loopback address or `localhost` (with the port of the
+/// preview server) are rejected, so that web pages cannot read the figure through DNS rebinding.
+fn handle_connection(
+    mut stream: TcpStream,
+    port: u16,
+    shared: &(Mutex<Shared>, Condvar),
+) -> io::Result<()> {
+    // Request line (e.g. "GET /events HTTP/1.1"), followed by headers, of which only `Host` is
+    // used.
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request = String::new();
+    reader.read_line(&mut request)?;
+    let mut host = None;
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 0 && !header.trim().is_empty() {
+        if let Some((name, value)) = header.split_once(':')
+            && name.trim().eq_ignore_ascii_case("host")
+        {
+            host = Some(value.trim().to_ascii_lowercase());
+        }
+        header.clear();
+    }
+    let allowed = [format!("127.0.0.1:{port}"), format!("localhost:{port}")];
+    if !host.is_some_and(|host| allowed.contains(&host)) {
+        return respond(&mut stream, "403 Forbidden", "text/plain", "Forbidden");
+    }
+    let mut parts = request.split_whitespace();
+    let (method, path) = (parts.next(), parts.next());
+    match (method, path) {
+        (Some("GET"), Some("/")) => {
+            let scripts = lock(&shared.0).html_options.plotly_js.scripts();
+            let page = PAGE.replacen("{scripts}", &scripts, 1);
+            respond(&mut stream, "200 OK", "text/html", &page)
+        }
+        (Some("GET"), Some("/events")) => stream_events(stream, shared),
+        _ => respond(&mut stream, "404 Not Found", "text/plain", "Not found"),
+    }
+}
+
+/// Write a complete HTTP response.
+///
+/// # Arguments
+///
+/// * `stream` - Connection.
+/// * `status` - Status (e.g. `"200 OK"`).
+/// * `content_type` - Content type of the body.
+/// * `body` - Body.
+///
+/// # Errors
+///
+/// If writing the response fails.
+fn respond(stream: &mut TcpStream, status: &str, content_type: &str, body: &str) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}; charset=utf-8\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
+
+/// Stream the figure to a browser tab as server-sent events, sending the current figure right
+/// away and every updated figure afterwards.
+///
+/// # Arguments
+///
+/// * `stream` - Connection.
+/// * `shared` - State shared with the preview server.
+///
+/// # Errors
+///
+/// If writing to the connection fails (i.e. once the browser tab is closed or reloaded).
+fn stream_events(mut stream: TcpStream, shared: &(Mutex<Shared>, Condvar)) -> io::Result<()> {
+    let (mutex, changed) = shared;
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-store\r\n\r\nretry: {}\n\n",
+        RECONNECT_INTERVAL.as_millis()
+    )?;
+    stream.flush()?;
+    lock(mutex).clients += 1;
+    changed.notify_all();
+
+    let mut sent = 0;
+    let result = loop {
+        // Wait for a figure that has not been sent yet.
+        let guard = lock(mutex);
+        let (guard, timeout) = changed
+            .wait_timeout_while(guard, KEEP_ALIVE_INTERVAL, |shared| {
+                shared.version <= sent && !shared.stopped
+            })
+            .unwrap_or_else(|error| error.into_inner());
+        if guard.stopped {
+            break Ok(());
+        }
+        let message = if timeout.timed_out() {
+            String::from(": keep-alive\n\n")
+        } else {
+            sent = guard.version;
+            let data: String = guard
+                .json
+                .lines()
+                .map(|line| format!("data: {line}\n"))
+                .collect();
+            format!("{data}\n")
+        };
+        drop(guard);
+        if let Err(error) = stream
+            .write_all(message.as_bytes())
+            .and_then(|()| stream.flush())
+        {
+            break Err(error);
+        }
+
+        // The figure is only delivered once it has been written to the connection.
+        let mut guard = lock(mutex);
+        guard.delivered = guard.delivered.max(sent);
+        drop(guard);
+        changed.notify_all();
+    };
+    lock(mutex).clients -= 1;
+    changed.notify_all();
+    result
+}
+
+/// Open a URL in the default web browser.
+///
+/// # Arguments
+///
+/// * `url` - URL.
+///
+/// # Note
+///
+/// This runs in a background thread, so a failure to launch the web browser is reported on the
+/// standard error stream instead of panicking.
+fn open_browser(url: &str) {
+    #[cfg(target_os = "macos")]
+    let mut command = std::process::Command::new("open");
+    #[cfg(target_os = "windows")]
+    let mut command = std::process::Command::new("explorer");
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let mut command = std::process::Command::new("xdg-open");
+    if let Err(error) = command.arg(url).status() {
+        eprintln!("Failed to open the web browser: {error}");
+    }
+}
+
+impl Figure {
+    /// Show the figure in a live-updating browser tab.
+    ///
+    /// Unlike [`Figure::show`], which opens a new browser tab on every call, this serves the
+    /// figure from a local preview server (see [`PreviewServer`]) and pushes it to the browser
+    /// tab that is already showing the previous figure. A new browser tab is only opened if no tab
+    /// is connected, including tabs left open by a previous run of the program, which reconnect
+    /// automatically.
+    ///
+    /// This function returns right away: the browser tab is opened (if necessary) and the figure
+    /// is delivered in the background, by the preview server shared by the whole program (see
+    /// [`PreviewServer::live`]), which keeps running until the program exits. A program that exits
+    /// right after showing its last figure should wait for the figure to be delivered first (see
+    /// [`PreviewServer::wait_until_delivered`]).
+    ///
+    /// # Panics
+    ///
+    /// If the preview server cannot be started. A failure to launch the web browser is reported on
+    /// the standard error stream.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use plotting::{quick_plot_2d, PreviewServer};
+    /// use std::time::Duration;
+    ///
+    /// // Iteratively refine a plot in the same browser tab.
+    /// for n in 1..=10 {
+    ///     let x: Vec<f64> = (0..=n).map(f64::from).collect();
+    ///     let y: Vec<f64> = x.iter().map(|x| x * x).collect();
+    ///     quick_plot_2d(x, y).show_live();
+    /// }
+    ///
+    /// // Make sure that the last figure is shown before the program exits.
+    /// PreviewServer::live().wait_until_delivered(Duration::from_secs(10));
+    /// ```
+    pub fn show_live(&self) {
+        let server = PreviewServer::live();
+        server.update(self);
+
+        // Give browser tabs left open by a previous run of the program the time to reconnect
+        // before opening a new one, without blocking the caller.
+        if server.clients() == 0 && !CONNECTING.swap(true, Ordering::SeqCst) {
+            std::thread::spawn(move || {
+                if !server.wait_for_client(CONNECT_TIMEOUT) {
+                    open_browser(&server.url());
+                }
+                CONNECTING.store(false, Ordering::SeqCst);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html::{HtmlOptionsBuilder, PlotlyJs};
+    use crate::quick::quick_plot_2d;
+    use std::io::Read;
+
+    /// Send a GET request to a preview server.
+    fn get(server: &PreviewServer, path: &str) -> TcpStream {
+        get_from_host(
+            server,
+            path,
+            &format!("localhost:{}", server.address.port()),
+        )
+    }
+
+    /// Send a GET request to a preview server with a given `Host` header.
+    fn get_from_host(server: &PreviewServer, path: &str, host: &str) -> TcpStream {
+        let mut stream = TcpStream::connect(server.address).unwrap();
+        write!(stream, "GET {path} HTTP/1.1\r\nHost: {host}\r\n\r\n").unwrap();
+        stream
+    }
+
+    #[test]
+    fn test_page() {
+        let server = PreviewServer::start(0).unwrap();
+        assert!(server.url().starts_with("http://127.0.0.1:"));
+        let mut response = String::new();
+        get(&server, "/").read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains(r#"new EventSource("/events")"#));
+        assert!(response.contains("https://cdn.plot.ly/plotly-3.0.1.min.js"));
+        let mut response = String::new();
+        get(&server, "/missing")
+            .read_to_string(&mut response)
+            .unwrap();
+        assert!(response.starts_with("HTTP/1.1 404 Not Found"));
+    }
+
+    #[test]
+    fn test_html_options() {
+        let options = HtmlOptionsBuilder::default()
+            .plotly_js(PlotlyJs::Path(String::from(
+                "http://localhost/plotly.min.js",
+            )))
+            .binary_threshold(2)
+            .build()
+            .unwrap();
+        let server = PreviewServer::start(0).unwrap().html_options(options);
+        let mut response = String::new();
+        get(&server, "/").read_to_string(&mut response).unwrap();
+        assert!(response.contains(r#"<script src="http://localhost/plotly.min.js"></script>"#));
+        assert!(!response.contains("https://cdn.plot.ly"));
+
+        // Data arrays are encoded as typed arrays.
+        server.update(&quick_plot_2d([1.0, 2.0], [3.0, 4.0]));
+        let mut events = BufReader::new(get(&server, "/events"));
+        let mut line = String::new();
+        while !line.starts_with("data: ") {
+            line.clear();
+            events.read_line(&mut line).unwrap();
+        }
+        assert!(line.contains(r#""bdata""#));
+    }
+
+    #[test]
+    fn test_host() {
+        let server = PreviewServer::start(0).unwrap();
+        let port = server.address.port();
+        for (host, status) in [
+            (format!("127.0.0.1:{port}"), "200 OK"),
+            (format!("LocalHost:{port}"), "200 OK"),
+            (String::from("localhost"), "403 Forbidden"),
+            (
+                format!("localhost:{}", port.wrapping_add(1)),
+                "403 Forbidden",
+            ),
+            (format!("attacker.example:{port}"), "403 Forbidden"),
+        ] {
+            let mut response = String::new();
+            get_from_host(&server, "/", &host)
+                .read_to_string(&mut response)
+                .unwrap();
+            assert!(
+                response.starts_with(&format!("HTTP/1.1 {status}")),
+                "{host}"
+            );
+        }
+
+        // Requests without a `Host` header are rejected as well.
+        let mut stream = TcpStream::connect(server.address).unwrap();
+        write!(stream, "GET / HTTP/1.1\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 403 Forbidden"));
+    }
+
+    #[test]
+    fn test_drop() {
+        let server = PreviewServer::start(0).unwrap();
+        let address = server.address;
+        let events = get(&server, "/events");
+        assert!(server.wait_for_client(Duration::from_secs(5)));
+        drop(server);
+
+        // The event stream is closed, and the port can be reused.
+        let mut response = String::new();
+        BufReader::new(events)
+            .read_to_string(&mut response)
+            .unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        let mut started = PreviewServer::start(address.port());
+        for _ in 0..50 {
+            if started.is_ok() {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(20));
+            started = PreviewServer::start(address.port());
+        }
+        assert!(started.is_ok());
+    }
+
+    #[test]
+    fn test_events() {
+        let server = PreviewServer::start(0).unwrap();
+        server.update(&quick_plot_2d([1.0, 2.0], [3.0, 4.0]));
+        assert!(!server.wait_until_delivered(Duration::ZERO));
+
+        let mut events = BufReader::new(get(&server, "/events"));
+        assert!(server.wait_for_client(Duration::from_secs(5)));
+        assert_eq!(server.clients(), 1);
+        let mut line = String::new();
+        while !line.starts_with("data: ") {
+            line.clear();
+            events.read_line(&mut line).unwrap();
+        }
+        assert!(line.contains(r#""x":[1.0,2.0]"#));
+        assert!(server.wait_until_delivered(Duration::from_secs(5)));
+
+        // Updates are pushed to connected browser tabs.
+        server.update(&quick_plot_2d([5.0, 6.0], [7.0, 8.0]));
+        line.clear();
+        while !line.starts_with("data: ") {
+            line.clear();
+            events.read_line(&mut line).unwrap();
+        }
+        assert!(line.contains(r#""x":[5.0,6.0]"#));
+    }
+}
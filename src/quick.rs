@@ -1,3 +1,4 @@
+use crate::data_source::DataSource;
 use crate::figure::Figure;
 use crate::format::FormatBuilder;
 use crate::trace::Trace;
@@ -6,8 +7,8 @@ use crate::trace::Trace;
 ///
 /// # Arguments
 ///
-/// * `x` - x-axis data.
-/// * `y` - y-axis data.
+/// * `x` - x-axis data (see [`DataSource`]).
+/// * `y` - y-axis data (see [`DataSource`]).
 ///
 /// # Returns
 ///
@@ -26,9 +27,9 @@ use crate::trace::Trace;
 ///
 /// Additional examples can be found at
 /// <https://tamaskis.github.io/plotting/plot_2d/quick_2d_plot.html>.
-pub fn quick_plot_2d(x: impl Into<Vec<f64>>, y: impl Into<Vec<f64>>) -> Figure {
+pub fn quick_plot_2d(x: impl DataSource, y: impl DataSource) -> Figure {
     // Create the trace.
-    let trace = Trace::new_2d(x.into(), y.into());
+    let trace = Trace::new_2d(x, y);
 
     // Formatting.
     let format = FormatBuilder::default().build().unwrap();
@@ -41,8 +42,8 @@ pub fn quick_plot_2d(x: impl Into<Vec<f64>>, y: impl Into<Vec<f64>>) -> Figure {
 ///
 /// # Arguments
 ///
-/// * `x` - x-axis data.
-/// * `y` - y-axis data.
+/// * `x` - x-axis data (see [`DataSource`]).
+/// * `y` - y-axis data (see [`DataSource`]).
 /// # `x_label` - x-axis label.
 /// * `y_label` - y-axis label.
 /// * `title` - Title.
@@ -71,14 +72,14 @@ pub fn quick_plot_2d(x: impl Into<Vec<f64>>, y: impl Into<Vec<f64>>) -> Figure {
 /// Additional examples can be found at
 /// <https://tamaskis.github.io/plotting/plot_2d/quick_2d_plot_with_labels.html>.
 pub fn quick_plot_2d_with_labels(
-    x: impl Into<Vec<f64>>,
-    y: impl Into<Vec<f64>>,
+    x: impl DataSource,
+    y: impl DataSource,
     x_label: impl Into<String>,
     y_label: impl Into<String>,
     title: impl Into<String>,
 ) -> Figure {
     // Create the trace.
-    let trace = Trace::new_2d(x.into(), y.into());
+    let trace = Trace::new_2d(x, y);
 
     // Formatting.
     let format = FormatBuilder::default()
@@ -96,9 +97,9 @@ pub fn quick_plot_2d_with_labels(
 ///
 /// # Arguments
 ///
-/// * `x` - x-axis data.
-/// * `y` - y-axis data.
-/// * `z` - z-axis data.
+/// * `x` - x-axis data (see [`DataSource`]).
+/// * `y` - y-axis data (see [`DataSource`]).
+/// * `z` - z-axis data (see [`DataSource`]).
 ///
 /// # Returns
 ///
@@ -116,13 +117,9 @@ pub fn quick_plot_2d_with_labels(
 ///
 /// Additional examples can be found at
 /// <https://tamaskis.github.io/plotting/plot_3d/quick_3d_plot.html>.
-pub fn quick_plot_3d(
-    x: impl Into<Vec<f64>>,
-    y: impl Into<Vec<f64>>,
-    z: impl Into<Vec<f64>>,
-) -> Figure {
+pub fn quick_plot_3d(x: impl DataSource, y: impl DataSource, z: impl DataSource) -> Figure {
     // Create the trace.
-    let trace = Trace::new_3d(x.into(), y.into(), z.into());
+    let trace = Trace::new_3d(x, y, z);
 
     // Formatting.
     let format = FormatBuilder::default().build().unwrap();
@@ -135,9 +132,9 @@ pub fn quick_plot_3d(
 ///
 /// # Arguments
 ///
-/// * `x` - x-axis data.
-/// * `y` - y-axis data.
-/// * `z` - z-axis data.
+/// * `x` - x-axis data (see [`DataSource`]).
+/// * `y` - y-axis data (see [`DataSource`]).
+/// * `z` - z-axis data (see [`DataSource`]).
 /// # `x_label` - x-axis label.
 /// * `y_label` - y-axis label.
 /// * `z_label` - z-axis label.
@@ -168,16 +165,16 @@ pub fn quick_plot_3d(
 /// Additional examples can be found at
 /// <https://tamaskis.github.io/plotting/plot_3d/quick_3d_plot_with_labels.html>.
 pub fn quick_plot_3d_with_labels(
-    x: impl Into<Vec<f64>>,
-    y: impl Into<Vec<f64>>,
-    z: impl Into<Vec<f64>>,
+    x: impl DataSource,
+    y: impl DataSource,
+    z: impl DataSource,
     x_label: impl Into<String>,
     y_label: impl Into<String>,
     z_label: impl Into<String>,
     title: impl Into<String>,
 ) -> Figure {
     // Create the trace.
-    let trace = Trace::new_3d(x.into(), y.into(), z.into());
+    let trace = Trace::new_3d(x, y, z);
 
     // Formatting.
     let format = FormatBuilder::default()
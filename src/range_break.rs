@@ -0,0 +1,87 @@
+use plotly::layout::RangeBreak as PlotlyRangeBreak;
+
+/// Range break.
+///
+/// A range break removes a range of values from a date axis (e.g. weekends or hours outside of
+/// trading hours), so that the data on either side of the break is drawn next to each other.
+///
+/// # Example
+///
+/// ```
+/// use plotting::{AxisType, Format, FormatBuilder, RangeBreak};
+///
+/// // Hide weekends and the hours between 5 PM and 9 AM.
+/// let format: Format = FormatBuilder::default()
+///     .x_axis_type(AxisType::Date)
+///     .x_range_breaks(vec![RangeBreak::weekends(), RangeBreak::hours(17.0, 9.0)])
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum RangeBreak {
+    /// Break over weekends (i.e. from Saturday to Monday).
+    Weekends,
+
+    /// Break over the hours of each day between two hours (where hours are in the range
+    /// `[0, 24)`).
+    Hours(f64, f64),
+
+    /// Break between two points in time (in milliseconds since the Unix epoch).
+    Between(f64, f64),
+}
+
+impl RangeBreak {
+    /// Constructor for a range break over weekends.
+    ///
+    /// # Returns
+    ///
+    /// Range break.
+    pub fn weekends() -> RangeBreak {
+        RangeBreak::Weekends
+    }
+
+    /// Constructor for a range break over the hours of each day between two hours.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Hour at which the break starts (in the range `[0, 24)`).
+    /// * `end` - Hour at which the break ends (in the range `[0, 24)`).
+    ///
+    /// # Returns
+    ///
+    /// Range break.
+    pub fn hours(start: f64, end: f64) -> RangeBreak {
+        RangeBreak::Hours(start, end)
+    }
+
+    /// Constructor for a range break between two points in time.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Time at which the break starts (in milliseconds since the Unix epoch).
+    /// * `end` - Time at which the break ends (in milliseconds since the Unix epoch).
+    ///
+    /// # Returns
+    ///
+    /// Range break.
+    pub fn between(start: f64, end: f64) -> RangeBreak {
+        RangeBreak::Between(start, end)
+    }
+
+    /// Create a plotly range break from this range break.
+    ///
+    /// # Returns
+    ///
+    /// Plotly range break.
+    pub(crate) fn plotly(&self) -> PlotlyRangeBreak {
+        match self {
+            RangeBreak::Weekends => PlotlyRangeBreak::new().bounds("sat", "mon"),
+            RangeBreak::Hours(start, end) => {
+                PlotlyRangeBreak::new().pattern("hour").bounds(*start, *end)
+            }
+            RangeBreak::Between(start, end) => PlotlyRangeBreak::new().bounds(*start, *end),
+        }
+    }
+}
@@ -0,0 +1,550 @@
+use crate::figure::Figure;
+use crate::html::{HtmlOptions, PlotlyJs};
+use crate::markdown;
+use crate::svg::escape;
+use file_io::save_string_to_file;
+use std::fmt::Display;
+use std::path::Path;
+
+/// Style sheet of reports.
+const STYLE: &str = r#"body { font-family: sans-serif; margin: 0 auto; max-width: 1100px; padding: 0 24px 48px; color: #222; }
+h1 { border-bottom: 1px solid #ddd; padding-bottom: 8px; }
+nav.toc { background: #f7f7f7; border: 1px solid #ddd; padding: 4px 24px; margin: 16px 0; }
+nav.tabs { border-bottom: 1px solid #ddd; margin: 16px 0; }
+nav.tabs button { background: none; border: 1px solid transparent; border-bottom: none; cursor: pointer; font-size: 1em; padding: 8px 16px; }
+nav.tabs button.active { background: #fff; border-color: #ddd; border-radius: 4px 4px 0 0; margin-bottom: -1px; font-weight: bold; }
+section.tab:not(.active) { display: none; }
+table { border-collapse: collapse; margin: 16px 0; }
+th, td { border: 1px solid #ddd; padding: 4px 12px; text-align: left; }
+th { background: #f7f7f7; }
+pre { background: #f7f7f7; padding: 8px 12px; overflow-x: auto; }
+blockquote { border-left: 4px solid #ddd; margin-left: 0; padding-left: 12px; color: #555; }
+.figure { margin: 16px 0; }"#;
+
+/// Script switching between the tabs of a tabbed report (and resizing the figures of the shown
+/// tab, since figures in hidden tabs cannot be sized).
+const TABS_SCRIPT: &str = r#"<script>
+    function showTab(id) {
+        for (const element of document.querySelectorAll("nav.tabs button, section.tab")) {
+            element.classList.toggle("active", element.id === id || element.dataset.tab === id);
+        }
+        for (const figure of document.querySelectorAll("section.tab.active .figure")) {
+            Plotly.Plots.resize(figure);
+        }
+    }
+    for (const button of document.querySelectorAll("nav.tabs button")) {
+        button.addEventListener("click", () => showTab(button.dataset.tab));
+    }
+</script>
+"#;
+
+/// Content of a report.
+enum Block {
+    /// Heading of a section (level 2) or subsection (level 3).
+    Heading(usize, String),
+
+    /// HTML.
+    Html(String),
+
+    /// Figure.
+    Figure(Box<Figure>),
+
+    /// Table (header and rows).
+    Table(Vec<String>, Vec<Vec<String>>),
+}
+
+/// Entry of the table of contents of a report.
+struct Contents<'a> {
+    /// ID of the heading.
+    id: String,
+
+    /// Text of the heading.
+    text: &'a str,
+
+    /// Entries of the subsections.
+    subsections: Vec<Contents<'a>>,
+}
+
+/// Report collecting several figures, text, and tables into a single self-contained HTML file.
+///
+/// Reports are organized into sections (see [`Report::add_heading`]), which are listed in a table
+/// of contents at the top of the report, or shown as tabs (see [`Report::tabs`]). All figures of
+/// a report share a single plotly.js include, which is embedded in the HTML file by default so that
+/// the report can be viewed offline (its source, and the encoding of the data of the figures, can
+/// be set using [`Report::html_options`]).
+///
+/// # Example
+///
+/// ```
+/// use plotting::{quick_plot_2d, Report};
+///
+/// let report = Report::new("Test Report")
+///     .add_markdown("Results of the **nightly** test run.")
+///     .add_heading("Speed")
+///     .add_figure(quick_plot_2d([0.0, 1.0, 2.0], [0.0, 50.0, 60.0]))
+///     .add_heading("Summary")
+///     .add_table(["Test", "Result"], [["Speed", "Passed"], ["Altitude", "Failed"]]);
+///
+/// // Save the report to an HTML file.
+/// report.save_html("folder/report.html");
+/// ```
+pub struct Report {
+    /// Title.
+    title: String,
+
+    /// Content.
+    blocks: Vec<Block>,
+
+    /// Whether to show the sections as tabs.
+    tabs: bool,
+
+    /// HTML export options.
+    html_options: HtmlOptions,
+}
+
+impl Report {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Title of the report.
+    ///
+    /// # Returns
+    ///
+    /// Empty report (embedding plotly.js, see [`PlotlyJs::Inline`]).
+    pub fn new(title: impl Into<String>) -> Report {
+        Report {
+            title: title.into(),
+            blocks: Vec::new(),
+            tabs: false,
+            html_options: HtmlOptions {
+                plotly_js: PlotlyJs::Inline,
+                ..HtmlOptions::default()
+            },
+        }
+    }
+
+    /// Add a heading starting a new section to the report.
+    ///
+    /// Sections are listed in the table of contents (or shown as tabs).
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text of the heading.
+    ///
+    /// # Returns
+    ///
+    /// The report with the heading added.
+    pub fn add_heading(mut self, text: impl Into<String>) -> Self {
+        self.blocks.push(Block::Heading(2, text.into()));
+        self
+    }
+
+    /// Add a heading starting a new subsection (of the current section) to the report.
+    ///
+    /// Subsections are listed under their section in the table of contents.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text of the heading.
+    ///
+    /// # Returns
+    ///
+    /// The report with the heading added.
+    pub fn add_subheading(mut self, text: impl Into<String>) -> Self {
+        self.blocks.push(Block::Heading(3, text.into()));
+        self
+    }
+
+    /// Add Markdown text to the report.
+    ///
+    /// # Arguments
+    ///
+    /// * `markdown` - Markdown text.
+    ///
+    /// # Returns
+    ///
+    /// The report with the text added.
+    ///
+    /// # Note
+    ///
+    /// This is not a full CommonMark implementation: only the following subset of Markdown is
+    /// supported.
+    ///
+    /// * ATX headings (`#` to `######`).
+    /// * Paragraphs, separated by blank lines (lines of a paragraph are joined by spaces).
+    /// * Unordered (`-`, `*`, or `+`) and ordered (`1.`) lists, with one item per line.
+    /// * Fenced code blocks (```` ``` ````, with an optional language).
+    /// * Block quotes (`>`), with one block quote per line.
+    /// * Horizontal rules (`---`, `***`, or `___`).
+    /// * Inline code (`` `code` ``), bold (`**bold**`) and italic (`*italic*` or `_italic_`)
+    ///   text, and inline links (`[text](url)`) with a relative URL or an `http`, `https`, or
+    ///   `mailto` URL (links with other URLs, e.g. `javascript:` URLs, are shown as plain text).
+    ///
+    /// Anything else is not supported: nested lists are flattened, images are shown as links, and
+    /// tables, setext headings, indented code blocks, reference links, and backslash escapes are
+    /// shown as plain text. Raw HTML is escaped (use [`Report::add_html`] to add HTML). Headings in
+    /// Markdown text are not listed in the table of contents (use [`Report::add_heading`] and
+    /// [`Report::add_subheading`] instead).
+    pub fn add_markdown(mut self, markdown: &str) -> Self {
+        self.blocks.push(Block::Html(markdown::to_html(markdown)));
+        self
+    }
+
+    /// Add HTML to the report.
+    ///
+    /// # Arguments
+    ///
+    /// * `html` - HTML (inserted into the report as is).
+    ///
+    /// # Returns
+    ///
+    /// The report with the HTML added.
+    pub fn add_html(mut self, html: impl Into<String>) -> Self {
+        self.blocks.push(Block::Html(html.into()));
+        self
+    }
+
+    /// Add a figure to the report.
+    ///
+    /// # Arguments
+    ///
+    /// * `figure` - Figure.
+    ///
+    /// # Returns
+    ///
+    /// The report with the figure added.
+    pub fn add_figure(mut self, figure: Figure) -> Self {
+        self.blocks.push(Block::Figure(Box::new(figure)));
+        self
+    }
+
+    /// Add a table to the report.
+    ///
+    /// # Arguments
+    ///
+    /// * `header` - Header of the table (names of the columns).
+    /// * `rows` - Rows of the table.
+    ///
+    /// # Returns
+    ///
+    /// The report with the table added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::Report;
+    ///
+    /// let report = Report::new("Run Times").add_table(
+    ///     ["Machine", "Run time [s]"],
+    ///     vec![vec![String::from("machine-1"), 4.2.to_string()]],
+    /// );
+    /// ```
+    pub fn add_table<H, R, C>(mut self, header: H, rows: R) -> Self
+    where
+        H: IntoIterator,
+        H::Item: Display,
+        R: IntoIterator<Item = C>,
+        C: IntoIterator,
+        C::Item: Display,
+    {
+        let header = header.into_iter().map(|cell| cell.to_string()).collect();
+        let rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(|cell| cell.to_string()).collect())
+            .collect();
+        self.blocks.push(Block::Table(header, rows));
+        self
+    }
+
+    /// Show the sections of the report as tabs (instead of one after another, with a table of
+    /// contents).
+    ///
+    /// Content added before the first heading is shown in the first tab (below its heading).
+    ///
+    /// # Arguments
+    ///
+    /// * `tabs` - `true` to show the sections as tabs, `false` otherwise.
+    ///
+    /// # Returns
+    ///
+    /// The report with the layout updated.
+    pub fn tabs(mut self, tabs: bool) -> Self {
+        self.tabs = tabs;
+        self
+    }
+
+    /// Set the HTML export options of the report (the source of plotly.js, and the encoding of the
+    /// data of the figures).
+    ///
+    /// # Arguments
+    ///
+    /// * `html_options` - HTML export options (e.g. [`PlotlyJs::Cdn`] for smaller reports, which
+    ///   require an internet connection to be viewed).
+    ///
+    /// # Returns
+    ///
+    /// The report with the HTML export options updated.
+    pub fn html_options(mut self, html_options: HtmlOptions) -> Self {
+        self.html_options = html_options;
+        self
+    }
+
+    /// Get the report as a standalone HTML document.
+    ///
+    /// # Returns
+    ///
+    /// HTML document.
+    pub fn to_html(&self) -> String {
+        let title = escape(&self.title);
+        let mut html = format!(
+            "<!doctype html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\" />\n\
+             <title>{title}</title>\n<style>\n{STYLE}\n</style>\n{}</head>\n<body>\n\
+             <h1>{title}</h1>\n",
+            self.html_options.plotly_js.scripts()
+        );
+
+        // Table of contents (or tabs).
+        let sections = self.sections();
+        if self.tabs {
+            html.push_str("<nav class=\"tabs\">\n");
+            for (index, Contents { id, text, .. }) in sections.iter().enumerate() {
+                let active = if index == 0 { " class=\"active\"" } else { "" };
+                html.push_str(&format!(
+                    "<button data-tab=\"{id}\"{active}>{}</button>\n",
+                    escape(text)
+                ));
+            }
+            html.push_str("</nav>\n");
+        } else if !sections.is_empty() {
+            html.push_str("<nav class=\"toc\">\n<h2>Contents</h2>\n<ul>\n");
+            for Contents {
+                id,
+                text,
+                subsections,
+            } in &sections
+            {
+                html.push_str(&format!("<li><a href=\"#{id}\">{}</a>", escape(text)));
+                if !subsections.is_empty() {
+                    html.push_str("\n<ul>\n");
+                    for Contents { id, text, .. } in subsections {
+                        html.push_str(&format!(
+                            "<li><a href=\"#{id}\">{}</a></li>\n",
+                            escape(text)
+                        ));
+                    }
+                    html.push_str("</ul>\n");
+                }
+                html.push_str("</li>\n");
+            }
+            html.push_str("</ul>\n</nav>\n");
+        }
+
+        // Content (in tabbed reports, the content before the first heading is moved into the first
+        // tab, so that it is not shown on every tab).
+        let mut blocks: Vec<&Block> = self.blocks.iter().collect();
+        if self.tabs
+            && let Some(first) = blocks
+                .iter()
+                .position(|block| matches!(block, Block::Heading(2, _)))
+        {
+            blocks[..=first].rotate_right(1);
+        }
+        let encoding = self.html_options.encoding();
+        let (mut section, mut subsection, mut figure) = (0, 0, 0);
+        for block in blocks {
+            match block {
+                Block::Heading(2, text) => {
+                    if section > 0 {
+                        html.push_str("</section>\n");
+                    }
+                    section += 1;
+                    subsection = 0;
+                    let id = format!("section-{section}");
+                    let class = match (self.tabs, section) {
+                        (true, 1) => " class=\"tab active\"",
+                        (true, _) => " class=\"tab\"",
+                        (false, _) => "",
+                    };
+                    html.push_str(&format!(
+                        "<section id=\"{id}\"{class}>\n<h2>{}</h2>\n",
+                        escape(text)
+                    ));
+                }
+                Block::Heading(level, text) => {
+                    subsection += 1;
+                    html.push_str(&format!(
+                        "<h{level} id=\"section-{section}-{subsection}\">{}</h{level}>\n",
+                        escape(text)
+                    ));
+                }
+                Block::Html(content) => {
+                    html.push_str(content);
+                    html.push('\n');
+                }
+                Block::Figure(fig) => {
+                    figure += 1;
+                    let spec = fig.plotly_with_encoding(encoding).to_json();
+                    html.push_str(&format!(
+                        "<div id=\"figure-{figure}\" class=\"figure\"></div>\n<script>\
+                         Plotly.newPlot(\"figure-{figure}\", {});</script>\n",
+                        spec.replace("</", "<\\/")
+                    ));
+                }
+                Block::Table(header, rows) => {
+                    html.push_str("<table>\n<thead>\n<tr>");
+                    for cell in header {
+                        html.push_str(&format!("<th>{}</th>", escape(cell)));
+                    }
+                    html.push_str("</tr>\n</thead>\n<tbody>\n");
+                    for row in rows {
+                        html.push_str("<tr>");
+                        for cell in row {
+                            html.push_str(&format!("<td>{}</td>", escape(cell)));
+                        }
+                        html.push_str("</tr>\n");
+                    }
+                    html.push_str("</tbody>\n</table>\n");
+                }
+            }
+        }
+        if section > 0 {
+            html.push_str("</section>\n");
+        }
+        if self.tabs {
+            html.push_str(TABS_SCRIPT);
+        }
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    /// Save the report to a standalone HTML file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the HTML file.
+    ///
+    /// # Panics
+    ///
+    /// If some error is encountered while creating the file or writing to it.
+    pub fn save_html<P: AsRef<Path>>(&self, path: P) {
+        save_string_to_file(&self.to_html(), path.as_ref());
+    }
+
+    /// Sections of the report (for the table of contents).
+    ///
+    /// # Returns
+    ///
+    /// Table of contents entry of each section.
+    fn sections(&self) -> Vec<Contents<'_>> {
+        let mut sections: Vec<Contents> = Vec::new();
+        for block in &self.blocks {
+            match block {
+                Block::Heading(2, text) => {
+                    sections.push(Contents {
+                        id: format!("section-{}", sections.len() + 1),
+                        text,
+                        subsections: Vec::new(),
+                    });
+                }
+                Block::Heading(_, text) => {
+                    let section = sections.len();
+                    if let Some(Contents { subsections, .. }) = sections.last_mut() {
+                        subsections.push(Contents {
+                            id: format!("section-{section}-{}", subsections.len() + 1),
+                            text,
+                            subsections: Vec::new(),
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+        sections
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quick::quick_plot_2d;
+
+    #[test]
+    fn test_to_html() {
+        let html = Report::new("Report <1>")
+            .add_markdown("Intro with **bold** text.")
+            .add_heading("Speed")
+            .add_figure(quick_plot_2d([1.0, 2.0], [3.0, 4.0]))
+            .add_subheading("Details")
+            .add_figure(quick_plot_2d([1.0, 2.0], [5.0, 6.0]))
+            .add_heading("Summary")
+            .add_table(["Test", "Result"], [["a", "<b>"]])
+            .to_html();
+
+        // plotly.js is embedded by default.
+        assert!(!html.contains("https://cdn.plot.ly/plotly-3.0.1.min.js"));
+        assert!(html.contains("plotly.js v3.0.1"));
+        assert_eq!(html.matches("class=\"figure\"></div>").count(), 2);
+
+        // Table of contents.
+        assert!(html.contains("<li><a href=\"#section-1\">Speed</a>"));
+        assert!(html.contains("<li><a href=\"#section-1-1\">Details</a></li>"));
+        assert!(html.contains("<li><a href=\"#section-2\">Summary</a>"));
+        assert!(html.contains("<h3 id=\"section-1-1\">Details</h3>"));
+
+        // Escaping, Markdown, and tables.
+        assert!(html.contains("<title>Report &lt;1&gt;</title>"));
+        assert!(html.contains("<p>Intro with <strong>bold</strong> text.</p>"));
+        assert!(html.contains("<tr><td>a</td><td>&lt;b&gt;</td></tr>"));
+        assert!(!html.contains("nav class=\"tabs\""));
+    }
+
+    #[test]
+    fn test_cdn() {
+        let html = Report::new("Report")
+            .add_figure(quick_plot_2d([1.0, 2.0], [3.0, 4.0]))
+            .add_figure(quick_plot_2d([1.0, 2.0], [5.0, 6.0]))
+            .html_options(HtmlOptions::default())
+            .to_html();
+
+        // A single plotly.js include shared by all figures.
+        assert_eq!(html.matches("cdn.plot.ly/plotly-3.0.1.min.js").count(), 1);
+        assert!(!html.contains("plotly.js v3.0.1"));
+    }
+
+    #[test]
+    fn test_tabs() {
+        let html = Report::new("Report")
+            .add_markdown("Intro")
+            .add_heading("First")
+            .add_heading("Second")
+            .tabs(true)
+            .to_html();
+        assert!(html.contains("<button data-tab=\"section-1\" class=\"active\">First</button>"));
+        assert!(html.contains("<button data-tab=\"section-2\">Second</button>"));
+        assert!(html.contains("<section id=\"section-1\" class=\"tab active\">"));
+        assert!(html.contains("<section id=\"section-2\" class=\"tab\">"));
+
+        // Content before the first heading is shown in the first tab.
+        assert!(html.contains(
+            "<section id=\"section-1\" class=\"tab active\">\n<h2>First</h2>\n<p>Intro</p>"
+        ));
+        assert!(!html.contains("nav class=\"toc\""));
+    }
+
+    #[test]
+    fn test_script_escaping() {
+        let fig = quick_plot_2d([1.0], [2.0]);
+        let html = Report::new("Report")
+            .add_figure(Figure::new(
+                fig.traces
+                    .into_iter()
+                    .map(|trace| trace.name("</script>"))
+                    .collect(),
+                fig.format,
+            ))
+            .to_html();
+        assert!(!html.contains("\"</script>\""));
+        assert!(html.contains("<\\/script>"));
+    }
+}
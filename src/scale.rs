@@ -0,0 +1,912 @@
+use crate::axis_data::AxisData;
+use crate::axis_type::AxisType;
+use crate::category_order::CategoryOrder;
+
+/// Number of milliseconds in a second.
+const SECOND: f64 = 1e3;
+
+/// Number of milliseconds in a minute.
+const MINUTE: f64 = 60.0 * SECOND;
+
+/// Number of milliseconds in an hour.
+const HOUR: f64 = 60.0 * MINUTE;
+
+/// Number of milliseconds in a day.
+const DAY: f64 = 24.0 * HOUR;
+
+/// Largest number of milliseconds from the Unix epoch that is labeled as a date (as in JavaScript,
+/// which cannot represent points in time further from the epoch).
+const MAX_DATE: f64 = 1e8 * DAY;
+
+/// Maximum number of multiples of a step between tick marks (more multiples indicate that the
+/// step is negligible compared to the range, e.g. because of floating-point precision).
+const MAX_MULTIPLES: f64 = 1000.0;
+
+/// Abbreviated month names.
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Categories along a categorical axis.
+#[derive(Default)]
+pub(crate) struct Categories(Vec<String>);
+
+impl Categories {
+    /// Collect the categories of the data plotted along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Data plotted along the axis, paired with the values plotted along the other axis
+    ///   (used to order the categories by their totals).
+    /// * `order` - Category order.
+    ///
+    /// # Returns
+    ///
+    /// Categories.
+    pub(crate) fn collect<'a>(
+        data: impl Iterator<Item = (&'a AxisData, &'a AxisData)>,
+        order: Option<&CategoryOrder>,
+    ) -> Categories {
+        let mut categories: Vec<String> = Vec::new();
+        let mut totals: Vec<f64> = Vec::new();
+        for (data, other) in data {
+            for (index, key) in category_keys(data).into_iter().enumerate() {
+                let position = match categories.iter().position(|category| *category == key) {
+                    Some(position) => position,
+                    None => {
+                        categories.push(key);
+                        totals.push(0.0);
+                        categories.len() - 1
+                    }
+                };
+                if let Some(value) = other.as_numeric().and_then(|other| other.get(index))
+                    && !value.is_nan()
+                {
+                    totals[position] += value;
+                }
+            }
+        }
+
+        // Order the categories.
+        let mut order_keys: Vec<(String, f64)> = categories.into_iter().zip(totals).collect();
+        match order {
+            Some(CategoryOrder::Alphabetical) => order_keys.sort_by(|a, b| a.0.cmp(&b.0)),
+            Some(CategoryOrder::TotalAscending) => order_keys.sort_by(|a, b| a.1.total_cmp(&b.1)),
+            Some(CategoryOrder::TotalDescending) => {
+                order_keys.sort_by(|a, b| b.1.total_cmp(&a.1));
+            }
+            Some(CategoryOrder::Explicit(explicit)) => order_keys.sort_by_key(|(key, _)| {
+                explicit
+                    .iter()
+                    .position(|category| category == key)
+                    .unwrap_or(explicit.len())
+            }),
+            Some(CategoryOrder::AsGiven) | None => {}
+        }
+        Categories(order_keys.into_iter().map(|(key, _)| key).collect())
+    }
+
+    /// Get the positions of data along the categorical axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Data plotted along the axis.
+    ///
+    /// # Returns
+    ///
+    /// Position (i.e. index of the category) of each data point.
+    pub(crate) fn positions(&self, data: &AxisData) -> Vec<f64> {
+        category_keys(data)
+            .iter()
+            .map(|key| {
+                self.0
+                    .iter()
+                    .position(|category| category == key)
+                    .map_or(f64::NAN, |position| position as f64)
+            })
+            .collect()
+    }
+}
+
+/// Get the category keys of axis data (numeric data is converted to strings).
+///
+/// # Arguments
+///
+/// * `data` - Axis data.
+///
+/// # Returns
+///
+/// Category key of each data point.
+fn category_keys(data: &AxisData) -> Vec<String> {
+    match data {
+        AxisData::Numeric(data) => data.iter().map(|value| value.to_string()).collect(),
+        AxisData::Categorical(data) => data.to_vec(),
+    }
+}
+
+/// Tick mark along an axis.
+pub(crate) struct Tick {
+    /// Position of the tick mark as a fraction of the axis length.
+    pub(crate) fraction: f64,
+
+    /// Tick label.
+    pub(crate) label: String,
+}
+
+/// Mapping from data values to positions along an axis.
+pub(crate) struct Scale {
+    /// Axis type.
+    pub(crate) axis_type: AxisType,
+
+    /// Categories (only used for categorical axes).
+    pub(crate) categories: Categories,
+
+    /// Lower limit of the axis (in transformed coordinates, i.e. the base-10 logarithm for log
+    /// axes).
+    lower: f64,
+
+    /// Upper limit of the axis (in transformed coordinates).
+    upper: f64,
+}
+
+impl Scale {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis_type` - Axis type.
+    /// * `categories` - Categories (only used for categorical axes).
+    /// * `values` - Positions of the data plotted along the axis (category indices for categorical
+    ///   axes).
+    /// * `range` - Axis range (determined from the data if not specified).
+    /// * `padding` - Fraction of the data range to add on either side of the data if the range is
+    ///   determined from the data.
+    ///
+    /// # Returns
+    ///
+    /// Scale.
+    pub(crate) fn new(
+        axis_type: AxisType,
+        categories: Categories,
+        values: impl Iterator<Item = f64>,
+        range: Option<(f64, f64)>,
+        padding: f64,
+    ) -> Scale {
+        let mut scale = Scale {
+            axis_type,
+            categories,
+            lower: 0.0,
+            upper: 1.0,
+        };
+        if let Some((lower, upper)) = range {
+            // Ranges of log axes are specified as base-10 logarithms (as in plotly).
+            (scale.lower, scale.upper) = (lower, upper);
+            return scale;
+        }
+        if axis_type == AxisType::Category {
+            let count = scale.categories.0.len().max(1) as f64;
+            (scale.lower, scale.upper) = (-0.5, count - 0.5);
+            return scale;
+        }
+
+        // Range of the data.
+        let (mut lower, mut upper) = values
+            .map(|value| scale.transform(value))
+            .filter(|value| value.is_finite())
+            .fold(
+                (f64::INFINITY, f64::NEG_INFINITY),
+                |(lower, upper), value| (lower.min(value), upper.max(value)),
+            );
+        if lower > upper {
+            (lower, upper) = (0.0, 1.0);
+        } else if lower == upper {
+            let half_width = if axis_type == AxisType::Date {
+                DAY
+            } else {
+                (lower.abs() * 0.1).max(0.5)
+            };
+            (lower, upper) = (lower - half_width, upper + half_width);
+        } else {
+            // The padding is computed so that it does not overflow for data spanning (almost) the
+            // whole range of floating-point numbers.
+            let pad = upper * padding - lower * padding;
+            (lower, upper) = (lower - pad, upper + pad);
+        }
+        (scale.lower, scale.upper) = (lower.max(f64::MIN), upper.min(f64::MAX));
+        scale
+    }
+
+    /// Transform a data value to the coordinates in which the axis is linear.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Data value.
+    ///
+    /// # Returns
+    ///
+    /// Transformed value (`NaN` if the value cannot be shown on the axis).
+    fn transform(&self, value: f64) -> f64 {
+        match self.axis_type {
+            AxisType::Log if value > 0.0 => value.log10(),
+            AxisType::Log => f64::NAN,
+            _ => value,
+        }
+    }
+
+    /// Get the position of a data value along the axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Data value.
+    ///
+    /// # Returns
+    ///
+    /// Position as a fraction of the axis length (`0.0` at the lower limit and `1.0` at the upper
+    /// limit, `NaN` if the value cannot be shown on the axis).
+    pub(crate) fn fraction(&self, value: f64) -> f64 {
+        self.position(self.transform(value))
+    }
+
+    /// Get the position of a value in transformed coordinates along the axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value in transformed coordinates.
+    ///
+    /// # Returns
+    ///
+    /// Position as a fraction of the axis length.
+    fn position(&self, value: f64) -> f64 {
+        let position = (value - self.lower) / (self.upper - self.lower);
+        if position.is_finite() || value.is_nan() {
+            position
+        } else {
+            // The differences overflow if the axis spans (almost) the whole range of
+            // floating-point numbers, while their halves do not.
+            (value / 2.0 - self.lower / 2.0) / (self.upper / 2.0 - self.lower / 2.0)
+        }
+    }
+
+    /// Get the range of the axis.
+    ///
+    /// # Returns
+    ///
+    /// Lower and upper limits of the axis (in transformed coordinates, i.e. as base-10 logarithms
+    /// for log axes, as for the axis ranges of a [`crate::Format`]).
+    #[cfg(feature = "png")]
+    pub(crate) fn range(&self) -> (f64, f64) {
+        (self.lower, self.upper)
+    }
+
+    /// Get the tick marks along the axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_ticks` - Maximum number of tick marks.
+    ///
+    /// # Returns
+    ///
+    /// Tick marks.
+    pub(crate) fn ticks(&self, max_ticks: usize) -> Vec<Tick> {
+        let max_ticks = max_ticks.max(2);
+        let (lower, upper) = (self.lower.min(self.upper), self.lower.max(self.upper));
+        let tick = |value: f64, label: String| Tick {
+            fraction: self.position(value),
+            label,
+        };
+        match self.axis_type {
+            AxisType::Category => {
+                let count = self.categories.0.len();
+                let step = count.div_ceil(max_ticks).max(1);
+                (0..count)
+                    .step_by(step)
+                    .filter(|&index| (lower..=upper).contains(&(index as f64)))
+                    .map(|index| tick(index as f64, self.categories.0[index].clone()))
+                    .collect()
+            }
+            AxisType::Log if upper - lower >= 1.0 => {
+                let step = ((upper.floor() - lower.ceil()) / max_ticks as f64)
+                    .ceil()
+                    .max(1.0);
+                multiples(lower, upper, step)
+                    .map(|exponent| tick(exponent, format_number(10f64.powf(exponent), 0)))
+                    .collect()
+            }
+            AxisType::Log => {
+                let (lower_value, upper_value) = (10f64.powf(lower), 10f64.powf(upper));
+                let step = nice_step((upper_value - lower_value) / max_ticks as f64);
+                let ticks: Vec<Tick> = multiples(lower_value, upper_value, step)
+                    .filter(|value| *value > 0.0)
+                    .map(|value| tick(value.log10(), format_tick(value, step)))
+                    .collect();
+                if ticks.is_empty() {
+                    // The range of the values is not representable (e.g. above 1e308).
+                    let (exponents, _) = evenly_spaced(lower, upper, max_ticks);
+                    return exponents
+                        .into_iter()
+                        .map(|exponent| {
+                            let value = 10f64.powf(exponent);
+                            tick(exponent, format_tick(value, value / 100.0))
+                        })
+                        .collect();
+                }
+                ticks
+            }
+            AxisType::Date if lower >= -MAX_DATE && upper <= MAX_DATE => {
+                date_ticks(lower, upper, max_ticks)
+                    .into_iter()
+                    .map(|(value, label)| tick(value, label))
+                    .collect()
+            }
+            AxisType::Date | AxisType::Linear => linear_ticks(lower, upper, max_ticks)
+                .into_iter()
+                .map(|(value, label)| tick(value, label))
+                .collect(),
+        }
+    }
+}
+
+/// Get the tick marks of a linear axis.
+///
+/// # Arguments
+///
+/// * `lower` - Lower limit of the axis.
+/// * `upper` - Upper limit of the axis.
+/// * `max_ticks` - Maximum number of tick marks (at least 2).
+///
+/// # Returns
+///
+/// Positions and labels of the tick marks (multiples of a "nice" step, or a fixed number of evenly
+/// spaced tick marks if the range is too large or too small for such a step).
+fn linear_ticks(lower: f64, upper: f64, max_ticks: usize) -> Vec<(f64, String)> {
+    let step = nice_step((upper - lower) / max_ticks as f64);
+    let mut values: Vec<f64> = if (upper - lower).is_finite() {
+        multiples(lower, upper, step).collect()
+    } else {
+        Vec::new()
+    };
+    let mut step = step;
+    if values.is_empty() {
+        // Evenly spaced tick marks are not round numbers, so they are labeled with more digits.
+        (values, step) = evenly_spaced(lower, upper, max_ticks);
+        step /= 100.0;
+    }
+    values
+        .into_iter()
+        .map(|value| (value, format_tick(value, step)))
+        .collect()
+}
+
+/// Get evenly spaced values within a range (without overflowing for large ranges).
+///
+/// # Arguments
+///
+/// * `lower` - Lower limit of the range.
+/// * `upper` - Upper limit of the range.
+/// * `count` - Number of values (at least 2).
+///
+/// # Returns
+///
+/// Values (including both limits), and the step between them.
+fn evenly_spaced(lower: f64, upper: f64, count: usize) -> (Vec<f64>, f64) {
+    let intervals = (count - 1) as f64;
+    let values = (0..count)
+        .map(|index| {
+            let t = index as f64 / intervals;
+            lower * (1.0 - t) + upper * t
+        })
+        .collect();
+    (values, upper / intervals - lower / intervals)
+}
+
+/// Round a step between tick marks up to a "nice" step (1, 2, or 5 times a power of 10).
+///
+/// # Arguments
+///
+/// * `step` - Step.
+///
+/// # Returns
+///
+/// Nice step.
+fn nice_step(step: f64) -> f64 {
+    if !step.is_finite() || step <= 0.0 {
+        return 1.0;
+    }
+    let magnitude = 10f64.powf(step.log10().floor());
+    let normalized = step / magnitude;
+    let nice = if normalized <= 1.0 {
+        1.0
+    } else if normalized <= 2.0 {
+        2.0
+    } else if normalized <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * magnitude
+}
+
+/// Get the multiples of a step within a range.
+///
+/// # Arguments
+///
+/// * `lower` - Lower limit of the range.
+/// * `upper` - Upper limit of the range.
+/// * `step` - Step.
+///
+/// # Returns
+///
+/// Multiples of the step within the range (none if there are more than [`MAX_MULTIPLES`], or if
+/// they cannot be computed).
+fn multiples(lower: f64, upper: f64, step: f64) -> impl Iterator<Item = f64> {
+    let first = (lower / step - 1e-9).ceil();
+    let last = (upper / step + 1e-9).floor();
+    let (first, last) = if last - first <= MAX_MULTIPLES {
+        (first as i64, last as i64)
+    } else {
+        (1, 0)
+    };
+    (first..=last).map(move |multiple| {
+        let value = multiple as f64 * step;
+        // Avoid labels such as "-0".
+        if value == 0.0 { 0.0 } else { value }
+    })
+}
+
+/// Get the number of decimal places needed to label multiples of a step.
+///
+/// # Arguments
+///
+/// * `step` - Step.
+///
+/// # Returns
+///
+/// Number of decimal places.
+fn decimals(step: f64) -> usize {
+    (-step.log10().floor()).max(0.0) as usize
+}
+
+/// Format a multiple of a step between tick marks as a tick label.
+///
+/// # Arguments
+///
+/// * `value` - Multiple of the step.
+/// * `step` - Step.
+///
+/// # Returns
+///
+/// Tick label, with as many digits as needed to distinguish multiples of the step (using exponent
+/// notation for very small steps, and for values that are very large or need more digits than
+/// floating-point numbers have).
+fn format_tick(value: f64, step: f64) -> String {
+    let step_magnitude = step.abs().log10().floor();
+    let magnitude = if value == 0.0 {
+        step_magnitude
+    } else {
+        value.abs().log10().floor()
+    };
+    let digits = magnitude - step_magnitude;
+    if step_magnitude >= -4.0 && magnitude < 16.0 && digits <= 15.0 {
+        return format_number(value, decimals(step));
+    }
+    if value == 0.0 {
+        return String::from("0");
+    }
+
+    // At most 17 significant digits are needed to represent a floating-point number.
+    let precision = if digits.is_finite() {
+        digits.clamp(0.0, 16.0) as usize
+    } else {
+        16
+    };
+    let label = format!("{value:.precision$e}");
+    match label.split_once('e') {
+        Some((mantissa, exponent)) => format!("{}e{exponent}", trim_zeros(mantissa.to_string())),
+        None => label,
+    }
+}
+
+/// Format a number as a tick label (using SI prefixes for large numbers, as in plotly).
+///
+/// # Arguments
+///
+/// * `value` - Number.
+/// * `decimals` - Number of decimal places.
+///
+/// # Returns
+///
+/// Tick label.
+pub(crate) fn format_number(value: f64, decimals: usize) -> String {
+    let prefixes = [(1e12, "T"), (1e9, "G"), (1e6, "M"), (1e3, "k")];
+    if value.abs() >= 1e4 {
+        for (factor, prefix) in prefixes {
+            if value.abs() >= factor {
+                let scaled = value / factor;
+                let decimals = decimals + factor.log10() as usize;
+                return format!("{}{prefix}", trim_zeros(format!("{scaled:.decimals$}")));
+            }
+        }
+    }
+    if value != 0.0 && value.abs() < 1e-4 {
+        return format!("{value:e}");
+    }
+    format!("{value:.decimals$}")
+}
+
+/// Trim trailing zeros after the decimal point of a formatted number.
+///
+/// # Arguments
+///
+/// * `number` - Formatted number.
+///
+/// # Returns
+///
+/// Formatted number without trailing zeros.
+fn trim_zeros(number: String) -> String {
+    if number.contains('.') {
+        number
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    } else {
+        number
+    }
+}
+
+/// Convert a number of days since the Unix epoch to a civil (proleptic Gregorian) date.
+///
+/// # Arguments
+///
+/// * `days` - Number of days since 1970-01-01.
+///
+/// # Returns
+///
+/// Year, month (1-12), and day (1-31).
+///
+/// # References
+///
+/// * \[1\] <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Convert a civil (proleptic Gregorian) date to a number of days since the Unix epoch.
+///
+/// # Arguments
+///
+/// * `year` - Year.
+/// * `month` - Month (1-12).
+/// * `day` - Day (1-31).
+///
+/// # Returns
+///
+/// Number of days since 1970-01-01.
+///
+/// # References
+///
+/// * \[1\] <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let month = i64::from(month);
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Get the tick marks along a date axis.
+///
+/// # Arguments
+///
+/// * `lower` - Lower limit of the axis (in milliseconds since the Unix epoch).
+/// * `upper` - Upper limit of the axis (in milliseconds since the Unix epoch).
+/// * `max_ticks` - Maximum number of tick marks.
+///
+/// # Returns
+///
+/// Positions (in milliseconds since the Unix epoch) and labels of the tick marks.
+fn date_ticks(lower: f64, upper: f64, max_ticks: usize) -> Vec<(f64, String)> {
+    let target = (upper - lower) / max_ticks as f64;
+
+    // Steps of less than a month are multiples of a fixed duration.
+    let fixed_steps = [
+        SECOND,
+        2.0 * SECOND,
+        5.0 * SECOND,
+        10.0 * SECOND,
+        15.0 * SECOND,
+        30.0 * SECOND,
+        MINUTE,
+        2.0 * MINUTE,
+        5.0 * MINUTE,
+        10.0 * MINUTE,
+        15.0 * MINUTE,
+        30.0 * MINUTE,
+        HOUR,
+        2.0 * HOUR,
+        3.0 * HOUR,
+        6.0 * HOUR,
+        12.0 * HOUR,
+        DAY,
+        2.0 * DAY,
+        7.0 * DAY,
+        14.0 * DAY,
+    ];
+    if target < SECOND {
+        let step = nice_step(target);
+        return multiples(lower, upper, step)
+            .map(|value| (value, format_time(value, decimals(step / SECOND))))
+            .collect();
+    }
+    if let Some(&step) = fixed_steps.iter().find(|&&step| step >= target) {
+        return multiples(lower, upper, step)
+            .map(|value| {
+                let label = if step < DAY {
+                    format_time(value, 0)
+                } else {
+                    format_date(value, false)
+                };
+                (value, label)
+            })
+            .collect();
+    }
+
+    // Steps of a month or more are multiples of a number of months.
+    let target_months = target / (30.44 * DAY);
+    let step_months = [1, 2, 3, 6, 12]
+        .into_iter()
+        .find(|&months| months as f64 >= target_months)
+        .unwrap_or_else(|| 12 * nice_step(target_months / 12.0).ceil() as i64);
+    let (year, month, _) = civil_from_days((lower / DAY).floor() as i64);
+    let mut index = (year * 12 + i64::from(month) - 1).div_euclid(step_months) * step_months;
+    let mut ticks = Vec::new();
+    loop {
+        let (year, month) = (index.div_euclid(12), index.rem_euclid(12) as u32 + 1);
+        let value = days_from_civil(year, month, 1) as f64 * DAY;
+        if value > upper {
+            break;
+        }
+        if value >= lower {
+            let label = if step_months % 12 == 0 {
+                year.to_string()
+            } else {
+                format!("{} {year}", MONTHS[month as usize - 1])
+            };
+            ticks.push((value, label));
+        }
+        index += step_months;
+    }
+    ticks
+}
+
+/// Format a point in time as a time of day.
+///
+/// # Arguments
+///
+/// * `millis` - Milliseconds since the Unix epoch.
+/// * `decimals` - Number of decimal places of the seconds (seconds are omitted if they are zero
+///   and no decimal places are requested).
+///
+/// # Returns
+///
+/// Time of day (e.g. `"14:30"`).
+fn format_time(millis: f64, decimals: usize) -> String {
+    let time_of_day = millis.rem_euclid(DAY);
+    let hours = (time_of_day / HOUR).floor();
+    let minutes = ((time_of_day - hours * HOUR) / MINUTE).floor();
+    let seconds = (time_of_day - hours * HOUR - minutes * MINUTE) / SECOND;
+    if decimals > 0 {
+        let width = decimals + 3;
+        format!("{hours:02}:{minutes:02}:{seconds:0width$.decimals$}")
+    } else if seconds >= 0.5 {
+        format!("{hours:02}:{minutes:02}:{:02}", seconds.round())
+    } else if time_of_day == 0.0 {
+        format_date(millis, false)
+    } else {
+        format!("{hours:02}:{minutes:02}")
+    }
+}
+
+/// Format a point in time as a date.
+///
+/// # Arguments
+///
+/// * `millis` - Milliseconds since the Unix epoch.
+/// * `with_year` - Whether to include the year.
+///
+/// # Returns
+///
+/// Date (e.g. `"Mar 1"`).
+fn format_date(millis: f64, with_year: bool) -> String {
+    let (year, month, day) = civil_from_days((millis / DAY).floor() as i64);
+    if with_year {
+        format!("{} {day}, {year}", MONTHS[month as usize - 1])
+    } else {
+        format!("{} {day}", MONTHS[month as usize - 1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_ticks() {
+        let scale = Scale::new(
+            AxisType::Linear,
+            Categories::default(),
+            [0.0, 9.7].into_iter(),
+            None,
+            0.0,
+        );
+        let labels: Vec<String> = scale.ticks(5).into_iter().map(|tick| tick.label).collect();
+        assert_eq!(labels, ["0", "2", "4", "6", "8"]);
+        assert_eq!(scale.fraction(0.0), 0.0);
+
+        let scale = Scale::new(
+            AxisType::Linear,
+            Categories::default(),
+            [].into_iter(),
+            Some((-0.25, 0.25)),
+            0.0,
+        );
+        let labels: Vec<String> = scale.ticks(5).into_iter().map(|tick| tick.label).collect();
+        assert_eq!(labels, ["-0.2", "-0.1", "0.0", "0.1", "0.2"]);
+    }
+
+    #[test]
+    fn test_log_ticks() {
+        let scale = Scale::new(
+            AxisType::Log,
+            Categories::default(),
+            [1.0, 1e5, -1.0].into_iter(),
+            None,
+            0.0,
+        );
+        let labels: Vec<String> = scale.ticks(10).into_iter().map(|tick| tick.label).collect();
+        assert_eq!(labels, ["1", "10", "100", "1000", "10k", "100k"]);
+        assert!(scale.fraction(-1.0).is_nan());
+        assert_eq!(scale.fraction(1e5), 1.0);
+    }
+
+    #[test]
+    fn test_date_ticks() {
+        // 2024-03-01 to 2024-03-04.
+        let start = days_from_civil(2024, 3, 1) as f64 * DAY;
+        let ticks = date_ticks(start, start + 3.0 * DAY, 5);
+        let labels: Vec<&str> = ticks.iter().map(|(_, label)| label.as_str()).collect();
+        assert_eq!(labels, ["Mar 1", "Mar 2", "Mar 3", "Mar 4"]);
+        let ticks = date_ticks(start, start + 3.0 * DAY, 8);
+        let labels: Vec<&str> = ticks.iter().map(|(_, label)| label.as_str()).collect();
+        assert_eq!(
+            labels,
+            [
+                "Mar 1", "12:00", "Mar 2", "12:00", "Mar 3", "12:00", "Mar 4"
+            ]
+        );
+
+        // 2023-11-15 to 2024-05-15.
+        let ticks = date_ticks(
+            days_from_civil(2023, 11, 15) as f64 * DAY,
+            days_from_civil(2024, 5, 15) as f64 * DAY,
+            4,
+        );
+        let labels: Vec<&str> = ticks.iter().map(|(_, label)| label.as_str()).collect();
+        assert_eq!(labels, ["Jan 2024", "Mar 2024", "May 2024"]);
+    }
+
+    #[test]
+    fn test_civil_days() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2024, 3, 1), 19_783);
+        assert_eq!(civil_from_days(19_783), (2024, 3, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    }
+
+    #[test]
+    fn test_categories() {
+        let x1 = AxisData::from(["b", "a"]);
+        let y1 = AxisData::from([1.0, 5.0]);
+        let x2 = AxisData::from(["c", "b"]);
+        let y2 = AxisData::from([2.0, 3.0]);
+        let data = [(&x1, &y1), (&x2, &y2)];
+
+        let categories = Categories::collect(data.into_iter(), None);
+        assert_eq!(categories.0, ["b", "a", "c"]);
+        assert_eq!(categories.positions(&x2), [2.0, 0.0]);
+
+        let categories =
+            Categories::collect(data.into_iter(), Some(&CategoryOrder::TotalDescending));
+        assert_eq!(categories.0, ["a", "b", "c"]);
+
+        let categories = Categories::collect(
+            data.into_iter(),
+            Some(&CategoryOrder::Explicit(vec![String::from("c")])),
+        );
+        assert_eq!(categories.0, ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(12_000.0, 0), "12k");
+        assert_eq!(format_number(2_500_000.0, 0), "2.5M");
+        assert_eq!(format_number(0.5, 1), "0.5");
+        assert_eq!(format_number(0.00001, 5), "1e-5");
+    }
+
+    #[test]
+    fn test_format_tick() {
+        assert_eq!(format_tick(0.2, 0.1), "0.2");
+        assert_eq!(format_tick(12_000.0, 1000.0), "12k");
+        assert_eq!(format_tick(3e-305, 1e-305), "3e-305");
+        assert_eq!(format_tick(0.0, 1e-305), "0");
+        assert_eq!(format_tick(2e20, 1e20), "2e20");
+        assert_eq!(format_tick(1e15 + 0.25, 0.05), "1.0000000000000002e15");
+    }
+
+    #[test]
+    fn test_extreme_ranges() {
+        let labels = |values: [f64; 2]| {
+            let scale = Scale::new(
+                AxisType::Linear,
+                Categories::default(),
+                values.into_iter(),
+                None,
+                0.05,
+            );
+            let ticks = scale.ticks(5);
+            assert!(!ticks.is_empty());
+            assert!(
+                ticks
+                    .iter()
+                    .all(|tick| (0.0..=1.0).contains(&tick.fraction))
+            );
+            ticks.into_iter().map(|tick| tick.label).collect::<Vec<_>>()
+        };
+
+        // Ranges whose span overflows.
+        let huge = labels([-1e308, 1e308]);
+        assert_eq!(huge.len(), 5);
+        assert_eq!(huge, ["-1.1e308", "-5.5e307", "0", "5.5e307", "1.1e308"]);
+        let log = Scale::new(
+            AxisType::Log,
+            Categories::default(),
+            [1e300, 1.5e308].into_iter(),
+            Some((300.0, 310.0)),
+            0.0,
+        );
+        assert!(!log.ticks(5).is_empty());
+
+        // Tiny ranges are labeled in exponent notation.
+        assert_eq!(labels([0.0, 1e-300]), ["0", "5e-301", "1e-300"]);
+
+        // Ranges needing more digits than floating-point numbers have.
+        let precise = labels([1e15, 1e15 + 1.0]);
+        assert_eq!(precise[0], "1e15");
+        assert!(
+            precise
+                .iter()
+                .all(|label| label.len() <= 21 && !label.ends_with('T'))
+        );
+
+        // Dates too far from the epoch are labeled as numbers.
+        let scale = Scale::new(
+            AxisType::Date,
+            Categories::default(),
+            [-1e308, 1e308].into_iter(),
+            None,
+            0.0,
+        );
+        assert_eq!(scale.ticks(5).len(), 5);
+    }
+}
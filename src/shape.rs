@@ -0,0 +1,516 @@
+use crate::colors::Color;
+use crate::coordinates::Coordinates;
+use crate::line_style::LineStyle;
+use plotly::layout::{Shape as PlotlyShape, ShapeLayer, ShapeLine, ShapeType};
+
+/// Layer on which a shape is drawn.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Layer {
+    /// Draw the shape above the traces.
+    #[default]
+    Above,
+
+    /// Draw the shape below the traces.
+    Below,
+}
+
+impl From<Layer> for ShapeLayer {
+    fn from(layer: Layer) -> Self {
+        match layer {
+            Layer::Above => ShapeLayer::Above,
+            Layer::Below => ShapeLayer::Below,
+        }
+    }
+}
+
+/// Kind of shape.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub(crate) enum ShapeKind {
+    /// Line segment from `(x0, y0)` to `(x1, y1)`.
+    Line,
+
+    /// Rectangle with opposite corners at `(x0, y0)` and `(x1, y1)`.
+    Rect,
+
+    /// Circle (or ellipse) inscribed in the rectangle with opposite corners at `(x0, y0)` and
+    /// `(x1, y1)`.
+    Circle,
+
+    /// Arbitrary SVG path.
+    Path,
+}
+
+/// Shape.
+///
+/// A shape is a geometric primitive (e.g. a reference line or a shaded region) drawn on a figure.
+///
+/// # Constructing a shape
+///
+/// See the following constructors:
+///
+/// * [`Shape::hline`] - for a horizontal reference line spanning the plotting area.
+/// * [`Shape::vline`] - for a vertical reference line spanning the plotting area.
+/// * [`Shape::hspan`] - for a horizontal band spanning the plotting area.
+/// * [`Shape::vspan`] - for a vertical band spanning the plotting area.
+/// * [`Shape::line`] - for a line segment.
+/// * [`Shape::rect`] - for a rectangle.
+/// * [`Shape::circle`] - for a circle.
+/// * [`Shape::path`] - for an arbitrary SVG path.
+///
+/// # Example
+///
+/// ```
+/// use plotting::{Color, Layer, LineStyle, NamedColor, Shape, quick_plot_2d};
+///
+/// // Upper limit.
+/// let limit = Shape::hline(8.0)
+///     .line_color(Color::named(NamedColor::Red))
+///     .line_style(LineStyle::Dash);
+///
+/// // Tolerance band.
+/// let band = Shape::hspan(3.0, 5.0)
+///     .fill_color(Color::named(NamedColor::Green).alpha(0.2))
+///     .line_width(0.0)
+///     .layer(Layer::Below);
+///
+/// let fig = quick_plot_2d([1.0, 2.0, 3.0], [2.0, 4.0, 9.0])
+///     .add_shape(limit)
+///     .add_shape(band);
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Shape {
+    /// Kind of shape.
+    pub(crate) kind: ShapeKind,
+
+    /// x-coordinate of the first point.
+    pub(crate) x0: f64,
+
+    /// y-coordinate of the first point.
+    pub(crate) y0: f64,
+
+    /// x-coordinate of the second point.
+    pub(crate) x1: f64,
+
+    /// y-coordinate of the second point.
+    pub(crate) y1: f64,
+
+    /// SVG path (only used for paths).
+    pub(crate) path: Option<String>,
+
+    /// Coordinate system of the x-coordinates.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) x_coordinates: Coordinates,
+
+    /// Coordinate system of the y-coordinates.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) y_coordinates: Coordinates,
+
+    /// Line color.
+    pub(crate) line_color: Option<Color>,
+
+    /// Line width.
+    pub(crate) line_width: Option<f64>,
+
+    /// Line style.
+    pub(crate) line_style: Option<LineStyle>,
+
+    /// Fill color.
+    pub(crate) fill_color: Option<Color>,
+
+    /// Layer on which the shape is drawn.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) layer: Layer,
+}
+
+impl Shape {
+    /// Base constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Kind of shape.
+    /// * `x0` - x-coordinate of the first point.
+    /// * `y0` - y-coordinate of the first point.
+    /// * `x1` - x-coordinate of the second point.
+    /// * `y1` - y-coordinate of the second point.
+    ///
+    /// # Returns
+    ///
+    /// Shape in data coordinates.
+    pub(crate) fn new(kind: ShapeKind, x0: f64, y0: f64, x1: f64, y1: f64) -> Shape {
+        Shape {
+            kind,
+            x0,
+            y0,
+            x1,
+            y1,
+            path: None,
+            x_coordinates: Coordinates::Data,
+            y_coordinates: Coordinates::Data,
+            line_color: None,
+            line_width: None,
+            line_style: None,
+            fill_color: None,
+            layer: Layer::default(),
+        }
+    }
+
+    /// Constructor for a horizontal reference line spanning the entire width of the plotting area.
+    ///
+    /// # Arguments
+    ///
+    /// * `y` - y-coordinate of the line (in data coordinates).
+    ///
+    /// # Returns
+    ///
+    /// Shape.
+    pub fn hline(y: f64) -> Shape {
+        let mut shape = Shape::new(ShapeKind::Line, 0.0, y, 1.0, y);
+        shape.x_coordinates = Coordinates::Paper;
+        shape
+    }
+
+    /// Constructor for a vertical reference line spanning the entire height of the plotting area.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - x-coordinate of the line (in data coordinates).
+    ///
+    /// # Returns
+    ///
+    /// Shape.
+    pub fn vline(x: f64) -> Shape {
+        let mut shape = Shape::new(ShapeKind::Line, x, 0.0, x, 1.0);
+        shape.y_coordinates = Coordinates::Paper;
+        shape
+    }
+
+    /// Constructor for a horizontal band spanning the entire width of the plotting area.
+    ///
+    /// # Arguments
+    ///
+    /// * `y0` - Lower y-coordinate of the band (in data coordinates).
+    /// * `y1` - Upper y-coordinate of the band (in data coordinates).
+    ///
+    /// # Returns
+    ///
+    /// Shape.
+    pub fn hspan(y0: f64, y1: f64) -> Shape {
+        let mut shape = Shape::new(ShapeKind::Rect, 0.0, y0, 1.0, y1);
+        shape.x_coordinates = Coordinates::Paper;
+        shape
+    }
+
+    /// Constructor for a vertical band spanning the entire height of the plotting area.
+    ///
+    /// # Arguments
+    ///
+    /// * `x0` - Lower x-coordinate of the band (in data coordinates).
+    /// * `x1` - Upper x-coordinate of the band (in data coordinates).
+    ///
+    /// # Returns
+    ///
+    /// Shape.
+    pub fn vspan(x0: f64, x1: f64) -> Shape {
+        let mut shape = Shape::new(ShapeKind::Rect, x0, 0.0, x1, 1.0);
+        shape.y_coordinates = Coordinates::Paper;
+        shape
+    }
+
+    /// Constructor for a line segment.
+    ///
+    /// # Arguments
+    ///
+    /// * `x0` - x-coordinate of the start point.
+    /// * `y0` - y-coordinate of the start point.
+    /// * `x1` - x-coordinate of the end point.
+    /// * `y1` - y-coordinate of the end point.
+    ///
+    /// # Returns
+    ///
+    /// Shape (in data coordinates by default).
+    pub fn line(x0: f64, y0: f64, x1: f64, y1: f64) -> Shape {
+        Shape::new(ShapeKind::Line, x0, y0, x1, y1)
+    }
+
+    /// Constructor for a rectangle.
+    ///
+    /// # Arguments
+    ///
+    /// * `x0` - x-coordinate of the first corner.
+    /// * `y0` - y-coordinate of the first corner.
+    /// * `x1` - x-coordinate of the opposite corner.
+    /// * `y1` - y-coordinate of the opposite corner.
+    ///
+    /// # Returns
+    ///
+    /// Shape (in data coordinates by default).
+    pub fn rect(x0: f64, y0: f64, x1: f64, y1: f64) -> Shape {
+        Shape::new(ShapeKind::Rect, x0, y0, x1, y1)
+    }
+
+    /// Constructor for a circle.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - x-coordinate of the center.
+    /// * `y` - y-coordinate of the center.
+    /// * `radius` - Radius.
+    ///
+    /// # Returns
+    ///
+    /// Shape (in data coordinates by default).
+    ///
+    /// # Note
+    ///
+    /// The circle will appear as an ellipse if the x- and y-axes are not equally scaled.
+    pub fn circle(x: f64, y: f64, radius: f64) -> Shape {
+        Shape::new(
+            ShapeKind::Circle,
+            x - radius,
+            y - radius,
+            x + radius,
+            y + radius,
+        )
+    }
+
+    /// Constructor for an arbitrary SVG path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - SVG path (e.g. `"M 1 1 L 2 3 L 3 1 Z"`).
+    ///
+    /// # Returns
+    ///
+    /// Shape (in data coordinates by default).
+    pub fn path(path: impl Into<String>) -> Shape {
+        let mut shape = Shape::new(ShapeKind::Path, 0.0, 0.0, 0.0, 0.0);
+        shape.path = Some(path.into());
+        shape
+    }
+
+    /// Set the coordinate system of the shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinates` - Coordinate system (used for both the x- and y-coordinates).
+    ///
+    /// # Returns
+    ///
+    /// The shape with the updated coordinate system.
+    pub fn coordinates(mut self, coordinates: Coordinates) -> Self {
+        self.x_coordinates = coordinates;
+        self.y_coordinates = coordinates;
+        self
+    }
+
+    /// Set the line color for this shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `line_color` - Line color.
+    ///
+    /// # Returns
+    ///
+    /// The shape with the updated line color.
+    pub fn line_color(mut self, line_color: Color) -> Self {
+        self.line_color = Some(line_color);
+        self
+    }
+
+    /// Set the line width for this shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `line_width` - Line width (use `0.0` to hide the outline).
+    ///
+    /// # Returns
+    ///
+    /// The shape with the updated line width.
+    pub fn line_width(mut self, line_width: f64) -> Self {
+        self.line_width = Some(line_width);
+        self
+    }
+
+    /// Set the line style for this shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `line_style` - Line style.
+    ///
+    /// # Returns
+    ///
+    /// The shape with the updated line style.
+    pub fn line_style(mut self, line_style: LineStyle) -> Self {
+        self.line_style = Some(line_style);
+        self
+    }
+
+    /// Set the fill color for this shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `fill_color` - Fill color.
+    ///
+    /// # Returns
+    ///
+    /// The shape with the updated fill color.
+    pub fn fill_color(mut self, fill_color: Color) -> Self {
+        self.fill_color = Some(fill_color);
+        self
+    }
+
+    /// Set the layer on which this shape is drawn.
+    ///
+    /// # Arguments
+    ///
+    /// * `layer` - Layer.
+    ///
+    /// # Returns
+    ///
+    /// The shape with the updated layer.
+    pub fn layer(mut self, layer: Layer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Create a plotly shape from this shape.
+    ///
+    /// # Returns
+    ///
+    /// Plotly shape.
+    pub(crate) fn plotly(&self) -> PlotlyShape {
+        // Line settings.
+        let mut line = ShapeLine::new();
+        if let Some(line_color) = &self.line_color {
+            line = line.color(line_color.to_plotly_rgba());
+        }
+        if let Some(line_width) = self.line_width {
+            line = line.width(line_width);
+        }
+        if let Some(line_style) = self.line_style {
+            line = line.dash(line_style.into());
+        }
+
+        // Geometry.
+        let mut shape = PlotlyShape::new()
+            .x_ref(self.x_coordinates.to_plotly_ref("x"))
+            .y_ref(self.y_coordinates.to_plotly_ref("y"))
+            .line(line)
+            .layer(self.layer.into());
+        shape = match self.kind {
+            ShapeKind::Line => shape.shape_type(ShapeType::Line),
+            ShapeKind::Rect => shape.shape_type(ShapeType::Rect),
+            ShapeKind::Circle => shape.shape_type(ShapeType::Circle),
+            ShapeKind::Path => shape.shape_type(ShapeType::Path),
+        };
+        if let Some(path) = &self.path {
+            shape = shape.path(path);
+        } else {
+            shape = shape.x0(self.x0).y0(self.y0).x1(self.x1).y1(self.y1);
+        }
+
+        // Fill settings.
+        if let Some(fill_color) = &self.fill_color {
+            shape = shape.fill_color(fill_color.to_plotly_rgba());
+        }
+
+        shape
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colors::NamedColor;
+    use serde_json::json;
+
+    #[test]
+    fn test_hline() {
+        let shape = Shape::hline(2.0);
+        assert_eq!(shape.kind, ShapeKind::Line);
+        assert_eq!(
+            (shape.x0, shape.y0, shape.x1, shape.y1),
+            (0.0, 2.0, 1.0, 2.0)
+        );
+        assert_eq!(shape.x_coordinates, Coordinates::Paper);
+        assert_eq!(shape.y_coordinates, Coordinates::Data);
+    }
+
+    #[test]
+    fn test_vspan() {
+        let shape = Shape::vspan(1.0, 3.0);
+        assert_eq!(shape.kind, ShapeKind::Rect);
+        assert_eq!(
+            (shape.x0, shape.y0, shape.x1, shape.y1),
+            (1.0, 0.0, 3.0, 1.0)
+        );
+        assert_eq!(shape.x_coordinates, Coordinates::Data);
+        assert_eq!(shape.y_coordinates, Coordinates::Paper);
+    }
+
+    #[test]
+    fn test_circle() {
+        let shape = Shape::circle(1.0, 2.0, 0.5);
+        assert_eq!(shape.kind, ShapeKind::Circle);
+        assert_eq!(
+            (shape.x0, shape.y0, shape.x1, shape.y1),
+            (0.5, 1.5, 1.5, 2.5)
+        );
+    }
+
+    #[test]
+    fn test_coordinates() {
+        let shape = Shape::rect(0.0, 0.0, 0.5, 0.5).coordinates(Coordinates::Paper);
+        assert_eq!(shape.x_coordinates, Coordinates::Paper);
+        assert_eq!(shape.y_coordinates, Coordinates::Paper);
+        assert_eq!(shape.layer, Layer::Above);
+    }
+
+    #[test]
+    fn test_plotly() {
+        // Line in mixed coordinates, with the default settings.
+        let json = serde_json::to_value(Shape::hline(2.0).plotly()).unwrap();
+        assert_eq!(json["type"], "line");
+        assert_eq!(
+            (&json["xref"], &json["yref"]),
+            (&json!("paper"), &json!("y"))
+        );
+        assert_eq!((&json["x0"], &json["y0"]), (&json!(0.0), &json!(2.0)));
+        assert_eq!((&json["x1"], &json["y1"]), (&json!(1.0), &json!(2.0)));
+        assert_eq!(json["layer"], "above");
+        assert!(json.get("fillcolor").is_none());
+
+        // Rectangle with line and fill settings.
+        let shape = Shape::rect(1.0, 2.0, 3.0, 4.0)
+            .line_color(Color::named(NamedColor::Red))
+            .line_width(2.0)
+            .line_style(LineStyle::Dash)
+            .fill_color(Color::named(NamedColor::Blue))
+            .layer(Layer::Below);
+        let json = serde_json::to_value(shape.plotly()).unwrap();
+        assert_eq!(json["type"], "rect");
+        assert_eq!((&json["xref"], &json["yref"]), (&json!("x"), &json!("y")));
+        assert_eq!(json["line"]["color"], "rgba(255, 0, 0, 1)");
+        assert_eq!(json["line"]["width"], 2.0);
+        assert_eq!(json["line"]["dash"], "dash");
+        assert_eq!(json["fillcolor"], "rgba(0, 0, 255, 1)");
+        assert_eq!(json["layer"], "below");
+
+        // Path in paper coordinates.
+        let shape = Shape::path("M 0 0 L 1 1 Z").coordinates(Coordinates::Paper);
+        let json = serde_json::to_value(shape.plotly()).unwrap();
+        assert_eq!(json["type"], "path");
+        assert_eq!(json["path"], "M 0 0 L 1 1 Z");
+        assert_eq!(
+            (&json["xref"], &json["yref"]),
+            (&json!("paper"), &json!("paper"))
+        );
+        assert!(json.get("x0").is_none());
+    }
+}
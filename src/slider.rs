@@ -0,0 +1,199 @@
+use crate::menu::MenuButton;
+use crate::trace::Trace;
+use plotly::common::Pad;
+use plotly::layout::update_menu::ButtonMethod;
+use plotly::layout::{Slider as PlotlySlider, SliderCurrentValue, SliderMethod, SliderStep};
+
+/// Interactive slider placed on a figure.
+///
+/// Each step of a slider is a [`MenuButton`] whose changes (e.g. showing a subset of the traces)
+/// are applied when the slider is moved to that step. Sliders are only shown in HTML output (e.g.
+/// [`crate::Figure::show`] or [`crate::Figure::save_html`]).
+///
+/// See [`crate::Figure::parameter_sweep`] for a figure whose slider steps through the traces
+/// computed for a list of parameter values.
+///
+/// # Example
+///
+/// ```
+/// use plotting::{Figure, Format, MenuButton, Slider, Trace};
+///
+/// let figure = Figure::new(
+///     vec![
+///         Trace::new_2d([0.0, 1.0], [0.0, 1.0]),
+///         Trace::new_2d([0.0, 1.0], [0.0, 2.0]).visible(false),
+///     ],
+///     Format::default(),
+/// )
+/// .add_slider(
+///     Slider::new()
+///         .prefix("Gain: ")
+///         .add_step(MenuButton::new("1").show_only([0]))
+///         .add_step(MenuButton::new("2").show_only([1])),
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Slider {
+    /// Steps (the label of each step is shown below the slider).
+    pub(crate) steps: Vec<MenuButton>,
+
+    /// Index of the step that is initially active.
+    pub(crate) active: usize,
+
+    /// Text shown before the label of the active step above the slider.
+    pub(crate) prefix: Option<String>,
+}
+
+impl Slider {
+    /// Constructor.
+    ///
+    /// # Returns
+    ///
+    /// Slider without steps.
+    pub fn new() -> Slider {
+        Slider::default()
+    }
+
+    /// Add a step to the slider.
+    ///
+    /// # Arguments
+    ///
+    /// * `step` - Step (the button's label is shown below the slider, and its changes are applied
+    ///   when the slider is moved to the step).
+    ///
+    /// # Returns
+    ///
+    /// The slider with the step added.
+    pub fn add_step(mut self, step: MenuButton) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Set the step that is initially active.
+    ///
+    /// # Arguments
+    ///
+    /// * `active` - Index of the step that is initially active (defaults to `0`).
+    ///
+    /// # Returns
+    ///
+    /// The slider with the active step set.
+    ///
+    /// # Note
+    ///
+    /// * Setting the active step only moves the slider; it does not apply the changes of the step
+    ///   to the figure.
+    /// * If the index is out of range, the last step is initially active.
+    pub fn active(mut self, active: usize) -> Self {
+        self.active = active;
+        self
+    }
+
+    /// Set the text shown before the label of the active step above the slider.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - Text shown before the label of the active step (e.g. `"Gain: "`).
+    ///
+    /// # Returns
+    ///
+    /// The slider with the prefix set.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Create a plotly slider from this slider.
+    ///
+    /// # Arguments
+    ///
+    /// * `traces` - Traces of the figure that the slider is placed on.
+    ///
+    /// # Returns
+    ///
+    /// Plotly slider.
+    ///
+    /// # Panics
+    ///
+    /// If a step of the slider references a trace that the figure does not have.
+    pub(crate) fn plotly(&self, traces: &[Trace]) -> PlotlySlider {
+        let steps = self
+            .steps
+            .iter()
+            .map(|step| {
+                let (method, args) = step.plotly_method(traces);
+                SliderStep::new()
+                    .label(step.label.as_str())
+                    .method(match method {
+                        ButtonMethod::Restyle => SliderMethod::Restyle,
+                        ButtonMethod::Relayout => SliderMethod::Relayout,
+                        ButtonMethod::Update => SliderMethod::Update,
+                        ButtonMethod::Animate => SliderMethod::Animate,
+                        ButtonMethod::Skip => SliderMethod::Skip,
+                    })
+                    .args(args)
+            })
+            .collect();
+        let mut slider = PlotlySlider::new()
+            .active(self.active.min(self.steps.len().saturating_sub(1)) as i32)
+            .steps(steps)
+            .pad(Pad::new(50, 10, 0));
+        if let Some(prefix) = &self.prefix {
+            slider = slider.current_value(SliderCurrentValue::new().prefix(prefix.as_str()));
+        }
+        slider
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn test_plotly() {
+        let traces = vec![
+            Trace::new_2d([0.0], [1.0]),
+            Trace::new_2d([0.0], [2.0]).visible(false),
+        ];
+        let slider = Slider::new()
+            .prefix("k = ")
+            .active(1)
+            .add_step(MenuButton::new("1").show_only([0]).title("k = 1"))
+            .add_step(MenuButton::new("2").show_only([1]));
+        let json: Value = serde_json::to_value(slider.plotly(&traces)).unwrap();
+        assert_eq!(json["active"], 1);
+        assert_eq!(json["currentvalue"]["prefix"], "k = ");
+        assert_eq!(json["steps"][0]["method"], "update");
+        assert_eq!(
+            json["steps"][0]["args"],
+            serde_json::json!([{"visible": [true, false]}, {"title.text": "k = 1"}])
+        );
+        assert_eq!(json["steps"][1]["method"], "restyle");
+        assert_eq!(json["steps"][1]["label"], "2");
+    }
+
+    #[test]
+    fn test_active_out_of_range() {
+        let traces = vec![Trace::new_2d([0.0], [1.0])];
+        let slider = Slider::new()
+            .active(5)
+            .add_step(MenuButton::new("1").show_only([0]))
+            .add_step(MenuButton::new("2").hide([0]));
+        let json: Value = serde_json::to_value(slider.plotly(&traces)).unwrap();
+        assert_eq!(json["active"], 1);
+        let json: Value = serde_json::to_value(Slider::new().active(5).plotly(&traces)).unwrap();
+        assert_eq!(json["active"], 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "references trace")]
+    fn test_missing_trace() {
+        let traces = vec![Trace::new_2d([0.0], [1.0]).name("a")];
+        Slider::new()
+            .add_step(MenuButton::new("1").show_only(["b"]))
+            .plotly(&traces);
+    }
+}
@@ -0,0 +1,576 @@
+use crate::axis_data::AxisData;
+use crate::figure::Figure;
+use crate::html::HtmlOptions;
+use crate::preview::PreviewServer;
+use std::collections::VecDeque;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Default minimum interval between two refreshes of the output of a streaming figure.
+const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Where a streaming figure is shown.
+enum Output {
+    /// Nowhere (the figure is only accessible through [`StreamingFigure::figure`]).
+    None,
+
+    /// Live-updating browser tab served by the preview server shared by the whole program (see
+    /// [`Figure::show_live`]).
+    Live {
+        /// Whether the figure has been shown already.
+        shown: bool,
+    },
+
+    /// Browser tabs connected to a preview server.
+    Server(PreviewServer),
+
+    /// Self-refreshing HTML file.
+    Html(PathBuf),
+}
+
+/// Data points of a trace of a streaming figure.
+struct Series {
+    /// Decimated data points that dropped out of the rolling window.
+    history: Vec<[f64; 3]>,
+
+    /// Only every `stride`-th data point that drops out of the rolling window is kept in the
+    /// history.
+    stride: usize,
+
+    /// Number of data points that dropped out of the rolling window.
+    evicted: usize,
+
+    /// Most recent data points (at full resolution).
+    recent: VecDeque<[f64; 3]>,
+}
+
+impl Series {
+    /// Constructor.
+    ///
+    /// # Returns
+    ///
+    /// Series without data points.
+    fn new() -> Series {
+        Series {
+            history: Vec::new(),
+            stride: 1,
+            evicted: 0,
+            recent: VecDeque::new(),
+        }
+    }
+
+    /// Move the data points outside the rolling window into the history.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - Maximum number of recent data points (see [`StreamingFigure::window`]).
+    /// * `history` - Maximum number of data points in the history (see
+    ///   [`StreamingFigure::history`]).
+    fn trim(&mut self, window: Option<usize>, history: usize) {
+        while window.is_some_and(|window| self.recent.len() > window) {
+            let Some(point) = self.recent.pop_front() else {
+                break;
+            };
+            if history == 0 {
+                continue;
+            }
+            if self.evicted.is_multiple_of(self.stride) {
+                self.history.push(point);
+
+                // Halve the resolution of the history once it is full.
+                if self.history.len() > history {
+                    let mut index = 0;
+                    self.history.retain(|_| {
+                        index += 1;
+                        index % 2 == 1
+                    });
+                    self.stride *= 2;
+                }
+            }
+            self.evicted += 1;
+        }
+    }
+
+    /// Get the coordinates of the data points (history followed by the recent data points).
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - Axis (`0` for the x-axis, `1` for the y-axis, and `2` for the z-axis).
+    ///
+    /// # Returns
+    ///
+    /// Coordinates of the data points along the axis.
+    fn coordinates(&self, axis: usize) -> AxisData {
+        AxisData::Numeric(
+            self.history
+                .iter()
+                .chain(&self.recent)
+                .map(|point| point[axis])
+                .collect(),
+        )
+    }
+}
+
+/// Figure whose traces are appended to incrementally (e.g. for monitoring simulations or telemetry
+/// while they run).
+///
+/// A streaming figure keeps the most recent data points of each trace at full resolution (see
+/// [`StreamingFigure::window`]), optionally preceded by a decimated history of the older data
+/// points (see [`StreamingFigure::history`]). Appending data points refreshes the output of the
+/// figure (a live-updating browser tab or a self-refreshing HTML file), at most once per refresh
+/// interval (see [`StreamingFigure::refresh_interval`]).
+///
+/// # Example
+///
+/// ```no_run
+/// use plotting::{Figure, FormatBuilder, StreamingFigure, Trace};
+///
+/// // Figure with an (initially empty) trace.
+/// let trace = Trace::new_2d(Vec::<f64>::new(), Vec::<f64>::new()).name("temperature");
+/// let format = FormatBuilder::default().title("Telemetry").build().unwrap();
+///
+/// // Show the last 1000 samples in a live-updating browser tab, preceded by a decimated history
+/// // of at most 500 older samples.
+/// let mut fig = StreamingFigure::new(Figure::new(vec![trace], format))
+///     .window(1000)
+///     .history(500)
+///     .live();
+/// for step in 0..100_000 {
+///     let t = f64::from(step) * 0.01;
+///     fig.append(0, t, 20.0 + t.sin());
+/// }
+///
+/// // Show the final data points.
+/// fig.refresh().expect("Failed to refresh the figure.");
+/// ```
+pub struct StreamingFigure {
+    /// Figure (the data of its traces is replaced by the streamed data points).
+    figure: Figure,
+
+    /// Data points of each trace.
+    series: Vec<Series>,
+
+    /// Whether data points were added since the data of the traces of the figure was updated.
+    changed: bool,
+
+    /// Maximum number of recent data points of each trace (all data points are kept if `None`).
+    window: Option<usize>,
+
+    /// Maximum number of data points in the history of each trace.
+    history: usize,
+
+    /// Where the figure is shown.
+    output: Output,
+
+    /// Options for self-refreshing HTML files.
+    html_options: HtmlOptions,
+
+    /// Minimum interval between two refreshes of the output.
+    refresh_interval: Duration,
+
+    /// Time of the last refresh of the output.
+    last_refresh: Option<Instant>,
+}
+
+impl StreamingFigure {
+    /// Constructor.
+    ///
+    /// The data points already contained in the traces of the figure are the first data points of
+    /// the streaming figure.
+    ///
+    /// # Arguments
+    ///
+    /// * `figure` - Figure.
+    ///
+    /// # Returns
+    ///
+    /// Streaming figure (not shown anywhere until [`StreamingFigure::live`],
+    /// [`StreamingFigure::server`], or [`StreamingFigure::html_file`] is called).
+    ///
+    /// # Panics
+    ///
+    /// If the data of some trace is not numeric.
+    pub fn new(figure: Figure) -> StreamingFigure {
+        let series = figure
+            .traces
+            .iter()
+            .map(|trace| {
+                let numeric = |data: &AxisData| {
+                    data.as_numeric()
+                        .expect("Streaming figures only support numeric data.")
+                        .to_vec()
+                };
+                let (x, y) = (numeric(&trace.x), numeric(&trace.y));
+                let z = trace.z.as_ref().map(numeric);
+                let mut series = Series::new();
+                for (index, (x, y)) in x.into_iter().zip(y).enumerate() {
+                    let z = z.as_ref().map_or(f64::NAN, |z| z[index]);
+                    series.recent.push_back([x, y, z]);
+                }
+                series
+            })
+            .collect();
+        StreamingFigure {
+            figure,
+            series,
+            changed: true,
+            window: None,
+            history: 0,
+            output: Output::None,
+            html_options: HtmlOptions::default(),
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
+            last_refresh: None,
+        }
+    }
+
+    /// Keep only a rolling window of the most recent data points of each trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - Maximum number of recent data points of each trace.
+    ///
+    /// # Returns
+    ///
+    /// The streaming figure with the rolling window set.
+    pub fn window(mut self, window: usize) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    /// Keep a decimated history of the data points that dropped out of the rolling window (see
+    /// [`StreamingFigure::window`]).
+    ///
+    /// Once the history is full, its resolution is halved (i.e. every other data point is
+    /// dropped), so that it always covers all of the older data points.
+    ///
+    /// # Arguments
+    ///
+    /// * `history` - Maximum number of data points in the history of each trace.
+    ///
+    /// # Returns
+    ///
+    /// The streaming figure with the history set.
+    pub fn history(mut self, history: usize) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Set the minimum interval between two refreshes of the output (defaults to 100 ms).
+    ///
+    /// # Arguments
+    ///
+    /// * `refresh_interval` - Minimum interval between two refreshes (for self-refreshing HTML
+    ///   files, also the interval at which the browser reloads the file, rounded to whole seconds
+    ///   and at least one second).
+    ///
+    /// # Returns
+    ///
+    /// The streaming figure with the refresh interval set.
+    pub fn refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
+        self
+    }
+
+    /// Show the figure in a live-updating browser tab (see [`Figure::show_live`]).
+    ///
+    /// # Returns
+    ///
+    /// The streaming figure, shown in a live-updating browser tab.
+    pub fn live(mut self) -> Self {
+        self.output = Output::Live { shown: false };
+        self
+    }
+
+    /// Show the figure in the browser tabs connected to a preview server.
+    ///
+    /// # Arguments
+    ///
+    /// * `server` - Preview server.
+    ///
+    /// # Returns
+    ///
+    /// The streaming figure, shown by the preview server.
+    pub fn server(mut self, server: PreviewServer) -> Self {
+        self.output = Output::Server(server);
+        self
+    }
+
+    /// Write the figure to a self-refreshing HTML file, which a browser showing it reloads
+    /// periodically (see [`StreamingFigure::refresh_interval`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the HTML file.
+    ///
+    /// # Returns
+    ///
+    /// The streaming figure, written to the HTML file.
+    pub fn html_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.output = Output::Html(path.into());
+        self
+    }
+
+    /// Set the options for the self-refreshing HTML file (see [`StreamingFigure::html_file`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `html_options` - HTML export options (e.g. to embed plotly.js, so that the file can be
+    ///   viewed offline).
+    ///
+    /// # Returns
+    ///
+    /// The streaming figure with the HTML export options set.
+    pub fn html_options(mut self, html_options: HtmlOptions) -> Self {
+        self.html_options = html_options;
+        self
+    }
+
+    /// Append a data point to a 2D trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `trace_index` - Index of the trace.
+    /// * `x` - x-coordinate.
+    /// * `y` - y-coordinate.
+    ///
+    /// # Panics
+    ///
+    /// If there is no trace at `trace_index`.
+    ///
+    /// # Note
+    ///
+    /// Errors when refreshing the output are not reported (the output is refreshed again when the
+    /// next data point is appended). Call [`StreamingFigure::refresh`] to check for them.
+    pub fn append(&mut self, trace_index: usize, x: f64, y: f64) {
+        self.append_3d(trace_index, x, y, f64::NAN);
+    }
+
+    /// Append a data point to a 3D trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `trace_index` - Index of the trace.
+    /// * `x` - x-coordinate.
+    /// * `y` - y-coordinate.
+    /// * `z` - z-coordinate.
+    ///
+    /// # Panics
+    ///
+    /// If there is no trace at `trace_index`.
+    ///
+    /// # Note
+    ///
+    /// Errors when refreshing the output are not reported (the output is refreshed again when the
+    /// next data point is appended). Call [`StreamingFigure::refresh`] to check for them.
+    pub fn append_3d(&mut self, trace_index: usize, x: f64, y: f64, z: f64) {
+        let series = self
+            .series
+            .get_mut(trace_index)
+            .expect("There is no trace at the given index.");
+        series.recent.push_back([x, y, z]);
+        series.trim(self.window, self.history);
+        self.changed = true;
+        if self
+            .last_refresh
+            .is_none_or(|last_refresh| last_refresh.elapsed() >= self.refresh_interval)
+        {
+            let _ = self.refresh();
+        }
+    }
+
+    /// Refresh the output of the figure with all data points appended so far.
+    ///
+    /// Appending data points only refreshes the output once per refresh interval, so this should be
+    /// called after the last data point is appended.
+    ///
+    /// # Errors
+    ///
+    /// If the self-refreshing HTML file cannot be written (see [`StreamingFigure::html_file`]).
+    pub fn refresh(&mut self) -> io::Result<()> {
+        self.last_refresh = Some(Instant::now());
+        self.update_traces();
+        match &mut self.output {
+            Output::None => {}
+            Output::Live { shown } => {
+                if *shown {
+                    PreviewServer::live().update(&self.figure);
+                } else {
+                    self.figure.show_live();
+                    *shown = true;
+                }
+            }
+            Output::Server(server) => server.update(&self.figure),
+            Output::Html(path) => {
+                let seconds = self.refresh_interval.as_secs_f64().round().max(1.0);
+                let html = self.figure.to_html_with(&self.html_options).replacen(
+                    "<head>",
+                    &format!(r#"<head><meta http-equiv="refresh" content="{seconds}">"#),
+                    1,
+                );
+                write_atomically(path, &html)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the figure with all data points appended so far.
+    ///
+    /// # Returns
+    ///
+    /// Figure.
+    pub fn figure(&mut self) -> &Figure {
+        self.update_traces();
+        &self.figure
+    }
+
+    /// Convert the streaming figure into a figure with all data points appended so far.
+    ///
+    /// # Returns
+    ///
+    /// Figure.
+    pub fn into_figure(mut self) -> Figure {
+        self.update_traces();
+        self.figure
+    }
+
+    /// Update the data of the traces of the figure with the streamed data points.
+    fn update_traces(&mut self) {
+        if !std::mem::take(&mut self.changed) {
+            return;
+        }
+        for (trace, series) in self.figure.traces.iter_mut().zip(&mut self.series) {
+            series.trim(self.window, self.history);
+            trace.x = series.coordinates(0);
+            trace.y = series.coordinates(1);
+            if trace.z.is_some() {
+                trace.z = Some(series.coordinates(2));
+            }
+        }
+    }
+}
+
+/// Write a file atomically, so that a browser reloading it never reads a partially written file.
+///
+/// The contents are written to a temporary file in the same directory, which is then renamed.
+///
+/// # Arguments
+///
+/// * `path` - Path to the file.
+/// * `contents` - Contents of the file.
+///
+/// # Errors
+///
+/// If the file cannot be written.
+fn write_atomically(path: &Path, contents: &str) -> io::Result<()> {
+    let mut temporary = path.as_os_str().to_owned();
+    temporary.push(".tmp");
+    let temporary = PathBuf::from(temporary);
+    if let Some(directory) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(directory)?;
+    }
+    std::fs::write(&temporary, contents)?;
+    std::fs::rename(&temporary, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&temporary);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::Format;
+    use crate::html::{HtmlOptionsBuilder, PlotlyJs};
+    use crate::quick::{quick_plot_2d, quick_plot_3d};
+    use crate::trace::Trace;
+
+    #[test]
+    fn test_append() {
+        let mut fig = StreamingFigure::new(quick_plot_2d([0.0], [0.0]));
+        fig.append(0, 1.0, 2.0);
+        fig.append(0, 2.0, 4.0);
+        let figure = fig.figure();
+        assert_eq!(figure.traces[0].x, [0.0, 1.0, 2.0]);
+        assert_eq!(figure.traces[0].y, [0.0, 2.0, 4.0]);
+        assert_eq!(figure.traces[0].z, None);
+
+        let mut fig = StreamingFigure::new(quick_plot_3d([0.0], [0.0], [0.0]));
+        fig.append_3d(0, 1.0, 2.0, 3.0);
+        assert_eq!(
+            fig.into_figure().traces[0].z,
+            Some(AxisData::from([0.0, 3.0]))
+        );
+    }
+
+    #[test]
+    fn test_window() {
+        let mut fig = StreamingFigure::new(quick_plot_2d([0.0, 1.0, 2.0], [0.0; 3])).window(2);
+        assert_eq!(fig.figure().traces[0].x, [1.0, 2.0]);
+        for i in 3..6 {
+            fig.append(0, f64::from(i), 0.0);
+        }
+        assert_eq!(fig.figure().traces[0].x, [4.0, 5.0]);
+    }
+
+    #[test]
+    fn test_history() {
+        let trace = Trace::new_2d(Vec::<f64>::new(), Vec::<f64>::new());
+        let mut fig = StreamingFigure::new(Figure::new(vec![trace], Format::default()))
+            .window(2)
+            .history(4);
+        for i in 0..12 {
+            fig.append(0, f64::from(i), 0.0);
+        }
+
+        // Points 0 to 9 dropped out of the window, and the history was halved twice.
+        assert_eq!(fig.figure().traces[0].x, [0.0, 4.0, 8.0, 10.0, 11.0]);
+        fig.append(0, 12.0, 0.0);
+        fig.append(0, 13.0, 0.0);
+        assert_eq!(fig.figure().traces[0].x, [0.0, 4.0, 8.0, 12.0, 13.0]);
+    }
+
+    #[test]
+    fn test_html_file() {
+        let path = std::env::temp_dir().join("plotting_streaming_figure.html");
+        let mut fig = StreamingFigure::new(quick_plot_2d([1.0], [2.0]))
+            .refresh_interval(Duration::from_secs(2))
+            .html_file(&path);
+        fig.append(0, 3.0, 4.0);
+        let html = std::fs::read_to_string(&path).unwrap();
+        assert!(html.contains(r#"<meta http-equiv="refresh" content="2">"#));
+        assert!(html.contains("[1.0,3.0]"));
+        assert!(!path.with_extension("html.tmp").exists());
+    }
+
+    #[test]
+    fn test_html_file_error() {
+        // The parent "directory" of the HTML file is a file.
+        let path = std::env::temp_dir().join("plotting_streaming_figure_error");
+        std::fs::write(&path, "").unwrap();
+        let mut fig =
+            StreamingFigure::new(quick_plot_2d([1.0], [2.0])).html_file(path.join("fig.html"));
+
+        // Appending data points does not panic, and refreshing reports the error.
+        fig.append(0, 3.0, 4.0);
+        assert!(fig.refresh().is_err());
+    }
+
+    #[test]
+    fn test_html_options() {
+        let path = std::env::temp_dir().join("plotting_streaming_figure_options.html");
+        let options = HtmlOptionsBuilder::default()
+            .plotly_js(PlotlyJs::Path(String::from("plotly.min.js")))
+            .build()
+            .unwrap();
+        let mut fig = StreamingFigure::new(quick_plot_2d([1.0], [2.0]))
+            .html_file(&path)
+            .html_options(options);
+        fig.refresh().unwrap();
+        let html = std::fs::read_to_string(&path).unwrap();
+        assert!(html.contains(r#"<meta http-equiv="refresh""#));
+        assert!(html.contains(r#"<script src="plotly.min.js"></script>"#));
+        assert!(!html.contains("https://cdn.plot.ly"));
+    }
+}
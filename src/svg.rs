@@ -0,0 +1,1299 @@
+use crate::annotation::Annotation;
+use crate::axes::{Axes, PlotTrace, project_cube};
+use crate::axis_format::AxisSide;
+use crate::axis_type::AxisType;
+use crate::colors::Color;
+use crate::coordinates::Coordinates;
+use crate::figure::Figure;
+use crate::line_style::LineStyle;
+use crate::plot_type::PlotType;
+use crate::scale::Scale;
+use crate::shape::{Layer, Shape, ShapeKind};
+use file_io::save_string_to_file;
+use std::fmt::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Number of SVG documents rendered so far (used to give the clip paths of each document a unique
+/// id, so that several documents can be inlined into the same HTML page).
+static RENDER_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Default width of a figure (in pixels, as in plotly.js).
+pub(crate) const DEFAULT_WIDTH: usize = 700;
+
+/// Default height of a figure (in pixels, as in plotly.js).
+pub(crate) const DEFAULT_HEIGHT: usize = 450;
+
+/// Margins around the plotting area (left, right, top, and bottom, in pixels, as in plotly.js).
+const MARGINS: (f64, f64, f64, f64) = (80.0, 80.0, 100.0, 80.0);
+
+/// Font size of tick labels, axis labels, and legend entries.
+const FONT_SIZE: f64 = 12.0;
+
+/// Font size of the title.
+const TITLE_FONT_SIZE: f64 = 17.0;
+
+/// Default line width of traces and box outlines.
+const LINE_WIDTH: f64 = 2.0;
+
+/// Default line width of shapes.
+const SHAPE_LINE_WIDTH: f64 = 2.0;
+
+/// Default marker size (diameter in pixels).
+const MARKER_SIZE: f64 = 6.0;
+
+/// Maximum line width and marker size (in pixels), beyond which lines and markers are clipped
+/// anyway (larger sizes overflow when rasterizing the figure).
+const MAX_STROKE_SIZE: f64 = 10_000.0;
+
+/// Default line color of shapes.
+const SHAPE_LINE_COLOR: u32 = 0x444444;
+
+/// Height of a legend entry (in pixels).
+const LEGEND_ENTRY_HEIGHT: f64 = 19.0;
+
+/// Edge of the axes cube of a 3D figure, parameterized by the position along the edge (from `-1`
+/// to `1`).
+type CubeEdge = fn(f64) -> (f64, f64, f64);
+
+/// Estimate the width of a text (in pixels).
+///
+/// # Arguments
+///
+/// * `text` - Text.
+/// * `font_size` - Font size.
+///
+/// # Returns
+///
+/// Estimated width of the text.
+fn text_width(text: &str, font_size: f64) -> f64 {
+    text.chars().count() as f64 * font_size * 0.6
+}
+
+/// Escape a text for use in an SVG (or HTML) document.
+///
+/// # Arguments
+///
+/// * `text` - Text.
+///
+/// # Returns
+///
+/// Escaped text.
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Format a color as SVG paint attributes.
+///
+/// # Arguments
+///
+/// * `attribute` - Paint attribute (`"fill"` or `"stroke"`).
+/// * `color` - Color.
+///
+/// # Returns
+///
+/// SVG attributes.
+fn paint(attribute: &str, color: &Color) -> String {
+    if color.opacity() == 1.0 {
+        format!(r#"{attribute}="{}""#, color.to_hex())
+    } else {
+        format!(
+            r#"{attribute}="{}" {attribute}-opacity="{}""#,
+            color.to_hex(),
+            color.opacity()
+        )
+    }
+}
+
+/// Get the SVG dash array of a line style (as in plotly.js, where dashes scale with the line
+/// width).
+///
+/// # Arguments
+///
+/// * `line_style` - Line style.
+/// * `line_width` - Line width.
+///
+/// # Returns
+///
+/// SVG attributes.
+fn dash_array(line_style: Option<LineStyle>, line_width: f64) -> String {
+    let w = line_width.max(3.0);
+    let pattern = match line_style {
+        None | Some(LineStyle::Solid) => return String::new(),
+        Some(LineStyle::Dot) => vec![w, w],
+        Some(LineStyle::Dash) => vec![3.0 * w, 3.0 * w],
+        Some(LineStyle::LongDash) => vec![5.0 * w, 5.0 * w],
+        Some(LineStyle::DashDot) => vec![3.0 * w, w, w, w],
+        Some(LineStyle::LongDashDot) => vec![5.0 * w, 2.0 * w, w, 2.0 * w],
+    };
+    let pattern: Vec<String> = pattern.iter().map(|length| length.to_string()).collect();
+    format!(r#" stroke-dasharray="{}""#, pattern.join(","))
+}
+
+/// Rectangular region of the canvas (in pixels).
+#[derive(Clone, Copy)]
+struct Rect {
+    /// Left edge.
+    left: f64,
+
+    /// Right edge.
+    right: f64,
+
+    /// Top edge.
+    top: f64,
+
+    /// Bottom edge.
+    bottom: f64,
+}
+
+impl Rect {
+    /// Get the horizontal pixel position of a fraction of the width of the region.
+    ///
+    /// # Arguments
+    ///
+    /// * `fraction` - Fraction of the width (`0.0` at the left edge).
+    ///
+    /// # Returns
+    ///
+    /// Horizontal pixel position.
+    fn x(&self, fraction: f64) -> f64 {
+        self.left + fraction * (self.right - self.left)
+    }
+
+    /// Get the vertical pixel position of a fraction of the height of the region.
+    ///
+    /// # Arguments
+    ///
+    /// * `fraction` - Fraction of the height (`0.0` at the bottom edge).
+    ///
+    /// # Returns
+    ///
+    /// Vertical pixel position.
+    fn y(&self, fraction: f64) -> f64 {
+        self.bottom - fraction * (self.bottom - self.top)
+    }
+}
+
+/// SVG renderer.
+struct SvgRenderer<'a> {
+    /// Axes of the figure.
+    axes: Axes<'a>,
+
+    /// Width of the figure.
+    width: f64,
+
+    /// Height of the figure.
+    height: f64,
+
+    /// Plotting area (including the space reserved for offset secondary y-axes).
+    paper: Rect,
+
+    /// Plotting area spanned by the x-axis.
+    plot: Rect,
+
+    /// SVG document.
+    svg: String,
+}
+
+impl<'a> SvgRenderer<'a> {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `figure` - Figure.
+    ///
+    /// # Returns
+    ///
+    /// SVG renderer.
+    fn new(figure: &'a Figure) -> SvgRenderer<'a> {
+        let axes = Axes::new(figure);
+        // Figures are at least one pixel wide and high, so that they can be rasterized.
+        let width = figure.format.width.unwrap_or(DEFAULT_WIDTH).max(1) as f64;
+        let height = figure.format.height.unwrap_or(DEFAULT_HEIGHT).max(1) as f64;
+        let (left, right, top, bottom) = MARGINS;
+
+        // Reserve space for the legend on the right side of the figure.
+        let legend_width = if axes.show_legend() {
+            axes.traces
+                .iter()
+                .map(|trace| text_width(&trace.name(), FONT_SIZE))
+                .fold(0.0, f64::max)
+                + 50.0
+        } else {
+            0.0
+        };
+        let paper = Rect {
+            left,
+            right: (width - right - legend_width).max(left + 1.0),
+            top,
+            bottom: (height - bottom).max(top + 1.0),
+        };
+        let (start, end) = figure.format.x_domain.unwrap_or((0.0, 1.0));
+        let plot = Rect {
+            left: paper.x(start),
+            right: paper.x(end),
+            ..paper
+        };
+        SvgRenderer {
+            axes,
+            width,
+            height,
+            paper,
+            plot,
+            svg: String::new(),
+        }
+    }
+
+    /// Render the figure.
+    ///
+    /// # Returns
+    ///
+    /// SVG document.
+    fn render(mut self) -> String {
+        let style = &self.axes.style;
+        let font_family = escape(style.font_family);
+        let _ = write!(
+            self.svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="{font_family}">"#,
+            w = self.width,
+            h = self.height,
+        );
+        let _ = write!(
+            self.svg,
+            r##"<rect width="{}" height="{}" fill="#{:06x}"/>"##,
+            self.width, self.height, style.paper_color
+        );
+
+        if self.axes.is_3d() {
+            self.render_3d();
+        } else {
+            self.render_2d();
+        }
+
+        // Title.
+        if let Some(title) = &self.axes.figure.format.title {
+            let color = self.axes.style.text_color;
+            let _ = write!(
+                self.svg,
+                r##"<text x="{}" y="{}" font-size="{TITLE_FONT_SIZE}" fill="#{color:06x}" text-anchor="middle">{}</text>"##,
+                self.width / 2.0,
+                MARGINS.2 / 2.0,
+                escape(title)
+            );
+        }
+
+        self.render_legend();
+        self.svg.push_str("</svg>\n");
+        self.svg
+    }
+
+    /// Render a 2D figure.
+    fn render_2d(&mut self) {
+        let style = &self.axes.style;
+        let plot = self.plot;
+        let (grid_color, zero_line_color) = (style.grid_color, style.zero_line_color);
+        let _ = write!(
+            self.svg,
+            r##"<rect x="{}" y="{}" width="{}" height="{}" fill="#{:06x}"/>"##,
+            plot.left,
+            plot.top,
+            plot.right - plot.left,
+            plot.bottom - plot.top,
+            style.plot_color
+        );
+        let clip_path_id = format!("plot-{}", RENDER_COUNT.fetch_add(1, Ordering::Relaxed));
+        let _ = write!(
+            self.svg,
+            r#"<clipPath id="{clip_path_id}"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath>"#,
+            plot.left,
+            plot.top,
+            plot.right - plot.left,
+            plot.bottom - plot.top,
+        );
+
+        // Grid lines and zero lines.
+        let x_ticks = self
+            .axes
+            .x
+            .ticks(((plot.right - plot.left) / 80.0) as usize);
+        let y_ticks = self.axes.y[0].ticks(((plot.bottom - plot.top) / 50.0) as usize);
+        for tick in &x_ticks {
+            let x = plot.x(tick.fraction);
+            let _ = write!(
+                self.svg,
+                r##"<line x1="{x}" y1="{}" x2="{x}" y2="{}" stroke="#{grid_color:06x}"/>"##,
+                plot.top, plot.bottom
+            );
+        }
+        for tick in &y_ticks {
+            let y = plot.y(tick.fraction);
+            let _ = write!(
+                self.svg,
+                r##"<line x1="{}" y1="{y}" x2="{}" y2="{y}" stroke="#{grid_color:06x}"/>"##,
+                plot.left, plot.right
+            );
+        }
+        if let Some(x) = zero_fraction(&self.axes.x) {
+            let x = plot.x(x);
+            let _ = write!(
+                self.svg,
+                r##"<line x1="{x}" y1="{}" x2="{x}" y2="{}" stroke="#{zero_line_color:06x}"/>"##,
+                plot.top, plot.bottom
+            );
+        }
+        if let Some(y) = zero_fraction(&self.axes.y[0]) {
+            let y = plot.y(y);
+            let _ = write!(
+                self.svg,
+                r##"<line x1="{}" y1="{y}" x2="{}" y2="{y}" stroke="#{zero_line_color:06x}"/>"##,
+                plot.left, plot.right
+            );
+        }
+
+        // Shapes below the traces, traces, and shapes above the traces.
+        let figure = self.axes.figure;
+        for shape in figure
+            .shapes
+            .iter()
+            .filter(|shape| shape.layer == Layer::Below)
+        {
+            self.render_shape(shape);
+        }
+        let _ = write!(self.svg, r#"<g clip-path="url(#{clip_path_id})">"#);
+        for index in 0..self.axes.traces.len() {
+            self.render_trace(index);
+        }
+        self.svg.push_str("</g>");
+        for shape in figure
+            .shapes
+            .iter()
+            .filter(|shape| shape.layer == Layer::Above)
+        {
+            self.render_shape(shape);
+        }
+
+        // Axis lines.
+        let text_color = self.axes.style.text_color;
+        if let Some(axis_line_color) = self.axes.style.axis_line_color {
+            let _ = write!(
+                self.svg,
+                r##"<path d="M{} {}V{}H{}" fill="none" stroke="#{axis_line_color:06x}"/>"##,
+                plot.left, plot.top, plot.bottom, plot.right
+            );
+        }
+
+        // Tick labels.
+        for tick in &x_ticks {
+            let _ = write!(
+                self.svg,
+                r##"<text x="{}" y="{}" font-size="{FONT_SIZE}" fill="#{text_color:06x}" text-anchor="middle">{}</text>"##,
+                plot.x(tick.fraction),
+                plot.bottom + 18.0,
+                escape(&tick.label)
+            );
+        }
+        for tick in &y_ticks {
+            let _ = write!(
+                self.svg,
+                r##"<text x="{}" y="{}" font-size="{FONT_SIZE}" fill="#{text_color:06x}" text-anchor="end">{}</text>"##,
+                plot.left - 6.0,
+                plot.y(tick.fraction) + FONT_SIZE * 0.35,
+                escape(&tick.label)
+            );
+        }
+
+        // Axis labels.
+        let format = &figure.format;
+        if let Some(x_label) = &format.x_label {
+            let _ = write!(
+                self.svg,
+                r##"<text x="{}" y="{}" font-size="{}" fill="#{text_color:06x}" text-anchor="middle">{}</text>"##,
+                (plot.left + plot.right) / 2.0,
+                plot.bottom + 45.0,
+                FONT_SIZE + 2.0,
+                escape(x_label)
+            );
+        }
+        if let Some(y_label) = &format.y_label {
+            self.vertical_label(y_label, plot.left - 55.0, -90.0);
+        }
+
+        // Secondary y-axes.
+        for (index, axis_format) in format.plotted_secondary_y_axes().iter().enumerate() {
+            let scale = &self.axes.y[index + 1];
+            let x = match (axis_format.position, axis_format.side) {
+                (Some(position), _) => self.paper.x(position),
+                (None, AxisSide::Left) => plot.left,
+                (None, AxisSide::Right) => plot.right,
+            };
+            let (anchor, direction) = match axis_format.side {
+                AxisSide::Left => ("end", -1.0),
+                AxisSide::Right => ("start", 1.0),
+            };
+            if axis_format.position.is_some() {
+                let _ = write!(
+                    self.svg,
+                    r##"<line x1="{x}" y1="{}" x2="{x}" y2="{}" stroke="#{text_color:06x}"/>"##,
+                    plot.top, plot.bottom
+                );
+            }
+            let ticks = scale.ticks(((plot.bottom - plot.top) / 50.0) as usize);
+            let mut label_width: f64 = 0.0;
+            for tick in &ticks {
+                label_width = label_width.max(text_width(&tick.label, FONT_SIZE));
+                let _ = write!(
+                    self.svg,
+                    r##"<text x="{}" y="{}" font-size="{FONT_SIZE}" fill="#{text_color:06x}" text-anchor="{anchor}">{}</text>"##,
+                    x + direction * 6.0,
+                    plot.y(tick.fraction) + FONT_SIZE * 0.35,
+                    escape(&tick.label)
+                );
+            }
+            if let Some(label) = &axis_format.label {
+                self.vertical_label(
+                    label,
+                    x + direction * (label_width + 20.0),
+                    direction * 90.0,
+                );
+            }
+        }
+
+        // Annotations.
+        for annotation in &figure.annotations {
+            self.render_annotation(annotation);
+        }
+    }
+
+    /// Render a vertical axis label.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Axis label.
+    /// * `x` - Horizontal position of the label.
+    /// * `rotation` - Rotation of the label (in degrees).
+    fn vertical_label(&mut self, label: &str, x: f64, rotation: f64) {
+        let y = (self.plot.top + self.plot.bottom) / 2.0;
+        let _ = write!(
+            self.svg,
+            r##"<text x="{x}" y="{y}" font-size="{}" fill="#{:06x}" text-anchor="middle" transform="rotate({rotation} {x} {y})">{}</text>"##,
+            FONT_SIZE + 2.0,
+            self.axes.style.text_color,
+            escape(label)
+        );
+    }
+
+    /// Get the pixel position of a point in data coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - x-coordinate.
+    /// * `y` - y-coordinate.
+    /// * `y_axis` - Index of the y-axis (`0` for the primary y-axis).
+    ///
+    /// # Returns
+    ///
+    /// Pixel position.
+    fn point(&self, x: f64, y: f64, y_axis: usize) -> (f64, f64) {
+        (
+            self.plot.x(self.axes.x.fraction(x)),
+            self.plot.y(self.axes.y[y_axis].fraction(y)),
+        )
+    }
+
+    /// Render a trace of a 2D figure.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the trace.
+    fn render_trace(&mut self, index: usize) {
+        let trace = &self.axes.traces[index];
+        let mut svg = String::new();
+
+        // Filled area.
+        if let Some(polygon) = &trace.fill_polygon {
+            let points: Vec<(f64, f64)> = polygon
+                .iter()
+                .map(|(x, y)| self.point(*x, *y, trace.y_axis))
+                .filter(|(x, y)| x.is_finite() && y.is_finite())
+                .collect();
+            let _ = write!(
+                svg,
+                r#"<path d="{}Z" {}/>"#,
+                path_data(&points),
+                paint("fill", &trace.fill_color())
+            );
+        }
+
+        match trace.trace.plot_type {
+            PlotType::Scatter => {
+                let line_width = stroke_size(trace.trace.line_width.unwrap_or(LINE_WIDTH));
+                if trace.has_lines() && line_width > 0.0 {
+                    // Lines are broken at gaps (i.e. missing data points).
+                    let mut d = String::new();
+                    let mut pen_down = false;
+                    for (x, y) in trace.x.iter().zip(&trace.y) {
+                        let (x, y) = self.point(*x, *y, trace.y_axis);
+                        if x.is_finite() && y.is_finite() {
+                            let _ = write!(d, "{}{x:.2} {y:.2}", if pen_down { 'L' } else { 'M' });
+                            pen_down = true;
+                        } else {
+                            pen_down = false;
+                        }
+                    }
+                    let _ = write!(
+                        svg,
+                        r#"<path d="{d}" fill="none" {} stroke-width="{line_width}" stroke-linejoin="round"{}/>"#,
+                        paint("stroke", &trace.line_color()),
+                        dash_array(trace.trace.line_style, line_width)
+                    );
+                }
+                if trace.has_markers() {
+                    let fill = paint("fill", &trace.marker_color());
+                    let radius = marker_radius(trace);
+                    for (x, y) in trace.x.iter().zip(&trace.y) {
+                        let (x, y) = self.point(*x, *y, trace.y_axis);
+                        if x.is_finite() && y.is_finite() {
+                            let _ = write!(
+                                svg,
+                                r#"<circle cx="{x:.2}" cy="{y:.2}" r="{radius}" {fill}/>"#
+                            );
+                        }
+                    }
+                }
+            }
+            PlotType::Bar => {
+                let (offset, width) = trace.bar;
+                let fill = paint("fill", &trace.marker_color());
+                let base = self.axes.y[trace.y_axis].fraction(0.0);
+                let base = self.plot.y(if base.is_finite() { base } else { 0.0 });
+                for (x, y) in trace.x.iter().zip(&trace.y) {
+                    let (left, top) = self.point(x + offset - width / 2.0, *y, trace.y_axis);
+                    let (right, _) = self.point(x + offset + width / 2.0, *y, trace.y_axis);
+                    if [left, right, top].iter().all(|value| value.is_finite()) {
+                        let _ = write!(
+                            svg,
+                            r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" {fill}/>"#,
+                            left.min(right),
+                            top.min(base),
+                            (right - left).abs(),
+                            (base - top).abs()
+                        );
+                    }
+                }
+            }
+            PlotType::Box => {
+                let stroke = paint("stroke", &trace.line_color());
+                let fill = paint("fill", &trace.fill_color());
+                let marker_fill = paint("fill", &trace.marker_color());
+                let half_width = trace.box_width / 2.0;
+                let line_width = stroke_size(trace.trace.line_width.unwrap_or(LINE_WIDTH));
+                for stats in &trace.boxes {
+                    let (left, q1) = self.point(stats.x - half_width, stats.q1, trace.y_axis);
+                    let (right, q3) = self.point(stats.x + half_width, stats.q3, trace.y_axis);
+                    let (center, median) = self.point(stats.x, stats.median, trace.y_axis);
+                    let (_, lower) = self.point(stats.x, stats.lower_whisker, trace.y_axis);
+                    let (_, upper) = self.point(stats.x, stats.upper_whisker, trace.y_axis);
+                    let _ = write!(
+                        svg,
+                        r#"<path d="M{left:.2} {q1:.2}H{right:.2}V{q3:.2}H{left:.2}ZM{left:.2} {median:.2}H{right:.2}M{center:.2} {q1:.2}V{lower:.2}M{center:.2} {q3:.2}V{upper:.2}" {fill} {stroke} stroke-width="{line_width}"/>"#,
+                    );
+                    for outlier in &stats.outliers {
+                        let (_, y) = self.point(stats.x, *outlier, trace.y_axis);
+                        let _ = write!(
+                            svg,
+                            r#"<circle cx="{center:.2}" cy="{y:.2}" r="{}" {marker_fill}/>"#,
+                            marker_radius(trace)
+                        );
+                    }
+                }
+            }
+        }
+        self.svg.push_str(&svg);
+    }
+
+    /// Render a shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - Shape.
+    fn render_shape(&mut self, shape: &Shape) {
+        let plot = self.plot;
+        let x = |x: f64| match shape.x_coordinates {
+            Coordinates::Data => plot.x(self.axes.x.fraction(x)),
+            Coordinates::Paper => plot.x(x),
+        };
+        let y = |y: f64| match shape.y_coordinates {
+            Coordinates::Data => plot.y(self.axes.y[0].fraction(y)),
+            Coordinates::Paper => plot.y(y),
+        };
+        let (x0, y0, x1, y1) = (x(shape.x0), y(shape.y0), x(shape.x1), y(shape.y1));
+        let line_width = stroke_size(shape.line_width.unwrap_or(SHAPE_LINE_WIDTH));
+        let stroke = if line_width > 0.0 {
+            format!(
+                r#"{} stroke-width="{line_width}"{}"#,
+                paint(
+                    "stroke",
+                    &shape
+                        .line_color
+                        .clone()
+                        .unwrap_or_else(|| Color::hex_literal(SHAPE_LINE_COLOR))
+                ),
+                dash_array(shape.line_style, line_width)
+            )
+        } else {
+            String::from(r#"stroke="none""#)
+        };
+        let fill = match &shape.fill_color {
+            Some(fill_color) if shape.kind != ShapeKind::Line => paint("fill", fill_color),
+            _ => String::from(r#"fill="none""#),
+        };
+        let element = match shape.kind {
+            ShapeKind::Line => {
+                format!(r#"<line x1="{x0:.2}" y1="{y0:.2}" x2="{x1:.2}" y2="{y1:.2}" {stroke}/>"#)
+            }
+            ShapeKind::Rect => format!(
+                r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" {fill} {stroke}/>"#,
+                x0.min(x1),
+                y0.min(y1),
+                (x1 - x0).abs(),
+                (y1 - y0).abs()
+            ),
+            ShapeKind::Circle => format!(
+                r#"<ellipse cx="{:.2}" cy="{:.2}" rx="{:.2}" ry="{:.2}" {fill} {stroke}/>"#,
+                (x0 + x1) / 2.0,
+                (y0 + y1) / 2.0,
+                (x1 - x0).abs() / 2.0,
+                (y1 - y0).abs() / 2.0
+            ),
+            ShapeKind::Path => format!(
+                r#"<path d="{}" {fill} {stroke}/>"#,
+                transform_path(shape.path.as_deref().unwrap_or_default(), |px, py| {
+                    (x(px), y(py))
+                })
+            ),
+        };
+        if [x0, y0, x1, y1].iter().all(|value| value.is_finite()) {
+            self.svg.push_str(&element);
+        }
+    }
+
+    /// Render an annotation.
+    ///
+    /// # Arguments
+    ///
+    /// * `annotation` - Annotation.
+    fn render_annotation(&mut self, annotation: &Annotation) {
+        let (x, y) = match annotation.coordinates {
+            Coordinates::Data => self.point(annotation.x, annotation.y, 0),
+            Coordinates::Paper => (self.plot.x(annotation.x), self.plot.y(annotation.y)),
+        };
+        if !x.is_finite() || !y.is_finite() {
+            return;
+        }
+        let (dx, dy) = annotation.arrow.unwrap_or((0.0, 0.0));
+        let (text_x, text_y) = (x + dx, y + dy);
+        let font_size = annotation.font_size.map_or(FONT_SIZE, |size| size as f64);
+        let font_color = annotation
+            .font_color
+            .clone()
+            .unwrap_or_else(|| Color::hex_literal(self.axes.style.text_color));
+
+        // Arrow.
+        if annotation.arrow.is_some() {
+            let arrow_color = annotation
+                .arrow_color
+                .clone()
+                .unwrap_or_else(|| Color::hex_literal(SHAPE_LINE_COLOR));
+            let length = (dx * dx + dy * dy).sqrt().max(1.0);
+            let (ux, uy) = (-dx / length, -dy / length);
+            let (head_x, head_y) = (x - ux * 8.0, y - uy * 8.0);
+            let _ = write!(
+                self.svg,
+                r#"<line x1="{text_x:.2}" y1="{text_y:.2}" x2="{head_x:.2}" y2="{head_y:.2}" {} stroke-width="1"/><path d="M{x:.2} {y:.2}L{:.2} {:.2}L{:.2} {:.2}Z" {}/>"#,
+                paint("stroke", &arrow_color),
+                head_x - uy * 3.5,
+                head_y + ux * 3.5,
+                head_x + uy * 3.5,
+                head_y - ux * 3.5,
+                paint("fill", &arrow_color),
+            );
+        }
+
+        // Text box.
+        let width = text_width(&annotation.text, font_size) + 6.0;
+        let height = font_size + 6.0;
+        if annotation.background_color.is_some() || annotation.border_color.is_some() {
+            let fill = annotation
+                .background_color
+                .as_ref()
+                .map_or(String::from(r#"fill="none""#), |color| paint("fill", color));
+            let stroke = annotation
+                .border_color
+                .as_ref()
+                .map_or(String::from(r#"stroke="none""#), |color| {
+                    paint("stroke", color)
+                });
+            let _ = write!(
+                self.svg,
+                r#"<rect x="{:.2}" y="{:.2}" width="{width:.2}" height="{height:.2}" {fill} {stroke}/>"#,
+                text_x - width / 2.0,
+                text_y - height / 2.0
+            );
+        }
+        let font_family = annotation
+            .font_family
+            .as_ref()
+            .map(|family| format!(r#" font-family="{}""#, escape(family)))
+            .unwrap_or_default();
+        let _ = write!(
+            self.svg,
+            r#"<text x="{text_x:.2}" y="{:.2}" font-size="{font_size}"{font_family} {} text-anchor="middle">{}</text>"#,
+            text_y + font_size * 0.35,
+            paint("fill", &font_color),
+            escape(&annotation.text)
+        );
+    }
+
+    /// Render a 3D figure (as seen from the default plotly.js camera position).
+    fn render_3d(&mut self) {
+        let plot = self.paper;
+        let size = (plot.right - plot.left).min(plot.bottom - plot.top);
+        let center = (
+            (plot.left + plot.right) / 2.0,
+            (plot.top + plot.bottom) / 2.0,
+        );
+        let screen =
+            |(u, v): (f64, f64)| (center.0 + (u - 0.5) * size, center.1 - (v - 0.5) * size);
+        let text_color = self.axes.style.text_color;
+        let grid = Color::hex_literal(text_color).alpha(0.3);
+
+        // Axes cube.
+        let corners = [-1.0, 1.0];
+        let mut d = String::new();
+        for a in corners {
+            for b in corners {
+                for (start, end) in [
+                    ((-1.0, a, b), (1.0, a, b)),
+                    ((a, -1.0, b), (a, 1.0, b)),
+                    ((a, b, -1.0), (a, b, 1.0)),
+                ] {
+                    let (x0, y0) = screen(project_cube(start.0, start.1, start.2));
+                    let (x1, y1) = screen(project_cube(end.0, end.1, end.2));
+                    let _ = write!(d, "M{x0:.2} {y0:.2}L{x1:.2} {y1:.2}");
+                }
+            }
+        }
+        let _ = write!(
+            self.svg,
+            r#"<path d="{d}" fill="none" {} stroke-width="1"/>"#,
+            paint("stroke", &grid)
+        );
+
+        // Tick labels and axis labels (along the front edges of the cube).
+        let format = &self.axes.figure.format;
+        let z_scale = self.axes.z.as_ref().unwrap_or(&self.axes.x);
+        let axes = [
+            (
+                &self.axes.x,
+                (|t| (t, 1.0, -1.0)) as CubeEdge,
+                &format.x_label,
+                (8.0, 14.0),
+                "start",
+            ),
+            (
+                &self.axes.y[0],
+                (|t| (1.0, t, -1.0)) as CubeEdge,
+                &format.y_label,
+                (-8.0, 14.0),
+                "end",
+            ),
+            (
+                z_scale,
+                (|t| (1.0, -1.0, t)) as CubeEdge,
+                &format.z_label,
+                (-10.0, 4.0),
+                "end",
+            ),
+        ];
+        for (scale, edge, label, (dx, dy), anchor) in axes {
+            for tick in scale.ticks(6) {
+                let (x, y, z) = edge(2.0 * tick.fraction - 1.0);
+                let (sx, sy) = screen(project_cube(x, y, z));
+                let _ = write!(
+                    self.svg,
+                    r##"<text x="{:.2}" y="{:.2}" font-size="{FONT_SIZE}" fill="#{text_color:06x}" text-anchor="{anchor}">{}</text>"##,
+                    sx + dx,
+                    sy + dy,
+                    escape(&tick.label)
+                );
+            }
+            if let Some(label) = label {
+                let (x, y, z) = edge(0.0);
+                let (sx, sy) = screen(project_cube(x, y, z));
+                let _ = write!(
+                    self.svg,
+                    r##"<text x="{:.2}" y="{:.2}" font-size="{}" fill="#{text_color:06x}" text-anchor="{anchor}">{}</text>"##,
+                    sx + 4.0 * dx,
+                    sy + 2.5 * dy,
+                    FONT_SIZE + 2.0,
+                    escape(label)
+                );
+            }
+        }
+
+        // Traces.
+        for trace in &self.axes.traces {
+            let z = trace.z.as_deref().unwrap_or_default();
+            let points: Vec<(f64, f64)> = trace
+                .x
+                .iter()
+                .zip(&trace.y)
+                .zip(z)
+                .map(|((x, y), z)| screen(self.axes.project(*x, *y, *z)))
+                .collect();
+            let line_width = trace.trace.line_width.unwrap_or(LINE_WIDTH);
+            if trace.has_lines() && line_width > 0.0 {
+                let mut d = String::new();
+                let mut pen_down = false;
+                for (x, y) in &points {
+                    if x.is_finite() && y.is_finite() {
+                        let _ = write!(d, "{}{x:.2} {y:.2}", if pen_down { 'L' } else { 'M' });
+                        pen_down = true;
+                    } else {
+                        pen_down = false;
+                    }
+                }
+                let _ = write!(
+                    self.svg,
+                    r#"<path d="{d}" fill="none" {} stroke-width="{line_width}" stroke-linejoin="round"{}/>"#,
+                    paint("stroke", &trace.line_color()),
+                    dash_array(trace.trace.line_style, line_width)
+                );
+            }
+            if trace.has_markers() {
+                let fill = paint("fill", &trace.marker_color());
+                let radius = marker_radius(trace);
+                for (x, y) in points
+                    .iter()
+                    .filter(|(x, y)| x.is_finite() && y.is_finite())
+                {
+                    let _ = write!(
+                        self.svg,
+                        r#"<circle cx="{x:.2}" cy="{y:.2}" r="{radius}" {fill}/>"#
+                    );
+                }
+            }
+        }
+    }
+
+    /// Render the legend.
+    fn render_legend(&mut self) {
+        if !self.axes.show_legend() {
+            return;
+        }
+        let left = self.paper.right + 20.0;
+        let text_color = self.axes.style.text_color;
+        let mut entries = String::new();
+        for (index, trace) in self.axes.traces.iter().enumerate() {
+            let y = self.paper.top + index as f64 * LEGEND_ENTRY_HEIGHT;
+            match trace.trace.plot_type {
+                PlotType::Scatter => {
+                    if trace.has_lines() {
+                        let _ = write!(
+                            entries,
+                            r#"<line x1="{left}" y1="{y}" x2="{}" y2="{y}" {} stroke-width="2"{}/>"#,
+                            left + 30.0,
+                            paint("stroke", &trace.line_color()),
+                            dash_array(trace.trace.line_style, 2.0)
+                        );
+                    }
+                    if trace.has_markers() {
+                        let _ = write!(
+                            entries,
+                            r#"<circle cx="{}" cy="{y}" r="3" {}/>"#,
+                            left + 15.0,
+                            paint("fill", &trace.marker_color())
+                        );
+                    }
+                }
+                PlotType::Bar | PlotType::Box => {
+                    let fill = if trace.trace.plot_type == PlotType::Bar {
+                        trace.marker_color()
+                    } else {
+                        trace.fill_color()
+                    };
+                    let _ = write!(
+                        entries,
+                        r#"<rect x="{}" y="{}" width="20" height="12" {} {}/>"#,
+                        left + 5.0,
+                        y - 6.0,
+                        paint("fill", &fill),
+                        paint("stroke", &trace.line_color())
+                    );
+                }
+            }
+            let _ = write!(
+                entries,
+                r##"<text x="{}" y="{}" font-size="{FONT_SIZE}" fill="#{text_color:06x}">{}</text>"##,
+                left + 40.0,
+                y + FONT_SIZE * 0.35,
+                escape(&trace.name())
+            );
+        }
+        self.svg.push_str(&entries);
+    }
+}
+
+/// Get the radius of the markers of a trace.
+///
+/// # Arguments
+///
+/// * `trace` - Trace.
+///
+/// # Returns
+///
+/// Marker radius (in pixels).
+fn marker_radius(trace: &PlotTrace) -> f64 {
+    stroke_size(
+        trace
+            .trace
+            .marker_size
+            .map_or(MARKER_SIZE, |size| size as f64),
+    ) / 2.0
+}
+
+/// Clamp a line width or marker size to the sizes that can be rendered.
+///
+/// # Arguments
+///
+/// * `size` - Line width or marker size (in pixels).
+///
+/// # Returns
+///
+/// Size between `0` and [`MAX_STROKE_SIZE`] (`0` if the size is not a number).
+fn stroke_size(size: f64) -> f64 {
+    if size.is_nan() {
+        0.0
+    } else {
+        size.clamp(0.0, MAX_STROKE_SIZE)
+    }
+}
+
+/// Get the position of zero along a linear axis.
+///
+/// # Arguments
+///
+/// * `scale` - Scale.
+///
+/// # Returns
+///
+/// Position of zero as a fraction of the axis length (`None` if the axis is not linear or zero is
+/// outside the axis range).
+fn zero_fraction(scale: &Scale) -> Option<f64> {
+    let fraction = scale.fraction(0.0);
+    (scale.axis_type == AxisType::Linear && (0.0..=1.0).contains(&fraction)).then_some(fraction)
+}
+
+/// Get the SVG path data of a polygon or polyline.
+///
+/// # Arguments
+///
+/// * `points` - Points (in pixels).
+///
+/// # Returns
+///
+/// SVG path data.
+fn path_data(points: &[(f64, f64)]) -> String {
+    let mut d = String::new();
+    for (index, (x, y)) in points.iter().enumerate() {
+        let _ = write!(d, "{}{x:.2} {y:.2}", if index == 0 { 'M' } else { 'L' });
+    }
+    d
+}
+
+/// Transform the coordinates of an SVG path.
+///
+/// Paths of shapes are specified in data coordinates, so each point of the path is transformed
+/// individually (relative commands are converted to absolute commands, and elliptical arcs are
+/// replaced by straight lines).
+///
+/// # Arguments
+///
+/// * `path` - SVG path data.
+/// * `transform` - Transformation of a point.
+///
+/// # Returns
+///
+/// Transformed SVG path data.
+fn transform_path(path: &str, transform: impl Fn(f64, f64) -> (f64, f64)) -> String {
+    // Tokenize the path into commands and numbers.
+    let mut tokens: Vec<Result<char, f64>> = Vec::new();
+    let mut number = String::new();
+    let flush = |number: &mut String, tokens: &mut Vec<Result<char, f64>>| {
+        if let Ok(value) = number.parse() {
+            tokens.push(Err(value));
+        }
+        number.clear();
+    };
+    for c in path.chars() {
+        if c.is_ascii_alphabetic() && c != 'e' && c != 'E' {
+            flush(&mut number, &mut tokens);
+            tokens.push(Ok(c));
+        } else if c == '-' && !number.is_empty() && !number.ends_with(['e', 'E']) {
+            flush(&mut number, &mut tokens);
+            number.push(c);
+        } else if c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E') {
+            number.push(c);
+        } else {
+            flush(&mut number, &mut tokens);
+        }
+    }
+    flush(&mut number, &mut tokens);
+
+    // Transform the commands.
+    let mut d = String::new();
+    let (mut current, mut start) = ((0.0, 0.0), (0.0, 0.0));
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        let Ok(command) = token else { continue };
+        let relative = command.is_ascii_lowercase();
+        let arguments: Vec<f64> = std::iter::from_fn(|| match tokens.peek() {
+            Some(Err(value)) => {
+                let value = *value;
+                tokens.next();
+                Some(value)
+            }
+            _ => None,
+        })
+        .collect();
+        let origin = if relative { current } else { (0.0, 0.0) };
+        let point = |x: f64, y: f64| (origin.0 + x, origin.1 + y);
+        let emit = |d: &mut String, command: char, points: &[(f64, f64)]| {
+            d.push(command);
+            for (x, y) in points {
+                let (x, y) = transform(*x, *y);
+                let _ = write!(d, "{x:.2} {y:.2} ");
+            }
+        };
+        match command.to_ascii_uppercase() {
+            'Z' => {
+                d.push('Z');
+                current = start;
+            }
+            'M' | 'L' | 'T' => {
+                for (index, pair) in arguments.chunks_exact(2).enumerate() {
+                    current = point(pair[0], pair[1]);
+                    let command = match command.to_ascii_uppercase() {
+                        'M' if index == 0 => {
+                            start = current;
+                            'M'
+                        }
+                        'T' => 'T',
+                        _ => 'L',
+                    };
+                    emit(&mut d, command, &[current]);
+                }
+            }
+            'H' | 'V' => {
+                for value in arguments {
+                    current = if command.eq_ignore_ascii_case(&'H') {
+                        (if relative { current.0 + value } else { value }, current.1)
+                    } else {
+                        (current.0, if relative { current.1 + value } else { value })
+                    };
+                    emit(&mut d, 'L', &[current]);
+                }
+            }
+            'C' | 'S' | 'Q' => {
+                let count = if command.eq_ignore_ascii_case(&'C') {
+                    3
+                } else {
+                    2
+                };
+                for group in arguments.chunks_exact(2 * count) {
+                    let points: Vec<(f64, f64)> = group
+                        .chunks_exact(2)
+                        .map(|pair| point(pair[0], pair[1]))
+                        .collect();
+                    emit(&mut d, command.to_ascii_uppercase(), &points);
+                    current = points[points.len() - 1];
+                }
+            }
+            'A' => {
+                for group in arguments.chunks_exact(7) {
+                    current = point(group[5], group[6]);
+                    emit(&mut d, 'L', &[current]);
+                }
+            }
+            _ => {}
+        }
+    }
+    d.trim_end().to_string()
+}
+
+impl Figure {
+    /// Render the figure as an SVG image.
+    ///
+    /// The figure is rendered natively (without plotly.js), mimicking the default plotly.js
+    /// styling. 3D figures are drawn as seen from the default plotly.js camera position.
+    ///
+    /// # Returns
+    ///
+    /// SVG document.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::quick_plot_2d;
+    ///
+    /// let svg = quick_plot_2d([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]).to_svg();
+    /// assert!(svg.starts_with("<svg"));
+    /// ```
+    pub fn to_svg(&self) -> String {
+        SvgRenderer::new(self).render()
+    }
+
+    /// Save the figure to an SVG file (see [`Figure::to_svg`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the SVG file.
+    ///
+    /// # Panics
+    ///
+    /// If some error is encountered while creating the file or writing to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{quick_plot_2d, Figure};
+    ///
+    /// let fig: Figure = quick_plot_2d([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]);
+    ///
+    /// // Save the figure to an SVG file.
+    /// fig.save_svg("folder/file.svg");
+    /// ```
+    pub fn save_svg<P: AsRef<Path>>(&self, path: P) {
+        save_string_to_file(&self.to_svg(), path.as_ref());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::FormatBuilder;
+    use crate::mode::Mode;
+    use crate::theme::Theme;
+    use crate::trace::Trace;
+
+    #[test]
+    fn test_to_svg() {
+        let format = FormatBuilder::default()
+            .title("A < B")
+            .x_label("x")
+            .theme(Theme::PlotlyDark)
+            .build()
+            .unwrap();
+        let fig = Figure::new(
+            vec![
+                Trace::new_2d([1.0, 2.0, f64::NAN, 4.0], [1.0, 2.0, 3.0, 4.0]).name("Line"),
+                Trace::new_2d([1.0, 2.0], [3.0, 1.0]).plot_type(PlotType::Bar),
+            ],
+            format,
+        )
+        .add_shape(Shape::hline(2.0));
+        let svg = fig.to_svg();
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="700""#));
+        assert!(svg.ends_with("</svg>\n"));
+        assert!(svg.contains(r##"fill="#111111""##));
+        assert!(svg.contains(">A &lt; B</text>"));
+        assert!(svg.contains(">Line</text>"));
+        assert!(svg.contains(">trace 1</text>"));
+
+        // The line is broken at the gap.
+        let line = svg
+            .split("<path d=\"")
+            .find(|path| path.contains("stroke-linejoin"))
+            .unwrap();
+        assert_eq!(line.matches('M').count(), 2);
+    }
+
+    #[test]
+    fn test_extreme_ranges() {
+        for (x, y) in [
+            ([-1e308, 1e308], [0.0, 1.0]),
+            ([0.0, 1e-300], [1e15, 1e15 + 1.0]),
+        ] {
+            let svg = Figure::new(vec![Trace::new_2d(x, y)], Default::default()).to_svg();
+            assert!(!svg.contains("NaN") && !svg.contains("inf"));
+        }
+    }
+
+    #[test]
+    fn test_sizes() {
+        // Zero sizes are rendered as one pixel.
+        let format = FormatBuilder::default().width(0).height(0).build().unwrap();
+        let svg = Figure::new(vec![Trace::new_2d([1.0], [2.0])], format).to_svg();
+        assert!(svg.contains(r#"width="1" height="1""#));
+
+        // Line widths and marker sizes are clamped.
+        let fig = Figure::new(
+            vec![
+                Trace::new_2d([1.0, 2.0], [1.0, 2.0]).line_width(1e30),
+                Trace::new_2d([1.0, 2.0], [1.0, 2.0])
+                    .mode(Mode::Markers)
+                    .marker_size(usize::MAX),
+            ],
+            Default::default(),
+        )
+        .add_shape(Shape::hline(1.5).line_width(f64::NAN));
+        let svg = fig.to_svg();
+        assert!(svg.contains(r#"stroke-width="10000""#));
+        assert!(svg.contains(r#"r="5000""#));
+        assert!(!svg.contains("NaN"));
+    }
+
+    #[test]
+    fn test_unique_clip_path_ids() {
+        let fig = Figure::new(
+            vec![Trace::new_2d([1.0, 2.0], [3.0, 4.0])],
+            Default::default(),
+        );
+        let clip_path_id = |svg: &str| {
+            let id = svg.split(r#"<clipPath id=""#).nth(1).unwrap();
+            let id = String::from(&id[..id.find('"').unwrap()]);
+            assert!(svg.contains(&format!(r#"clip-path="url(#{id})""#)));
+            id
+        };
+        assert_ne!(clip_path_id(&fig.to_svg()), clip_path_id(&fig.to_svg()));
+    }
+
+    #[test]
+    fn test_to_svg_3d() {
+        let fig = Figure::new(
+            vec![Trace::new_3d([0.0, 1.0], [0.0, 1.0], [0.0, 1.0])],
+            FormatBuilder::default().z_label("z").build().unwrap(),
+        );
+        let svg = fig.to_svg();
+        assert!(svg.contains(">z</text>"));
+        assert_eq!(svg.matches("<circle").count(), 2);
+    }
+
+    #[test]
+    fn test_transform_path() {
+        let path = transform_path("M 0,0 l 1 1 H 3 v -1 Z", |x, y| (10.0 * x, -y));
+        assert_eq!(path, "M0.00 -0.00 L10.00 -1.00 L30.00 -1.00 L30.00 -0.00 Z");
+    }
+
+    #[test]
+    fn test_dash_array() {
+        assert_eq!(dash_array(Some(LineStyle::Solid), 2.0), "");
+        assert_eq!(
+            dash_array(Some(LineStyle::DashDot), 4.0),
+            r#" stroke-dasharray="12,4,4,4""#
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use crate::colors::Color;
+use crate::figure::Figure;
+use crate::format::Format;
+use crate::menu::MenuButton;
+use crate::slider::Slider;
+use crate::trace::Trace;
+
+impl Figure {
+    /// Create a figure exploring how traces depend on a parameter, using a slider that steps
+    /// through the parameter values.
+    ///
+    /// The traces are computed for each parameter value up front, and moving the slider shows the
+    /// traces of the selected parameter value (and hides all others). The selected parameter value
+    /// is shown in the title of the figure (rounded to at most 15 decimals, so that e.g.
+    /// `0.30000000000000004` is shown as `0.3`). Initially, the traces of the first parameter value
+    /// are shown (which is also what natively rendered output, e.g. [`Figure::to_svg`], shows).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the parameter (shown next to the slider and in the title).
+    /// * `parameters` - Parameter values.
+    /// * `traces` - Function computing the traces for a parameter value.
+    /// * `format` - Formatting.
+    ///
+    /// # Returns
+    ///
+    /// Figure with a slider stepping through the parameter values.
+    ///
+    /// # Note
+    ///
+    /// Traces without explicit line or marker colors are colored by their position in the set of
+    /// traces computed for a parameter value, so that corresponding traces have the same color
+    /// for every parameter value. The axes are rescaled to the shown traces when moving the slider,
+    /// unless their ranges are set in the format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{Figure, FormatBuilder, Trace};
+    ///
+    /// let t: Vec<f64> = (0..=200).map(|i| i as f64 * 0.05).collect();
+    /// let format = FormatBuilder::default()
+    ///     .title("Damped oscillator")
+    ///     .x_label("t")
+    ///     .build()
+    ///     .unwrap();
+    /// let fig = Figure::parameter_sweep(
+    ///     "ζ",
+    ///     [0.1, 0.2, 0.5, 1.0],
+    ///     |zeta| {
+    ///         vec![
+    ///             Trace::new_2d(&t[..], t.iter().map(|t| (-zeta * t).exp() * t.cos()))
+    ///                 .name("x(t)"),
+    ///         ]
+    ///     },
+    ///     format,
+    /// );
+    /// ```
+    pub fn parameter_sweep(
+        name: impl Into<String>,
+        parameters: impl IntoIterator<Item = f64>,
+        traces: impl Fn(f64) -> Vec<Trace>,
+        mut format: Format,
+    ) -> Figure {
+        let name = name.into();
+        let colorway = format.theme.unwrap_or_default().style().colorway;
+        let base_title = format.title.clone();
+        let title = |parameter: f64| match &base_title {
+            Some(title) => format!("{title} ({name} = {})", label(parameter)),
+            None => format!("{name} = {}", label(parameter)),
+        };
+
+        // Compute the traces for each parameter value.
+        let mut all_traces: Vec<Trace> = Vec::new();
+        let mut steps: Vec<(f64, Vec<usize>)> = Vec::new();
+        for parameter in parameters {
+            let first = all_traces.len();
+            for (index, mut trace) in traces(parameter).into_iter().enumerate() {
+                let color = Color::hex_literal(colorway[index % colorway.len()]);
+                trace.line_color = trace.line_color.or_else(|| Some(color.clone()));
+                trace.marker_color = trace.marker_color.or(Some(color));
+                if !steps.is_empty() {
+                    trace.visible = Some(false);
+                }
+                all_traces.push(trace);
+            }
+            steps.push((parameter, (first..all_traces.len()).collect()));
+        }
+
+        // Slider showing the traces of the selected parameter value.
+        let mut slider = Slider::new().prefix(format!("{name} = "));
+        for (parameter, indices) in &steps {
+            slider = slider.add_step(
+                MenuButton::new(label(*parameter))
+                    .show_only(indices.iter().copied())
+                    .title(title(*parameter)),
+            );
+        }
+        let Some((first, _)) = steps.first() else {
+            return Figure::new(all_traces, format);
+        };
+        format.title = Some(title(*first));
+        Figure::new(all_traces, format).add_slider(slider)
+    }
+}
+
+/// Format a parameter value for display.
+///
+/// # Arguments
+///
+/// * `parameter` - Parameter value.
+///
+/// # Returns
+///
+/// Shortest decimal representation of the parameter value (with at most 15 decimals) that is
+/// equal to it up to a relative error of `1e-12` (e.g. `"0.3"` for `0.30000000000000004`).
+fn label(parameter: f64) -> String {
+    (0..=15)
+        .map(|decimals| format!("{parameter:.decimals$}"))
+        .find(|label| {
+            let value: f64 = label.parse().unwrap_or(f64::NAN);
+            (value - parameter).abs() <= 1e-12 * parameter.abs()
+        })
+        .unwrap_or_else(|| parameter.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn test_parameter_sweep() {
+        let figure = Figure::parameter_sweep(
+            "k",
+            [1.0, 2.5],
+            |k| {
+                vec![
+                    Trace::new_2d([0.0, 1.0], [0.0, k]),
+                    Trace::new_2d([0.0, 1.0], [k, 0.0]),
+                ]
+            },
+            Format::default(),
+        );
+        assert_eq!(figure.traces.len(), 4);
+        assert_eq!(figure.traces[0].visible, None);
+        assert_eq!(figure.traces[2].visible, Some(false));
+        assert_eq!(figure.traces[0].line_color, figure.traces[2].line_color);
+        assert_ne!(figure.traces[0].line_color, figure.traces[1].line_color);
+        assert_eq!(figure.format.title.as_deref(), Some("k = 1"));
+
+        let json: Value = serde_json::from_str(&figure.to_plotly_json()).unwrap();
+        let slider = &json["layout"]["sliders"][0];
+        assert_eq!(slider["currentvalue"]["prefix"], "k = ");
+        assert_eq!(slider["steps"][1]["label"], "2.5");
+        assert_eq!(
+            slider["steps"][1]["args"],
+            serde_json::json!([
+                {"visible": [false, false, true, true]},
+                {"title.text": "k = 2.5"}
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parameter_sweep_with_menu() {
+        use crate::menu::Menu;
+
+        let figure = Figure::parameter_sweep(
+            "k",
+            [1.0, 2.0, 3.0],
+            |k| {
+                vec![
+                    Trace::new_2d([0.0, 1.0], [0.0, k]).name("a"),
+                    Trace::new_2d([0.0, 1.0], [k, 0.0]).name("b"),
+                ]
+            },
+            Format::default(),
+        )
+        .add_menu(Menu::new().add_button(MenuButton::new("Hide a").hide(["a"])));
+        let json: Value = serde_json::from_str(&figure.to_plotly_json()).unwrap();
+        assert_eq!(
+            json["layout"]["updatemenus"][0]["buttons"][0]["args"],
+            serde_json::json!([{"visible": [false, true, false, false, false, false]}])
+        );
+    }
+
+    #[test]
+    fn test_label() {
+        assert_eq!(label(0.1 + 0.2), "0.3");
+        assert_eq!(label(3.0 * 0.1), "0.3");
+        assert_eq!(label(2.5), "2.5");
+        assert_eq!(label(-40.0), "-40");
+        assert_eq!(label(0.0), "0");
+        assert_eq!(label(1e-3), "0.001");
+        assert_eq!(label(1.23456789e-20), "0.0000000000000000000123456789");
+        assert_eq!(label(f64::INFINITY), "inf");
+        assert_eq!(label(f64::NAN), "NaN");
+    }
+
+    #[test]
+    fn test_parameter_sweep_without_parameters() {
+        let figure = Figure::parameter_sweep("k", [], |_| Vec::new(), Format::default());
+        assert!(figure.traces.is_empty());
+        assert!(figure.sliders.is_empty());
+    }
+}
@@ -0,0 +1,795 @@
+use crate::axes::{Axes, PlotTrace, project_cube};
+use crate::figure::Figure;
+use crate::plot_type::PlotType;
+use std::fmt::Write;
+
+/// Default width of a figure rendered to a terminal (in characters), if the width of the terminal
+/// cannot be determined.
+const DEFAULT_COLUMNS: usize = 80;
+
+/// Minimum width of a figure rendered to a terminal (in characters).
+const MIN_COLUMNS: usize = 20;
+
+/// Minimum height of a figure rendered to a terminal (in lines).
+const MIN_LINES: usize = 8;
+
+/// Color of the axes cube of 3D figures.
+const CUBE_COLOR: (u8, u8, u8) = (0x88, 0x88, 0x88);
+
+/// Bits of the dots of a braille character, indexed by row and column within the character.
+const BRAILLE_DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// Canvas of braille characters, each of which is a grid of 2 × 4 dots.
+struct Canvas {
+    /// Width (in characters).
+    columns: usize,
+
+    /// Height (in characters).
+    rows: usize,
+
+    /// Dots set in each character (as braille bits).
+    dots: Vec<u8>,
+
+    /// Color of each character (the color of the last dot set in it).
+    colors: Vec<Option<(u8, u8, u8)>>,
+}
+
+impl Canvas {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - Width (in characters).
+    /// * `rows` - Height (in characters).
+    ///
+    /// # Returns
+    ///
+    /// Empty canvas.
+    fn new(columns: usize, rows: usize) -> Canvas {
+        Canvas {
+            columns,
+            rows,
+            dots: vec![0; columns * rows],
+            colors: vec![None; columns * rows],
+        }
+    }
+
+    /// Width of the canvas (in dots).
+    ///
+    /// # Returns
+    ///
+    /// Width of the canvas.
+    fn width(&self) -> f64 {
+        (2 * self.columns) as f64
+    }
+
+    /// Height of the canvas (in dots).
+    ///
+    /// # Returns
+    ///
+    /// Height of the canvas.
+    fn height(&self) -> f64 {
+        (4 * self.rows) as f64
+    }
+
+    /// Convert a position as fractions of the canvas size to dot coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Horizontal position (`0.0` is the left edge).
+    /// * `y` - Vertical position (`0.0` is the bottom edge).
+    ///
+    /// # Returns
+    ///
+    /// Dot coordinates (may lie outside the canvas).
+    fn dot(&self, x: f64, y: f64) -> (f64, f64) {
+        (x * (self.width() - 1.0), (1.0 - y) * (self.height() - 1.0))
+    }
+
+    /// Set a dot (dots outside the canvas are ignored).
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Horizontal dot coordinate.
+    /// * `y` - Vertical dot coordinate.
+    /// * `color` - Color.
+    fn set(&mut self, x: f64, y: f64, color: (u8, u8, u8)) {
+        let (x, y) = (x.round(), y.round());
+        if !(0.0..self.width()).contains(&x) || !(0.0..self.height()).contains(&y) {
+            return;
+        }
+        let (x, y) = (x as usize, y as usize);
+        let index = (y / 4) * self.columns + x / 2;
+        self.dots[index] |= BRAILLE_DOTS[y % 4][x % 2];
+        self.colors[index] = Some(color);
+    }
+
+    /// Draw a line.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Dot coordinates of the start of the line.
+    /// * `to` - Dot coordinates of the end of the line.
+    /// * `color` - Color.
+    fn line(&mut self, from: (f64, f64), to: (f64, f64), color: (u8, u8, u8)) {
+        if ![from.0, from.1, to.0, to.1]
+            .iter()
+            .all(|value| value.is_finite())
+        {
+            return;
+        }
+
+        // Clip the line to (a margin around) the canvas so that far away points stay cheap.
+        let limit = 4.0 * self.width().max(self.height());
+        let (from, to) = match clip_line(from, to, -limit, limit) {
+            Some(line) => line,
+            None => return,
+        };
+        let steps = (to.0 - from.0).abs().max((to.1 - from.1).abs()).ceil() as usize;
+        for step in 0..=steps {
+            let t = if steps == 0 {
+                0.0
+            } else {
+                step as f64 / steps as f64
+            };
+            self.set(
+                from.0 + t * (to.0 - from.0),
+                from.1 + t * (to.1 - from.1),
+                color,
+            );
+        }
+    }
+
+    /// Fill a polygon (using the even-odd rule).
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - Dot coordinates of the vertices of the polygon.
+    /// * `color` - Color.
+    fn fill(&mut self, points: &[(f64, f64)], color: (u8, u8, u8)) {
+        for y in 0..4 * self.rows {
+            let y = y as f64;
+            let mut crossings: Vec<f64> = points
+                .iter()
+                .zip(points.iter().cycle().skip(1))
+                .filter(|((_, y0), (_, y1))| (*y0 <= y) != (*y1 <= y))
+                .map(|((x0, y0), (x1, y1))| x0 + (y - y0) / (y1 - y0) * (x1 - x0))
+                .collect();
+            crossings.sort_by(f64::total_cmp);
+            for pair in crossings.chunks_exact(2) {
+                let (start, end) = (pair[0].max(0.0).ceil(), pair[1].min(self.width() - 1.0));
+                let mut x = start;
+                while x <= end {
+                    self.set(x, y, color);
+                    x += 1.0;
+                }
+            }
+        }
+    }
+
+    /// Render a row of the canvas.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - Index of the row.
+    /// * `colored` - Whether to color the characters using ANSI escape codes.
+    ///
+    /// # Returns
+    ///
+    /// Rendered row.
+    fn render_row(&self, row: usize, colored: bool) -> String {
+        let mut line = String::new();
+        let mut current = None;
+        for index in row * self.columns..(row + 1) * self.columns {
+            let dots = self.dots[index];
+            if colored && dots != 0 && self.colors[index] != current {
+                current = self.colors[index];
+                if let Some(color) = current {
+                    line.push_str(&foreground(color));
+                }
+            }
+            line.push(char::from_u32(0x2800 + u32::from(dots)).unwrap_or(' '));
+        }
+        if colored && current.is_some() {
+            line.push_str(RESET);
+        }
+        line
+    }
+}
+
+/// ANSI escape code resetting the text style.
+const RESET: &str = "\x1b[0m";
+
+/// Get the ANSI escape code setting a truecolor foreground color.
+///
+/// # Arguments
+///
+/// * `color` - Red, green, and blue components of the color.
+///
+/// # Returns
+///
+/// ANSI escape code.
+fn foreground((r, g, b): (u8, u8, u8)) -> String {
+    format!("\x1b[38;2;{r};{g};{b}m")
+}
+
+/// Clip a line to a square (Liang–Barsky algorithm).
+///
+/// # Arguments
+///
+/// * `from` - Start of the line.
+/// * `to` - End of the line.
+/// * `lower` - Lower bound of both coordinates.
+/// * `upper` - Upper bound of both coordinates.
+///
+/// # Returns
+///
+/// Start and end of the clipped line (`None` if the line lies outside the square).
+fn clip_line(
+    from: (f64, f64),
+    to: (f64, f64),
+    lower: f64,
+    upper: f64,
+) -> Option<((f64, f64), (f64, f64))> {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let (mut t0, mut t1) = (0.0f64, 1.0f64);
+    for (p, q) in [
+        (-dx, from.0 - lower),
+        (dx, upper - from.0),
+        (-dy, from.1 - lower),
+        (dy, upper - from.1),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else if p < 0.0 {
+            t0 = t0.max(q / p);
+        } else {
+            t1 = t1.min(q / p);
+        }
+    }
+    (t0 <= t1).then_some((
+        (from.0 + t0 * dx, from.1 + t0 * dy),
+        (from.0 + t1 * dx, from.1 + t1 * dy),
+    ))
+}
+
+/// Pad a text to a width, aligning it within the width.
+///
+/// # Arguments
+///
+/// * `text` - Text.
+/// * `width` - Width (in characters).
+/// * `position` - Position of the center of the text, as a fraction of the width (`0.0` aligns
+///   the text to the left, `1.0` to the right).
+///
+/// # Returns
+///
+/// Padded text (truncated if it is wider than `width`).
+fn align(text: &str, width: usize, position: f64) -> String {
+    let text: String = text.chars().take(width).collect();
+    let length = text.chars().count();
+    let start = ((width as f64 * position - length as f64 / 2.0)
+        .round()
+        .max(0.0) as usize)
+        .min(width - length);
+    format!("{}{text}", " ".repeat(start))
+}
+
+/// Renderer of figures to a terminal.
+struct TerminalRenderer<'a> {
+    /// Axes of the figure.
+    axes: Axes<'a>,
+
+    /// Width (in characters).
+    columns: usize,
+
+    /// Height (in lines).
+    lines: usize,
+
+    /// Whether to color the output using ANSI escape codes.
+    colored: bool,
+}
+
+impl TerminalRenderer<'_> {
+    /// Color text using ANSI escape codes (if the output is colored).
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text.
+    /// * `color` - Red, green, and blue components of the color.
+    ///
+    /// # Returns
+    ///
+    /// Colored text.
+    fn paint(&self, text: &str, color: (u8, u8, u8)) -> String {
+        if self.colored {
+            format!("{}{text}{RESET}", foreground(color))
+        } else {
+            String::from(text)
+        }
+    }
+
+    /// Render the figure.
+    ///
+    /// # Returns
+    ///
+    /// Lines of the rendered figure.
+    fn render(&self) -> Vec<String> {
+        let format = &self.axes.figure.format;
+        let mut header = Vec::new();
+        let mut footer = Vec::new();
+        if let Some(title) = &format.title {
+            let title = align(title, self.columns, 0.5);
+            header.push(if self.colored {
+                format!("\x1b[1m{title}{RESET}")
+            } else {
+                title
+            });
+        }
+        self.render_legend(&mut footer);
+
+        let mut body = if self.axes.is_3d() {
+            self.render_3d(self.lines.saturating_sub(header.len() + footer.len()))
+        } else {
+            self.render_2d(self.lines.saturating_sub(header.len() + footer.len()))
+        };
+        header.append(&mut body);
+        header.append(&mut footer);
+        header
+    }
+
+    /// Render a 2D figure.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - Height available for the axes (in lines).
+    ///
+    /// # Returns
+    ///
+    /// Lines of the rendered axes, tick labels, and axis labels.
+    fn render_2d(&self, lines: usize) -> Vec<String> {
+        let format = &self.axes.figure.format;
+        let (x_scale, y_scale) = (&self.axes.x, &self.axes.y[0]);
+
+        // Layout: y-axis label, plot rows, x-axis line, x-axis tick labels, and x-axis label.
+        let mut rows = lines.saturating_sub(2);
+        rows -= usize::from(format.y_label.is_some()).min(rows);
+        rows -= usize::from(format.x_label.is_some()).min(rows);
+        let rows = rows.max(2);
+        let y_ticks = y_scale.ticks((rows / 3).max(2));
+        let gutter = y_ticks
+            .iter()
+            .map(|tick| tick.label.chars().count())
+            .max()
+            .unwrap_or(0);
+        let columns = self.columns.saturating_sub(gutter + 2).max(2);
+        let x_ticks = x_scale.ticks((columns / 10).max(2));
+
+        let mut canvas = Canvas::new(columns, rows);
+        for trace in &self.axes.traces {
+            self.draw_trace(&mut canvas, trace);
+        }
+
+        // y-axis label, and rows of the plot with y-axis tick labels.
+        let mut output = Vec::new();
+        if let Some(y_label) = &format.y_label {
+            output.push(align(y_label, self.columns, 0.0));
+        }
+        let mut row_labels = vec![None; rows];
+        for tick in &y_ticks {
+            let (_, y) = canvas.dot(0.0, tick.fraction);
+            if let Some(label) = row_labels.get_mut((y.round() as usize) / 4) {
+                *label = Some(&tick.label);
+            }
+        }
+        for (row, label) in row_labels.iter().enumerate() {
+            let (label, axis) = match label {
+                Some(label) => (label.as_str(), '┤'),
+                None => ("", '│'),
+            };
+            output.push(format!(
+                "{label:>gutter$} {axis}{}",
+                canvas.render_row(row, self.colored)
+            ));
+        }
+
+        // x-axis line, and x-axis tick labels (skipping labels that would overlap).
+        let mut axis: Vec<char> = vec!['─'; columns];
+        let mut labels: Vec<char> = vec![' '; columns];
+        let mut next_free = 0;
+        for tick in &x_ticks {
+            let (x, _) = canvas.dot(tick.fraction, 0.0);
+            let column = ((x.round() as usize) / 2).min(columns - 1);
+            axis[column] = '┬';
+            let length = tick.label.chars().count();
+            let start = column
+                .saturating_sub(length / 2)
+                .min(columns.saturating_sub(length));
+            if start >= next_free {
+                for (offset, c) in tick.label.chars().enumerate() {
+                    if let Some(slot) = labels.get_mut(start + offset) {
+                        *slot = c;
+                    }
+                }
+                next_free = start + length + 1;
+            }
+        }
+        output.push(format!(
+            "{} └{}",
+            " ".repeat(gutter),
+            axis.iter().collect::<String>()
+        ));
+        output.push(format!(
+            "{}  {}",
+            " ".repeat(gutter),
+            labels.iter().collect::<String>().trim_end()
+        ));
+        if let Some(x_label) = &format.x_label {
+            output.push(format!(
+                "{}  {}",
+                " ".repeat(gutter),
+                align(x_label, columns, 0.5)
+            ));
+        }
+        output
+    }
+
+    /// Render a 3D figure, as seen from the default plotly.js camera position.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - Height available for the axes (in lines).
+    ///
+    /// # Returns
+    ///
+    /// Lines of the rendered axes and axis labels.
+    fn render_3d(&self, lines: usize) -> Vec<String> {
+        let format = &self.axes.figure.format;
+        let labels: Vec<String> = [
+            ("x", &format.x_label),
+            ("y", &format.y_label),
+            ("z", &format.z_label),
+        ]
+        .into_iter()
+        .filter_map(|(axis, label)| Some(format!("{axis}: {}", label.as_ref()?)))
+        .collect();
+        let rows = lines.saturating_sub(usize::from(!labels.is_empty())).max(2);
+
+        // The projected axes cube is drawn in a square region (braille dots are roughly square).
+        let columns = self.columns.min(2 * rows);
+        let mut canvas = Canvas::new(columns, rows);
+        let corners = [-1.0, 1.0];
+        for a in corners {
+            for b in corners {
+                let edges = [
+                    [(-1.0, a, b), (1.0, a, b)],
+                    [(a, -1.0, b), (a, 1.0, b)],
+                    [(a, b, -1.0), (a, b, 1.0)],
+                ];
+                for [from, to] in edges {
+                    let from = project_cube(from.0, from.1, from.2);
+                    let to = project_cube(to.0, to.1, to.2);
+                    canvas.line(
+                        canvas.dot(from.0, from.1),
+                        canvas.dot(to.0, to.1),
+                        CUBE_COLOR,
+                    );
+                }
+            }
+        }
+        for trace in &self.axes.traces {
+            let z = trace.z.as_deref().unwrap_or_default();
+            let points: Vec<(f64, f64)> = trace
+                .x
+                .iter()
+                .zip(&trace.y)
+                .zip(z)
+                .map(|((x, y), z)| {
+                    let (u, v) = self.axes.project(*x, *y, *z);
+                    canvas.dot(u, v)
+                })
+                .collect();
+            self.draw_points(&mut canvas, trace, &points);
+        }
+
+        let padding = " ".repeat((self.columns - columns) / 2);
+        let mut output: Vec<String> = (0..rows)
+            .map(|row| format!("{padding}{}", canvas.render_row(row, self.colored)))
+            .collect();
+        if !labels.is_empty() {
+            output.push(align(&labels.join("  "), self.columns, 0.5));
+        }
+        output
+    }
+
+    /// Draw a 2D trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `canvas` - Canvas.
+    /// * `trace` - Trace.
+    fn draw_trace(&self, canvas: &mut Canvas, trace: &PlotTrace) {
+        let x_scale = &self.axes.x;
+        let y_scale = &self.axes.y[trace.y_axis];
+        let (width, height) = (canvas.width(), canvas.height());
+        let dot = |x: f64, y: f64| {
+            (
+                x_scale.fraction(x) * (width - 1.0),
+                (1.0 - y_scale.fraction(y)) * (height - 1.0),
+            )
+        };
+
+        // Filled area.
+        if let Some(polygon) = &trace.fill_polygon {
+            let points: Vec<(f64, f64)> = polygon
+                .iter()
+                .map(|(x, y)| dot(*x, *y))
+                .filter(|(x, y)| x.is_finite() && y.is_finite())
+                .collect();
+            canvas.fill(&points, trace.fill_color().to_rgb());
+        }
+
+        match trace.trace.plot_type {
+            PlotType::Scatter => {
+                let points: Vec<(f64, f64)> = trace
+                    .x
+                    .iter()
+                    .zip(&trace.y)
+                    .map(|(x, y)| dot(*x, *y))
+                    .collect();
+                self.draw_points(canvas, trace, &points);
+            }
+            PlotType::Bar => {
+                let (offset, width) = trace.bar;
+                let color = trace.marker_color().to_rgb();
+                let base = y_scale.fraction(0.0);
+                let bottom = (1.0 - if base.is_finite() { base } else { 0.0 }) * (height - 1.0);
+                for (x, y) in trace.x.iter().zip(&trace.y) {
+                    let (left, top) = dot(x + offset - width / 2.0, *y);
+                    let (right, _) = dot(x + offset + width / 2.0, *y);
+                    canvas.fill(
+                        &[(left, top), (right, top), (right, bottom), (left, bottom)],
+                        color,
+                    );
+                }
+            }
+            PlotType::Box => {
+                let color = trace.line_color().to_rgb();
+                let marker_color = trace.marker_color().to_rgb();
+                let half_width = trace.box_width / 2.0;
+                for stats in &trace.boxes {
+                    let (left, q1) = dot(stats.x - half_width, stats.q1);
+                    let (right, q3) = dot(stats.x + half_width, stats.q3);
+                    let (center, median) = dot(stats.x, stats.median);
+                    let (_, lower) = dot(stats.x, stats.lower_whisker);
+                    let (_, upper) = dot(stats.x, stats.upper_whisker);
+                    for (from, to) in [
+                        ((left, q1), (right, q1)),
+                        ((right, q1), (right, q3)),
+                        ((right, q3), (left, q3)),
+                        ((left, q3), (left, q1)),
+                        ((left, median), (right, median)),
+                        ((center, q1), (center, lower)),
+                        ((center, q3), (center, upper)),
+                    ] {
+                        canvas.line(from, to, color);
+                    }
+                    for outlier in &stats.outliers {
+                        let (_, y) = dot(stats.x, *outlier);
+                        canvas.set(center, y, marker_color);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Draw the lines and/or markers of a scatter trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `canvas` - Canvas.
+    /// * `trace` - Trace.
+    /// * `points` - Dot coordinates of the data points.
+    fn draw_points(&self, canvas: &mut Canvas, trace: &PlotTrace, points: &[(f64, f64)]) {
+        if trace.has_lines() && trace.trace.line_width != Some(0.0) {
+            // Lines are broken at gaps (i.e. missing data points).
+            let color = trace.line_color().to_rgb();
+            for pair in points.windows(2) {
+                canvas.line(pair[0], pair[1], color);
+            }
+            if let [point] = points {
+                canvas.set(point.0, point.1, color);
+            }
+        }
+        if trace.has_markers() {
+            let color = trace.marker_color().to_rgb();
+            for (x, y) in points {
+                if x.is_finite() && y.is_finite() {
+                    for (dx, dy) in [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0)] {
+                        canvas.set(x + dx, y + dy, color);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Render the legend (if shown), wrapping its entries to the width of the figure.
+    ///
+    /// # Arguments
+    ///
+    /// * `output` - Lines of the rendered figure to append the legend to.
+    fn render_legend(&self, output: &mut Vec<String>) {
+        if !self.axes.show_legend() {
+            return;
+        }
+        let mut line = String::new();
+        let mut length = 0;
+        for trace in &self.axes.traces {
+            let (symbol, color) = match trace.trace.plot_type {
+                PlotType::Scatter if trace.has_lines() => ("──", trace.line_color()),
+                PlotType::Scatter => ("••", trace.marker_color()),
+                PlotType::Bar => ("██", trace.marker_color()),
+                PlotType::Box => ("██", trace.fill_color()),
+            };
+            let name = trace.name();
+            let entry_length = name.chars().count() + 5;
+            if length > 0 && length + entry_length > self.columns {
+                output.push(std::mem::take(&mut line));
+                length = 0;
+            }
+            let _ = write!(line, "{} {name}   ", self.paint(symbol, color.to_rgb()));
+            length += entry_length;
+        }
+        output.push(String::from(line.trim_end()));
+    }
+}
+
+impl Figure {
+    /// Render the figure for display in a terminal.
+    ///
+    /// Traces are drawn with Unicode braille characters, colored using ANSI truecolor escape
+    /// codes. Shapes and annotations are not drawn, and 3D figures are drawn as seen from the
+    /// default plotly.js camera position (without tick labels).
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - Width (in characters).
+    /// * `lines` - Height (in lines).
+    ///
+    /// # Returns
+    ///
+    /// Rendered figure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::quick_plot_2d;
+    ///
+    /// let text = quick_plot_2d([1.0, 2.0, 3.0], [1.0, 4.0, 9.0]).to_terminal(60, 20);
+    /// assert_eq!(text.lines().count(), 20);
+    /// ```
+    pub fn to_terminal(&self, columns: usize, lines: usize) -> String {
+        self.render_terminal(columns, lines, true)
+    }
+
+    /// Print the figure to the terminal (see [`Figure::to_terminal`]).
+    ///
+    /// This is an alternative to [`Figure::show`] when no web browser is available (e.g. over
+    /// SSH). The figure spans the width of the terminal, and is not colored if the `NO_COLOR`
+    /// environment variable is set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::quick_plot_2d;
+    ///
+    /// quick_plot_2d([1.0, 2.0, 3.0], [1.0, 4.0, 9.0]).show_terminal();
+    /// ```
+    pub fn show_terminal(&self) {
+        let (columns, lines) = match terminal_size::terminal_size() {
+            Some((terminal_size::Width(width), terminal_size::Height(height))) => {
+                (usize::from(width), Some(usize::from(height)))
+            }
+            None => (DEFAULT_COLUMNS, None),
+        };
+
+        // Keep roughly the aspect ratio of plotly.js figures (characters are about twice as tall
+        // as they are wide), without exceeding the height of the terminal.
+        let height = columns * 3 / 10;
+        let lines = lines.map_or(height, |lines| height.min(lines.saturating_sub(1)));
+        let colored = std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty());
+        println!("{}", self.render_terminal(columns, lines, colored));
+    }
+
+    /// Render the figure for display in a terminal (see [`Figure::to_terminal`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - Width (in characters).
+    /// * `lines` - Height (in lines).
+    /// * `colored` - Whether to color the output using ANSI escape codes.
+    ///
+    /// # Returns
+    ///
+    /// Rendered figure.
+    fn render_terminal(&self, columns: usize, lines: usize, colored: bool) -> String {
+        let renderer = TerminalRenderer {
+            axes: Axes::new(self),
+            columns: columns.max(MIN_COLUMNS),
+            lines: lines.max(MIN_LINES),
+            colored,
+        };
+        renderer.render().join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::FormatBuilder;
+    use crate::quick::{quick_plot_2d_with_labels, quick_plot_3d};
+    use crate::trace::Trace;
+
+    #[test]
+    fn test_to_terminal() {
+        let fig = quick_plot_2d_with_labels([0.0, 1.0, 2.0], [0.0, 5.0, 10.0], "x", "y", "Title");
+        let text = fig.render_terminal(40, 16, false);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 16);
+        assert!(lines[0].trim() == "Title");
+        assert_eq!(lines[1], "y");
+        assert!(lines[2].starts_with("10 ┤"));
+        assert!(lines.iter().any(|line| line.contains('└')));
+        assert_eq!(lines[15].trim(), "x");
+        assert!(lines.iter().all(|line| line.chars().count() <= 40));
+        assert!(fig.to_terminal(40, 16).contains("\x1b[38;2;31;119;180m"));
+    }
+
+    #[test]
+    fn test_extreme_ranges() {
+        let fig = Figure::new(
+            vec![Trace::new_2d([-1e308, 1e308], [0.0, 1e-300])],
+            FormatBuilder::default().build().unwrap(),
+        );
+        let text = fig.render_terminal(60, 16, false);
+        assert!(text.contains("1.1e308"));
+        assert!(text.contains("1e-300"));
+    }
+
+    #[test]
+    fn test_legend() {
+        let fig = Figure::new(
+            vec![
+                Trace::new_2d([1.0, 2.0], [1.0, 2.0]).name("first"),
+                Trace::new_2d(["a", "b"], [3.0, 1.0]).name("second"),
+            ],
+            FormatBuilder::default().build().unwrap(),
+        );
+        let text = fig.render_terminal(30, 10, false);
+        assert_eq!(text.lines().last(), Some("── first   ── second"));
+    }
+
+    #[test]
+    fn test_to_terminal_3d() {
+        let fig = quick_plot_3d([1.0, 2.0], [3.0, 4.0], [5.0, 6.0]);
+        let text = fig.render_terminal(50, 12, false);
+        assert_eq!(text.lines().count(), 12);
+        assert!(text.chars().any(|c| ('\u{2801}'..='\u{28ff}').contains(&c)));
+    }
+
+    #[test]
+    fn test_canvas() {
+        let mut canvas = Canvas::new(2, 1);
+        canvas.line((0.0, 0.0), (3.0, 3.0), (1, 2, 3));
+        assert_eq!(canvas.render_row(0, false), "⠑⢄");
+        assert_eq!(canvas.render_row(0, true), "\x1b[38;2;1;2;3m⠑⢄\x1b[0m");
+        canvas.line((0.0, f64::NAN), (3.0, 3.0), (1, 2, 3));
+        assert_eq!(
+            clip_line((-2.0, 0.0), (2.0, 0.0), -1.0, 1.0),
+            Some(((-1.0, 0.0), (1.0, 0.0)))
+        );
+        assert_eq!(clip_line((-2.0, 2.0), (2.0, 2.0), -1.0, 1.0), None);
+    }
+}
@@ -0,0 +1,233 @@
+use plotly::layout::themes::BuiltinTheme;
+
+/// Figure theme.
+///
+/// A theme sets the default colors (background, grid lines, text, and trace colors) and fonts of a
+/// figure. Themes are a re-implementation of the built-in themes of the [`plotly`] crate (see
+/// [`BuiltinTheme`]).
+///
+/// # Example
+///
+/// ```
+/// use plotting::{Format, FormatBuilder, Theme};
+///
+/// let format: Format = FormatBuilder::default()
+///     .title("Dark figure")
+///     .theme(Theme::PlotlyDark)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Theme {
+    /// Default plotly.js theme (white background with light gray grid lines).
+    #[default]
+    Default,
+
+    /// White background with light blue-gray grid lines.
+    PlotlyWhite,
+
+    /// Dark background.
+    PlotlyDark,
+
+    /// Seaborn-style theme (light gray-blue background with white grid lines).
+    Seaborn,
+
+    /// Seaborn-style theme with a white background and gray grid lines.
+    SeabornWhitegrid,
+
+    /// Seaborn-style theme with a dark background.
+    SeabornDark,
+
+    /// Matplotlib-style theme (white background with black axis lines and outside ticks).
+    Matplotlib,
+
+    /// Plotnine-style (ggplot2-style) theme (gray background with white grid lines).
+    Plotnine,
+}
+
+impl From<Theme> for BuiltinTheme {
+    fn from(theme: Theme) -> Self {
+        match theme {
+            Theme::Default => BuiltinTheme::Default,
+            Theme::PlotlyWhite => BuiltinTheme::PlotlyWhite,
+            Theme::PlotlyDark => BuiltinTheme::PlotlyDark,
+            Theme::Seaborn => BuiltinTheme::Seaborn,
+            Theme::SeabornWhitegrid => BuiltinTheme::SeabornWhitegrid,
+            Theme::SeabornDark => BuiltinTheme::SeabornDark,
+            Theme::Matplotlib => BuiltinTheme::Matplotlib,
+            Theme::Plotnine => BuiltinTheme::Plotnine,
+        }
+    }
+}
+
+/// Colors and fonts of a theme, used by the native (non-plotly.js) renderers.
+pub(crate) struct ThemeStyle {
+    /// Background color of the figure.
+    pub(crate) paper_color: u32,
+
+    /// Background color of the plotting area.
+    pub(crate) plot_color: u32,
+
+    /// Color of the grid lines.
+    pub(crate) grid_color: u32,
+
+    /// Color of the zero lines.
+    pub(crate) zero_line_color: u32,
+
+    /// Color of the axis lines (`None` if axis lines are not drawn).
+    pub(crate) axis_line_color: Option<u32>,
+
+    /// Text color.
+    pub(crate) text_color: u32,
+
+    /// Font family.
+    pub(crate) font_family: &'static str,
+
+    /// Default trace colors.
+    pub(crate) colorway: &'static [u32],
+}
+
+/// Default trace colors of plotly.js.
+pub(crate) const DEFAULT_COLORWAY: [u32; 10] = [
+    0x1F77B4, 0xFF7F0E, 0x2CA02C, 0xD62728, 0x9467BD, 0x8C564B, 0xE377C2, 0x7F7F7F, 0xBCBD22,
+    0x17BECF,
+];
+
+/// Trace colors of the plotly themes.
+const PLOTLY_COLORWAY: [u32; 10] = [
+    0x636EFA, 0xEF553B, 0x00CC96, 0xAB63FA, 0xFFA15A, 0x19D3F3, 0xFF6692, 0xB6E880, 0xFF97FF,
+    0xFECB52,
+];
+
+/// Trace colors of the seaborn themes.
+const SEABORN_COLORWAY: [u32; 6] = [0x4C72B0, 0x55A868, 0xC44E52, 0x8172B2, 0xCCB974, 0x64B5CD];
+
+/// Trace colors of the plotnine theme.
+const PLOTNINE_COLORWAY: [u32; 7] = [
+    0xF8766D, 0x7CAE00, 0x00BFC4, 0xC77CFF, 0xE58700, 0x00B0F6, 0xFF61C3,
+];
+
+/// Font family used by plotly.js by default.
+const DEFAULT_FONT_FAMILY: &str = "\"Open Sans\", verdana, arial, sans-serif";
+
+impl Theme {
+    /// Get the colors and fonts of the theme.
+    ///
+    /// # Returns
+    ///
+    /// Colors and fonts of the theme.
+    pub(crate) fn style(self) -> ThemeStyle {
+        match self {
+            Theme::Default => ThemeStyle {
+                paper_color: 0xFFFFFF,
+                plot_color: 0xFFFFFF,
+                grid_color: 0xEEEEEE,
+                zero_line_color: 0x444444,
+                axis_line_color: None,
+                text_color: 0x444444,
+                font_family: DEFAULT_FONT_FAMILY,
+                colorway: &DEFAULT_COLORWAY,
+            },
+            Theme::PlotlyWhite => ThemeStyle {
+                paper_color: 0xFFFFFF,
+                plot_color: 0xFFFFFF,
+                grid_color: 0xEBF0F8,
+                zero_line_color: 0xEBF0F8,
+                axis_line_color: None,
+                text_color: 0x2A3F5F,
+                font_family: DEFAULT_FONT_FAMILY,
+                colorway: &PLOTLY_COLORWAY,
+            },
+            Theme::PlotlyDark => ThemeStyle {
+                paper_color: 0x111111,
+                plot_color: 0x111111,
+                grid_color: 0x283442,
+                zero_line_color: 0x283442,
+                axis_line_color: None,
+                text_color: 0xF2F5FA,
+                font_family: DEFAULT_FONT_FAMILY,
+                colorway: &PLOTLY_COLORWAY,
+            },
+            Theme::Seaborn => ThemeStyle {
+                paper_color: 0xEAEAF2,
+                plot_color: 0xEAEAF2,
+                grid_color: 0xD3D3D3,
+                zero_line_color: 0xD3D3D3,
+                axis_line_color: None,
+                text_color: 0x333333,
+                font_family: "DejaVu Sans",
+                colorway: &SEABORN_COLORWAY,
+            },
+            Theme::SeabornWhitegrid => ThemeStyle {
+                paper_color: 0xFFFFFF,
+                plot_color: 0xFFFFFF,
+                grid_color: 0xE5E5E5,
+                zero_line_color: 0xE5E5E5,
+                axis_line_color: None,
+                text_color: 0x333333,
+                font_family: "DejaVu Sans",
+                colorway: &SEABORN_COLORWAY,
+            },
+            Theme::SeabornDark => ThemeStyle {
+                paper_color: 0x222222,
+                plot_color: 0x222222,
+                grid_color: 0x444444,
+                zero_line_color: 0x444444,
+                axis_line_color: None,
+                text_color: 0xEAEAF2,
+                font_family: "DejaVu Sans",
+                colorway: &SEABORN_COLORWAY,
+            },
+            Theme::Matplotlib => ThemeStyle {
+                paper_color: 0xFFFFFF,
+                plot_color: 0xFFFFFF,
+                grid_color: 0xE5E5E5,
+                zero_line_color: 0xE5E5E5,
+                axis_line_color: Some(0x000000),
+                text_color: 0x000000,
+                font_family: "Arial",
+                colorway: &DEFAULT_COLORWAY,
+            },
+            Theme::Plotnine => ThemeStyle {
+                paper_color: 0xEBEBEB,
+                plot_color: 0xEBEBEB,
+                grid_color: 0xFFFFFF,
+                zero_line_color: 0xFFFFFF,
+                axis_line_color: None,
+                text_color: 0x525252,
+                font_family: "DejaVu Sans",
+                colorway: &PLOTNINE_COLORWAY,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::FormatBuilder;
+    use crate::quick::quick_plot_2d;
+
+    #[test]
+    fn test_plotly_template() {
+        let mut fig = quick_plot_2d([1.0, 2.0], [3.0, 4.0]);
+        assert!(!fig.to_plotly_json().contains("template"));
+        fig.format = FormatBuilder::default()
+            .theme(Theme::PlotlyDark)
+            .build()
+            .unwrap();
+        assert!(
+            fig.to_plotly_json()
+                .contains(r##""paper_bgcolor":"#111111""##)
+        );
+    }
+
+    #[test]
+    fn test_style() {
+        assert_eq!(Theme::default(), Theme::Default);
+        assert_eq!(Theme::Default.style().colorway, DEFAULT_COLORWAY);
+        assert_eq!(Theme::Matplotlib.style().axis_line_color, Some(0x000000));
+    }
+}
@@ -0,0 +1,231 @@
+/// Point in time that can be plotted against a date axis.
+///
+/// Points in time are plotted at their wall-clock (local) time. For timestamps that include a
+/// time zone or UTC offset, this means that they are plotted in that time zone or UTC offset. To
+/// plot timestamps in a different time zone, convert them to that time zone before plotting (e.g.
+/// using [`chrono::DateTime::with_timezone`] or [`time::OffsetDateTime::to_offset`]).
+///
+/// # Implementations
+///
+/// | Feature | Types |
+/// | ------- | ----- |
+/// | `chrono` | [`chrono::DateTime`], [`chrono::NaiveDateTime`], [`chrono::NaiveDate`], and ISO 8601 strings ([`str`] and [`String`]) |
+/// | `time` | [`time::OffsetDateTime`], [`time::PrimitiveDateTime`], [`time::Date`], and ISO 8601 strings ([`str`] and [`String`]) |
+///
+/// ISO 8601 strings may either be full RFC 3339 timestamps (e.g. `"2024-03-01T12:30:00+01:00"`),
+/// date-times without a UTC offset (e.g. `"2024-03-01T12:30:00"` or `"2024-03-01 12:30:00.250"`),
+/// or dates (e.g. `"2024-03-01"`).
+///
+/// # Note
+///
+/// Strings that cannot be parsed are converted to NaN, and thus plotted as gaps rather than
+/// reported as errors. Use [`Timestamp::to_millis`] to check strings beforehand if needed.
+pub trait Timestamp {
+    /// Convert the point in time to the number of milliseconds between the Unix epoch
+    /// (1970-01-01T00:00:00) and its wall-clock time.
+    ///
+    /// # Returns
+    ///
+    /// Number of milliseconds since the Unix epoch.
+    fn to_millis(&self) -> f64;
+}
+
+impl<T: Timestamp + ?Sized> Timestamp for &T {
+    fn to_millis(&self) -> f64 {
+        (**self).to_millis()
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use super::Timestamp;
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone};
+
+    /// Formats accepted when parsing ISO 8601 date-times without a UTC offset.
+    const NAIVE_DATE_TIME_FORMATS: [&str; 4] = [
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M",
+    ];
+
+    impl<Tz: TimeZone> Timestamp for DateTime<Tz> {
+        fn to_millis(&self) -> f64 {
+            self.naive_local().to_millis()
+        }
+    }
+
+    impl Timestamp for NaiveDateTime {
+        fn to_millis(&self) -> f64 {
+            self.and_utc().timestamp_micros() as f64 / 1e3
+        }
+    }
+
+    impl Timestamp for NaiveDate {
+        fn to_millis(&self) -> f64 {
+            self.and_hms_opt(0, 0, 0).unwrap().to_millis()
+        }
+    }
+
+    impl Timestamp for str {
+        fn to_millis(&self) -> f64 {
+            let timestamp = self.trim();
+
+            // Full RFC 3339 timestamp (i.e. with a UTC offset).
+            if let Ok(date_time) = DateTime::parse_from_rfc3339(timestamp) {
+                return date_time.to_millis();
+            }
+
+            // Date-time without a UTC offset.
+            for format in NAIVE_DATE_TIME_FORMATS {
+                if let Ok(date_time) = NaiveDateTime::parse_from_str(timestamp, format) {
+                    return date_time.to_millis();
+                }
+            }
+
+            // Date.
+            if let Ok(date) = NaiveDate::parse_from_str(timestamp, "%Y-%m-%d") {
+                return date.to_millis();
+            }
+
+            f64::NAN
+        }
+    }
+
+    impl Timestamp for String {
+        fn to_millis(&self) -> f64 {
+            self.as_str().to_millis()
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impls {
+    use super::Timestamp;
+    use time::{Date, OffsetDateTime, PrimitiveDateTime};
+
+    /// Formats accepted when parsing ISO 8601 date-times without a UTC offset.
+    #[cfg(not(feature = "chrono"))]
+    const NAIVE_DATE_TIME_FORMATS: [&str; 4] = [
+        "[year]-[month]-[day]T[hour]:[minute]:[second][optional [.[subsecond]]]",
+        "[year]-[month]-[day] [hour]:[minute]:[second][optional [.[subsecond]]]",
+        "[year]-[month]-[day]T[hour]:[minute]",
+        "[year]-[month]-[day] [hour]:[minute]",
+    ];
+
+    impl Timestamp for OffsetDateTime {
+        fn to_millis(&self) -> f64 {
+            PrimitiveDateTime::new(self.date(), self.time()).to_millis()
+        }
+    }
+
+    impl Timestamp for PrimitiveDateTime {
+        fn to_millis(&self) -> f64 {
+            // Split off the whole milliseconds first, since nanoseconds since the epoch cannot be
+            // represented exactly as floating-point numbers.
+            let nanos = self.assume_utc().unix_timestamp_nanos();
+            (nanos.div_euclid(1_000_000) as f64) + (nanos.rem_euclid(1_000_000) as f64) / 1e6
+        }
+    }
+
+    impl Timestamp for Date {
+        fn to_millis(&self) -> f64 {
+            self.midnight().to_millis()
+        }
+    }
+
+    // Strings are parsed using chrono if both features are enabled.
+    #[cfg(not(feature = "chrono"))]
+    impl Timestamp for str {
+        fn to_millis(&self) -> f64 {
+            use time::format_description::{self, well_known::Rfc3339};
+
+            let timestamp = self.trim();
+
+            // Full RFC 3339 timestamp (i.e. with a UTC offset).
+            if let Ok(date_time) = OffsetDateTime::parse(timestamp, &Rfc3339) {
+                return date_time.to_millis();
+            }
+
+            // Date-time without a UTC offset.
+            for format in NAIVE_DATE_TIME_FORMATS {
+                let format = format_description::parse_owned::<2>(format).unwrap();
+                if let Ok(date_time) = PrimitiveDateTime::parse(timestamp, &format) {
+                    return date_time.to_millis();
+                }
+            }
+
+            // Date.
+            let format = format_description::parse("[year]-[month]-[day]").unwrap();
+            if let Ok(date) = Date::parse(timestamp, &format) {
+                return date.to_millis();
+            }
+
+            f64::NAN
+        }
+    }
+
+    #[cfg(not(feature = "chrono"))]
+    impl Timestamp for String {
+        fn to_millis(&self) -> f64 {
+            self.as_str().to_millis()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono() {
+        use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+
+        // Naive dates and date-times.
+        let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        assert_eq!(date.to_millis(), 1_709_251_200_000.0);
+        assert_eq!(
+            date.and_hms_milli_opt(12, 30, 0, 250).unwrap().to_millis(),
+            1_709_296_200_250.0
+        );
+
+        // Date-times with a time zone are plotted at their wall-clock time.
+        let utc = Utc.with_ymd_and_hms(2024, 3, 1, 12, 30, 0).unwrap();
+        let offset = utc.with_timezone(&FixedOffset::east_opt(3600).unwrap());
+        assert_eq!(utc.to_millis(), 1_709_296_200_000.0);
+        assert_eq!(offset.to_millis(), 1_709_296_200_000.0 + 3_600_000.0);
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    #[test]
+    fn test_iso_8601_str() {
+        assert_eq!("2024-03-01".to_millis(), 1_709_251_200_000.0);
+        assert_eq!("2024-03-01T12:30:00".to_millis(), 1_709_296_200_000.0);
+        assert_eq!("2024-03-01 12:30:00.250".to_millis(), 1_709_296_200_250.0);
+        assert_eq!("2024-03-01T12:30".to_millis(), 1_709_296_200_000.0);
+        assert_eq!("2024-03-01T12:30:00+01:00".to_millis(), 1_709_296_200_000.0);
+        assert_eq!(
+            String::from("2024-03-01T12:30:00Z").to_millis(),
+            1_709_296_200_000.0
+        );
+        assert!("not a date".to_millis().is_nan());
+        assert!("2024-13-01".to_millis().is_nan());
+        assert!("".to_millis().is_nan());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time() {
+        use time::{Date, Month, PrimitiveDateTime, Time, UtcOffset};
+
+        let date = Date::from_calendar_date(2024, Month::March, 1).unwrap();
+        let date_time = PrimitiveDateTime::new(date, Time::from_hms(12, 30, 0).unwrap());
+        assert_eq!(date.to_millis(), 1_709_251_200_000.0);
+        assert_eq!(date_time.to_millis(), 1_709_296_200_000.0);
+
+        // Date-times with a UTC offset are plotted at their wall-clock time.
+        let offset = date_time.assume_offset(UtcOffset::from_hms(1, 0, 0).unwrap());
+        assert_eq!(offset.to_millis(), 1_709_296_200_000.0);
+    }
+}
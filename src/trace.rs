@@ -1,5 +1,22 @@
+use crate::axis_data::AxisData;
 use crate::colors::Color;
+use crate::data_source::DataSource;
+use crate::downsampling::Downsampling;
+use crate::fill::Fill;
+use crate::format::Format;
+use crate::html::ArrayEncoding;
 use crate::line_style::LineStyle;
+use crate::mode::Mode;
+use crate::plot_type::PlotType;
+#[cfg(any(feature = "chrono", feature = "time"))]
+use crate::timestamp::Timestamp;
+use plotly::common::{Line, Marker, Visible};
+use plotly::{Bar, BoxPlot, Scatter, Scatter3D};
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// Number of points above which 2D scatter traces are rendered using WebGL by default.
+const DEFAULT_WEB_GL_THRESHOLD: usize = 100_000;
 
 /// Trace.
 ///
@@ -11,19 +28,51 @@ use crate::line_style::LineStyle;
 ///
 /// * [`Trace::new_2d`] - for 2D traces.
 /// * [`Trace::new_3d`] - for 3D traces.
+/// * [`Trace::new_time_series`] - for 2D traces whose x-axis data are points in time (requires the
+///   `chrono` or `time` feature).
+/// * [`Trace::new_stacked_area`] - for a set of 2D traces forming a stacked area chart.
+/// * `Trace::from_ndarray_columns` - for a set of 2D traces from the columns of a 2D `ndarray`
+///   array (requires the `ndarray` feature).
+/// * `Trace::from_nalgebra_columns` - for a set of 2D traces from the columns of a `nalgebra`
+///   matrix (requires the `nalgebra` feature).
+/// * [`Trace::from_csv`] - for a set of traces from the columns of a delimited text (e.g. CSV)
+///   file.
+/// * `Trace::from_polars` - for a set of traces from the columns of a `polars` data frame
+///   (requires the `polars` feature).
+/// * `Trace::from_arrow` - for a set of traces from the columns of an Arrow record batch (requires
+///   the `arrow` feature).
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Trace {
     /// x-axis data.
-    pub(crate) x: Vec<f64>,
+    pub(crate) x: AxisData,
 
     /// y-axis data.
-    pub(crate) y: Vec<f64>,
+    pub(crate) y: AxisData,
 
     /// z-axis data.
-    pub(crate) z: Option<Vec<f64>>,
+    pub(crate) z: Option<AxisData>,
+
+    /// Whether the x-axis data are points in time (in milliseconds since the Unix epoch).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) time_series: bool,
 
     /// Trace name (appears in the legend.)
     pub(crate) name: Option<String>,
 
+    /// Type of plot used to draw the trace.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) plot_type: PlotType,
+
+    /// Drawing mode (only used for scatter plots).
+    pub(crate) mode: Option<Mode>,
+
+    /// Marker color.
+    pub(crate) marker_color: Option<Color>,
+
+    /// Marker size.
+    pub(crate) marker_size: Option<usize>,
+
     /// Line color.
     pub(crate) line_color: Option<Color>,
 
@@ -32,6 +81,29 @@ pub struct Trace {
 
     /// Line style.
     pub(crate) line_style: Option<LineStyle>,
+
+    /// Fill mode.
+    pub(crate) fill: Option<Fill>,
+
+    /// Fill color.
+    pub(crate) fill_color: Option<Color>,
+
+    /// Stack group (traces in the same stack group are stacked on top of one another).
+    pub(crate) stack_group: Option<String>,
+
+    /// y-axis that the trace is plotted against (`1` for the primary y-axis, `2` for the first
+    /// secondary y-axis, and so on).
+    pub(crate) y_axis: Option<usize>,
+
+    /// Downsampling strategy (overrides the downsampling strategy of the figure).
+    pub(crate) downsampling: Option<Downsampling>,
+
+    /// Whether to render the trace using WebGL (determined from the number of points if not
+    /// specified).
+    pub(crate) web_gl: Option<bool>,
+
+    /// Whether the trace is visible (visible if not specified).
+    pub(crate) visible: Option<bool>,
 }
 
 impl Trace {
@@ -39,14 +111,16 @@ impl Trace {
     ///
     /// # Arguments
     ///
-    /// * `x` - x-axis data.
-    /// * `y` - y-axis data.
+    /// * `x` - x-axis data (numeric or categorical, see [`DataSource`]).
+    /// * `y` - y-axis data (numeric or categorical, see [`DataSource`]).
     ///
     /// # Returns
     ///
     /// Trace.
     ///
-    /// # Example
+    /// # Examples
+    ///
+    /// ## Numeric data
     ///
     /// ```
     /// use plotting::{Color, LineStyle, NamedColor, Trace};
@@ -57,15 +131,37 @@ impl Trace {
     ///     .line_width(2.0)
     ///     .line_style(LineStyle::Dot);
     /// ```
-    pub fn new_2d(x: impl Into<Vec<f64>>, y: impl Into<Vec<f64>>) -> Trace {
+    ///
+    /// ## Categorical data
+    ///
+    /// ```
+    /// use plotting::{PlotType, Trace};
+    ///
+    /// let trace = Trace::new_2d(["machine-1", "machine-2", "machine-3"], [4.0, 5.0, 6.0])
+    ///     .name("Run time")
+    ///     .plot_type(PlotType::Bar);
+    /// ```
+    pub fn new_2d(x: impl DataSource, y: impl DataSource) -> Trace {
         Trace {
-            x: x.into(),
-            y: y.into(),
+            x: x.into_axis_data(),
+            y: y.into_axis_data(),
             z: None,
+            time_series: false,
             name: None,
+            plot_type: PlotType::default(),
+            mode: None,
+            marker_color: None,
+            marker_size: None,
             line_color: None,
             line_width: None,
             line_style: None,
+            fill: None,
+            fill_color: None,
+            stack_group: None,
+            y_axis: None,
+            downsampling: None,
+            web_gl: None,
+            visible: None,
         }
     }
 
@@ -73,9 +169,9 @@ impl Trace {
     ///
     /// # Arguments
     ///
-    /// * `x` - x-axis data.
-    /// * `y` - y-axis data.
-    /// * `z` - z-axis data.
+    /// * `x` - x-axis data (see [`DataSource`]).
+    /// * `y` - y-axis data (see [`DataSource`]).
+    /// * `z` - z-axis data (see [`DataSource`]).
     ///
     /// # Returns
     ///
@@ -92,18 +188,139 @@ impl Trace {
     ///     .line_width(2.0)
     ///     .line_style(LineStyle::Dot);
     /// ```
-    pub fn new_3d(x: impl Into<Vec<f64>>, y: impl Into<Vec<f64>>, z: impl Into<Vec<f64>>) -> Trace {
+    pub fn new_3d(x: impl DataSource, y: impl DataSource, z: impl DataSource) -> Trace {
         Trace {
-            x: x.into(),
-            y: y.into(),
-            z: Some(z.into()),
+            x: x.into_axis_data(),
+            y: y.into_axis_data(),
+            z: Some(z.into_axis_data()),
+            time_series: false,
             name: None,
+            plot_type: PlotType::default(),
+            mode: None,
+            marker_color: None,
+            marker_size: None,
             line_color: None,
             line_width: None,
             line_style: None,
+            fill: None,
+            fill_color: None,
+            stack_group: None,
+            y_axis: None,
+            downsampling: None,
+            web_gl: None,
+            visible: None,
         }
     }
 
+    /// Constructor for a 2D trace whose x-axis data are points in time.
+    ///
+    /// Unless another x-axis type is specified in the figure format, figures containing a time
+    /// series are plotted with a date x-axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - Points in time (x-axis data). See [`Timestamp`] for the supported types.
+    /// * `y` - y-axis data (see [`DataSource`]).
+    ///
+    /// # Returns
+    ///
+    /// Trace.
+    ///
+    /// # Note
+    ///
+    /// Strings that cannot be parsed as points in time do not cause an error: they are plotted as
+    /// gaps (see [`Timestamp`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(any(feature = "chrono", feature = "time"))]
+    /// # {
+    /// use plotting::Trace;
+    ///
+    /// let trace = Trace::new_time_series(
+    ///     ["2024-03-01T09:00:00", "2024-03-01T12:00:00", "2024-03-01T15:00:00"],
+    ///     [1.0, 4.0, 2.0],
+    /// )
+    /// .name("Load");
+    /// # }
+    /// ```
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    pub fn new_time_series<T: Timestamp>(
+        t: impl IntoIterator<Item = T>,
+        y: impl DataSource,
+    ) -> Trace {
+        let x: Vec<f64> = t.into_iter().map(|t| t.to_millis()).collect();
+        let mut trace = Trace::new_2d(x, y);
+        trace.time_series = true;
+        trace
+    }
+
+    /// Constructor for a set of 2D traces forming a stacked area chart.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - x-axis data (shared by all of the traces, see [`DataSource`]).
+    /// * `ys` - Numeric y-axis data for each trace (see [`DataSource`]), ordered from the bottom of
+    ///   the stack to the top.
+    ///
+    /// # Returns
+    ///
+    /// Traces, where the y-axis data of each trace is the cumulative sum of the y-axis data up to
+    /// and including that trace. The first trace is filled to `y = 0` and every subsequent trace
+    /// is filled to the trace below it.
+    ///
+    /// # Panics
+    ///
+    /// * If the y-axis data for any of the traces is not numeric.
+    /// * If the y-axis data for any of the traces does not have the same length as the x-axis
+    ///   data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::Trace;
+    ///
+    /// let traces: Vec<Trace> = Trace::new_stacked_area(
+    ///     [1.0, 2.0, 3.0],
+    ///     vec![vec![1.0, 2.0, 1.0], vec![2.0, 1.0, 3.0]],
+    /// )
+    /// .into_iter()
+    /// .zip(["Bottom", "Top"])
+    /// .map(|(trace, name)| trace.name(name))
+    /// .collect();
+    /// ```
+    pub fn new_stacked_area(
+        x: impl DataSource,
+        ys: impl IntoIterator<Item = impl DataSource>,
+    ) -> Vec<Trace> {
+        let x = x.into_axis_data();
+        let mut cumulative = vec![0.0; x.len()];
+        ys.into_iter()
+            .enumerate()
+            .map(|(i, y)| {
+                let y = y.into_axis_data();
+                let y = y
+                    .as_numeric()
+                    .expect("The y-axis data of each trace must be numeric.");
+                assert_eq!(
+                    y.len(),
+                    x.len(),
+                    "The y-axis data of each trace must have the same length as the x-axis data."
+                );
+
+                // Stack the y-axis data on top of the previous traces.
+                for (total, value) in cumulative.iter_mut().zip(y) {
+                    *total += value;
+                }
+
+                // The bottom trace is filled to zero and all others to the trace below.
+                let fill = if i == 0 { Fill::ToZeroY } else { Fill::ToNextY };
+                Trace::new_2d(x.clone(), cumulative.clone()).fill(fill)
+            })
+            .collect()
+    }
+
     /// Set the name of this trace.
     ///
     /// # Arguments
@@ -118,6 +335,71 @@ impl Trace {
         self
     }
 
+    /// Set the type of plot used to draw this trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `plot_type` - Type of plot.
+    ///
+    /// # Returns
+    ///
+    /// The trace with the updated plot type.
+    ///
+    /// # Note
+    ///
+    /// Bar charts and box plots are only supported for 2D traces.
+    pub fn plot_type(mut self, plot_type: PlotType) -> Self {
+        self.plot_type = plot_type;
+        self
+    }
+
+    /// Set the drawing mode for this trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Drawing mode (e.g. [`Mode::Markers`] for a dot plot).
+    ///
+    /// # Returns
+    ///
+    /// The trace with the updated drawing mode.
+    ///
+    /// # Note
+    ///
+    /// The drawing mode is only used for scatter plots.
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Set the marker color for this trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `marker_color` - Marker color (also used as the color of the bars of a bar chart and the
+    ///   markers of a box plot).
+    ///
+    /// # Returns
+    ///
+    /// The trace with the updated marker color.
+    pub fn marker_color(mut self, marker_color: Color) -> Self {
+        self.marker_color = Some(marker_color);
+        self
+    }
+
+    /// Set the marker size for this trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `marker_size` - Marker size.
+    ///
+    /// # Returns
+    ///
+    /// The trace with the updated marker size.
+    pub fn marker_size(mut self, marker_size: usize) -> Self {
+        self.marker_size = Some(marker_size);
+        self
+    }
+
     /// Set the line color for this trace.
     ///
     /// # Arguments
@@ -159,4 +441,674 @@ impl Trace {
         self.line_style = Some(line_style);
         self
     }
+
+    /// Set the fill mode for this trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `fill` - Fill mode.
+    ///
+    /// # Returns
+    ///
+    /// The trace with the updated fill mode.
+    ///
+    /// # Note
+    ///
+    /// Fills are only supported for 2D traces.
+    pub fn fill(mut self, fill: Fill) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    /// Set the fill color for this trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `fill_color` - Fill color.
+    ///
+    /// # Returns
+    ///
+    /// The trace with the updated fill color.
+    pub fn fill_color(mut self, fill_color: Color) -> Self {
+        self.fill_color = Some(fill_color);
+        self
+    }
+
+    /// Set the stack group for this trace.
+    ///
+    /// All 2D traces in the same stack group are stacked on top of one another (in the order that
+    /// they were added to the figure) and filled to the trace below them by plotly, unless a
+    /// different fill mode is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `stack_group` - Name of the stack group.
+    ///
+    /// # Returns
+    ///
+    /// The trace with the updated stack group.
+    pub fn stack_group(mut self, stack_group: impl Into<String>) -> Self {
+        self.stack_group = Some(stack_group.into());
+        self
+    }
+
+    /// Set the y-axis that this trace is plotted against.
+    ///
+    /// # Arguments
+    ///
+    /// * `y_axis` - y-axis number (`1` for the primary y-axis, `2` for the first secondary y-axis
+    ///   defined in the figure format, and so on).
+    ///
+    /// # Returns
+    ///
+    /// The trace with the updated y-axis.
+    ///
+    /// # Panics
+    ///
+    /// If `y_axis` is `0`.
+    ///
+    /// # Note
+    ///
+    /// * Secondary y-axes are only supported for 2D traces.
+    /// * If the figure format does not define the y-axis, or if the y-axis is beyond y-axis 8 (the
+    ///   last y-axis that is plotted), the trace is plotted against the last plotted y-axis.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{AxisFormatBuilder, Figure, FormatBuilder, Trace};
+    ///
+    /// let altitude = Trace::new_2d([0.0, 1.0, 2.0], [0.0, 120.0, 400.0]).name("Altitude");
+    /// let speed = Trace::new_2d([0.0, 1.0, 2.0], [0.0, 50.0, 60.0])
+    ///     .name("Speed")
+    ///     .y_axis(2);
+    ///
+    /// let format = FormatBuilder::default()
+    ///     .x_label("Time [s]")
+    ///     .y_label("Altitude [m]")
+    ///     .secondary_y_axes(vec![
+    ///         AxisFormatBuilder::default()
+    ///             .label("Speed [m/s]")
+    ///             .build()
+    ///             .unwrap(),
+    ///     ])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let fig = Figure::new(vec![altitude, speed], format);
+    /// ```
+    pub fn y_axis(mut self, y_axis: usize) -> Self {
+        assert!(y_axis >= 1, "y-axis numbers start at 1.");
+        self.y_axis = Some(y_axis);
+        self
+    }
+
+    /// Set the downsampling strategy for this trace.
+    ///
+    /// The downsampling strategy set for a trace overrides the downsampling strategy of the
+    /// figure (see [`crate::FormatBuilder::downsampling`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `downsampling` - Downsampling strategy.
+    ///
+    /// # Returns
+    ///
+    /// The trace with the updated downsampling strategy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::{Downsampling, Trace};
+    ///
+    /// let x: Vec<f64> = (0..100_000).map(|i| i as f64).collect();
+    /// let y: Vec<f64> = x.iter().map(|x| (x / 100.0).sin()).collect();
+    ///
+    /// // Keep the minimum and maximum of 500 buckets (i.e. at most 1000 points).
+    /// let trace = Trace::new_2d(x, y).downsampling(Downsampling::MinMax(1000));
+    /// ```
+    pub fn downsampling(mut self, downsampling: Downsampling) -> Self {
+        self.downsampling = Some(downsampling);
+        self
+    }
+
+    /// Set whether to render this trace using WebGL.
+    ///
+    /// By default, 2D scatter traces are rendered using WebGL (i.e. as plotly `scattergl` traces)
+    /// if they have more points than the WebGL threshold of the figure (see
+    /// [`crate::FormatBuilder::web_gl_threshold`]), which keeps interactive figures with millions
+    /// of points responsive. This setting overrides the threshold for this trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `web_gl` - `true` to render the trace using WebGL, `false` to render it using SVG.
+    ///
+    /// # Returns
+    ///
+    /// The trace with the updated WebGL setting.
+    ///
+    /// # Note
+    ///
+    /// WebGL is only used for 2D scatter traces that are not in a stack group (plotly.js does not
+    /// support stacking WebGL traces). All marker and line settings apply identically to both
+    /// rendering paths. WebGL is only used for HTML output (see [`crate::Figure::show`] and
+    /// [`crate::Figure::save_html`]); natively rendered output is unaffected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::Trace;
+    ///
+    /// let trace = Trace::new_2d([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]).web_gl(true);
+    /// ```
+    pub fn web_gl(mut self, web_gl: bool) -> Self {
+        self.web_gl = Some(web_gl);
+        self
+    }
+
+    /// Set whether this trace is visible.
+    ///
+    /// Hidden traces are not drawn (and do not appear in the legend), but are still part of the
+    /// figure, so that interactive controls (see [`crate::Menu`] and [`crate::Slider`]) can show
+    /// them.
+    ///
+    /// # Arguments
+    ///
+    /// * `visible` - `true` to show the trace, `false` to hide it.
+    ///
+    /// # Returns
+    ///
+    /// The trace with the updated visibility.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotting::Trace;
+    ///
+    /// let trace = Trace::new_2d([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]).visible(false);
+    /// ```
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// Get the data of this trace to render, after downsampling.
+    ///
+    /// # Arguments
+    ///
+    /// * `downsampling` - Downsampling strategy of the figure (only used if this trace does not
+    ///   specify its own).
+    ///
+    /// # Returns
+    ///
+    /// x-, y-, and z-axis data to render.
+    pub(crate) fn data(
+        &self,
+        downsampling: Option<Downsampling>,
+    ) -> (
+        Cow<'_, AxisData>,
+        Cow<'_, AxisData>,
+        Option<Cow<'_, AxisData>>,
+    ) {
+        let indices = self
+            .downsampling
+            .or(downsampling)
+            .filter(|_| self.plot_type != PlotType::Box)
+            .map(|downsampling| {
+                if self.z.is_some() || self.stack_group.is_some() {
+                    Downsampling::Stride(downsampling.max_points())
+                } else {
+                    downsampling
+                }
+            })
+            .and_then(|downsampling| {
+                // The points beyond the shortest axis data are not rendered, so they are not
+                // considered when selecting the points to render either.
+                let len = self
+                    .z
+                    .as_ref()
+                    .map_or(usize::MAX, AxisData::len)
+                    .min(self.x.len())
+                    .min(self.y.len());
+                downsampling.indices(
+                    self.x.as_numeric().map(|x| &x[..len]),
+                    self.y.as_numeric().map(|y| &y[..len]),
+                    len,
+                )
+            });
+        match indices {
+            Some(indices) => (
+                Cow::Owned(self.x.select(&indices)),
+                Cow::Owned(self.y.select(&indices)),
+                self.z.as_ref().map(|z| Cow::Owned(z.select(&indices))),
+            ),
+            None => (
+                Cow::Borrowed(&self.x),
+                Cow::Borrowed(&self.y),
+                self.z.as_ref().map(Cow::Borrowed),
+            ),
+        }
+    }
+
+    /// Create a plotly trace from this trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Format of the figure that the trace is placed on.
+    /// * `encoding` - Encoding of the numeric data arrays.
+    ///
+    /// # Returns
+    ///
+    /// Plotly trace.
+    ///
+    /// # Note
+    ///
+    /// The data of the trace is not copied into the plotly trace (unless it is downsampled);
+    /// instead, the plotly trace shares it with this trace.
+    pub(crate) fn plotly(
+        &self,
+        format: &Format,
+        encoding: ArrayEncoding,
+    ) -> Box<dyn plotly::Trace> {
+        let (x, y, z) = self.data(format.downsampling);
+        let threshold = format.web_gl_threshold.unwrap_or(DEFAULT_WEB_GL_THRESHOLD);
+        let web_gl = self.web_gl.unwrap_or(x.len() > threshold) && self.stack_group.is_none();
+        let x_encoding = if self.time_series {
+            encoding.full_precision()
+        } else {
+            encoding
+        };
+        let settings = match z {
+            Some(_) => self.plotly_3d(),
+            None => self.plotly_2d(web_gl, format.y_axis(self)),
+        };
+        Box::new(PlotlyTrace {
+            settings,
+            x: PlotlyData(x.into_owned(), x_encoding),
+            y: PlotlyData(y.into_owned(), encoding),
+            z: z.map(|z| PlotlyData(z.into_owned(), encoding)),
+        })
+    }
+
+    /// Get the value of a plotly.js attribute of this trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `attribute` - plotly.js trace attribute, with nested attributes separated by dots (e.g.
+    ///   `"line.color"`).
+    ///
+    /// # Returns
+    ///
+    /// Value of the attribute (`null` if the attribute is not set, i.e. if plotly.js uses its
+    /// default value).
+    pub(crate) fn plotly_attribute(&self, attribute: &str) -> serde_json::Value {
+        let settings = match self.z {
+            Some(_) => self.plotly_3d(),
+            None => self.plotly_2d(false, self.y_axis.unwrap_or(1)),
+        };
+        let settings: serde_json::Value =
+            serde_json::from_str(&settings.to_json()).expect("Plotly traces should be valid JSON.");
+        attribute
+            .split('.')
+            .try_fold(&settings, |value, key| value.get(key))
+            .cloned()
+            .unwrap_or(serde_json::Value::Null)
+    }
+
+    /// Create a plotly 2D trace (without any data) from the settings of this trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `web_gl` - Whether to render 2D scatter traces using WebGL.
+    /// * `y_axis` - y-axis that the trace is plotted against (`1` for the primary y-axis).
+    ///
+    /// # Returns
+    ///
+    /// Plotly trace.
+    fn plotly_2d(&self, web_gl: bool, y_axis: usize) -> Box<dyn plotly::Trace> {
+        // y-axis that the trace is plotted against.
+        let y_axis = (y_axis > 1).then(|| format!("y{y_axis}"));
+
+        match self.plot_type {
+            PlotType::Scatter => {
+                let mut scatter = Box::new(Scatter::<f64, f64>::default()).line(self.plotly_line());
+                if let Some(name) = &self.name {
+                    scatter = scatter.name(name);
+                }
+                if let Some(mode) = self.mode {
+                    scatter = scatter.mode(mode.into());
+                }
+                if let Some(marker) = self.plotly_marker() {
+                    scatter = scatter.marker(marker);
+                }
+                if let Some(fill) = self.fill {
+                    scatter = scatter.fill(fill.into());
+                }
+                if let Some(fill_color) = &self.fill_color {
+                    scatter = scatter.fill_color(fill_color.to_plotly_rgba());
+                }
+                if let Some(stack_group) = &self.stack_group {
+                    scatter = scatter.stack_group(stack_group);
+                }
+                if let Some(y_axis) = y_axis {
+                    scatter = scatter.y_axis(y_axis);
+                }
+                if let Some(visible) = self.plotly_visible() {
+                    scatter = scatter.visible(visible);
+                }
+                if web_gl {
+                    scatter = scatter.web_gl_mode(true);
+                }
+                scatter
+            }
+            PlotType::Bar => {
+                let mut bar = Box::new(Bar::<f64, f64>::default());
+                if let Some(name) = &self.name {
+                    bar = bar.name(name);
+                }
+                if let Some(marker) = self.plotly_marker() {
+                    bar = bar.marker(marker);
+                }
+                if let Some(y_axis) = y_axis {
+                    bar = bar.y_axis(y_axis);
+                }
+                if let Some(visible) = self.plotly_visible() {
+                    bar = bar.visible(visible);
+                }
+                bar
+            }
+            PlotType::Box => {
+                let mut box_plot =
+                    Box::new(BoxPlot::<f64, f64>::default()).line(self.plotly_line());
+                if let Some(name) = &self.name {
+                    box_plot = box_plot.name(name);
+                }
+                if let Some(marker) = self.plotly_marker() {
+                    box_plot = box_plot.marker(marker);
+                }
+                if let Some(fill_color) = &self.fill_color {
+                    box_plot = box_plot.fill_color(fill_color.to_plotly_rgba());
+                }
+                if let Some(y_axis) = y_axis {
+                    box_plot = box_plot.y_axis(y_axis);
+                }
+                if let Some(visible) = self.plotly_visible() {
+                    box_plot = box_plot.visible(visible);
+                }
+                box_plot
+            }
+        }
+    }
+
+    /// Create a plotly 3D trace (without any data) from the settings of this trace.
+    ///
+    /// # Returns
+    ///
+    /// Plotly trace.
+    fn plotly_3d(&self) -> Box<dyn plotly::Trace> {
+        let mut scatter = Box::new(Scatter3D::<f64, f64, f64>::default()).line(self.plotly_line());
+        if let Some(name) = &self.name {
+            scatter = scatter.name(name);
+        }
+        if let Some(mode) = self.mode {
+            scatter = scatter.mode(mode.into());
+        }
+        if let Some(marker) = self.plotly_marker() {
+            scatter = scatter.marker(marker);
+        }
+        if let Some(visible) = self.plotly_visible() {
+            scatter = scatter.visible(visible);
+        }
+        scatter
+    }
+
+    /// Get the plotly visibility of this trace.
+    ///
+    /// # Returns
+    ///
+    /// Plotly visibility (`None` if the visibility was not specified).
+    fn plotly_visible(&self) -> Option<Visible> {
+        self.visible.map(|visible| {
+            if visible {
+                Visible::True
+            } else {
+                Visible::False
+            }
+        })
+    }
+
+    /// Create a plotly line from the line settings of this trace.
+    ///
+    /// # Returns
+    ///
+    /// Plotly line.
+    fn plotly_line(&self) -> Line {
+        let mut line = Line::new();
+        if let Some(line_color) = &self.line_color {
+            line = line.color(line_color.to_plotly_rgba());
+        }
+        if let Some(line_width) = self.line_width {
+            line = line.width(line_width);
+        }
+        if let Some(line_style) = self.line_style {
+            line = line.dash(line_style.into());
+        }
+        line
+    }
+
+    /// Create a plotly marker from the marker settings of this trace.
+    ///
+    /// # Returns
+    ///
+    /// Plotly marker (`None` if no marker settings were specified).
+    fn plotly_marker(&self) -> Option<Marker> {
+        if self.marker_color.is_none() && self.marker_size.is_none() {
+            return None;
+        }
+        let mut marker = Marker::new();
+        if let Some(marker_color) = &self.marker_color {
+            marker = marker.color(marker_color.to_plotly_rgba());
+        }
+        if let Some(marker_size) = self.marker_size {
+            marker = marker.size(marker_size);
+        }
+        Some(marker)
+    }
+}
+
+/// Plotly trace whose data is shared with a [`Trace`] (rather than copied into the plotly trace).
+#[derive(Clone, Serialize)]
+struct PlotlyTrace {
+    /// Plotly trace holding all settings of the trace, but no data.
+    #[serde(flatten)]
+    settings: Box<dyn plotly::Trace>,
+
+    /// x-axis data.
+    x: PlotlyData,
+
+    /// y-axis data.
+    y: PlotlyData,
+
+    /// z-axis data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    z: Option<PlotlyData>,
+}
+
+impl plotly::Trace for PlotlyTrace {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+/// Axis data of a plotly trace, together with the encoding of numeric data (by default, numeric
+/// data is serialized the same way as plotly serializes vectors of data, i.e. with `NaN` values
+/// represented as `null`).
+#[derive(Clone)]
+struct PlotlyData(AxisData, ArrayEncoding);
+
+impl Serialize for PlotlyData {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.0 {
+            AxisData::Numeric(data) => self.1.serialize(data, serializer),
+            AxisData::Categorical(data) => serializer.collect_seq(data.iter()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_stacked_area() {
+        let traces = Trace::new_stacked_area(
+            [1.0, 2.0, 3.0],
+            vec![
+                vec![1.0, 2.0, 1.0],
+                vec![2.0, 1.0, 3.0],
+                vec![0.5, 0.5, 0.5],
+            ],
+        );
+        assert_eq!(traces.len(), 3);
+        assert_eq!(traces[0].x, [1.0, 2.0, 3.0]);
+        assert_eq!(traces[0].y, [1.0, 2.0, 1.0]);
+        assert_eq!(traces[1].y, [3.0, 3.0, 4.0]);
+        assert_eq!(traces[2].y, [3.5, 3.5, 4.5]);
+        assert_eq!(traces[0].fill, Some(Fill::ToZeroY));
+        assert_eq!(traces[1].fill, Some(Fill::ToNextY));
+        assert_eq!(traces[2].fill, Some(Fill::ToNextY));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_stacked_area_length_mismatch() {
+        Trace::new_stacked_area([1.0, 2.0, 3.0], vec![vec![1.0, 2.0]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "y-axis numbers start at 1.")]
+    fn test_y_axis_zero() {
+        Trace::new_2d([0.0], [0.0]).y_axis(0);
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    #[test]
+    fn test_new_time_series_invalid() {
+        // Strings that cannot be parsed are plotted as gaps.
+        let trace = Trace::new_time_series(["2024-03-01", "not a date"], [1.0, 2.0]);
+        let json = serde_json::to_value(trace.plotly(&Format::default(), ArrayEncoding::default()))
+            .unwrap();
+        assert_eq!(json["x"], serde_json::json!([1_709_251_200_000.0, null]));
+        assert_eq!(json["y"], serde_json::json!([1.0, 2.0]));
+    }
+
+    #[test]
+    fn test_data_downsampling() {
+        let x: Vec<f64> = (0..100).map(|i| i as f64).collect();
+        let trace = Trace::new_2d(x.clone(), x.clone());
+
+        // No downsampling.
+        let (x_data, y_data, z_data) = trace.data(None);
+        assert_eq!(x_data.len(), 100);
+        assert_eq!(y_data.len(), 100);
+        assert!(z_data.is_none());
+
+        // Downsampling of the figure.
+        let (x_data, y_data, _) = trace.data(Some(Downsampling::Stride(10)));
+        assert_eq!(x_data.len(), 10);
+        assert_eq!(x_data, y_data);
+
+        // Downsampling of the trace overrides the downsampling of the figure.
+        let trace = trace.downsampling(Downsampling::Stride(4));
+        let (x_data, _, _) = trace.data(Some(Downsampling::Stride(10)));
+        assert_eq!(*x_data, [0.0, 33.0, 66.0, 99.0]);
+
+        // Box plots are never downsampled.
+        let trace = Trace::new_2d(x.clone(), x)
+            .plot_type(PlotType::Box)
+            .downsampling(Downsampling::Stride(4));
+        assert_eq!(trace.data(None).0.len(), 100);
+    }
+
+    #[test]
+    fn test_data_downsampling_length_mismatch() {
+        let x: Vec<f64> = (0..50).map(|i| i as f64).collect();
+        let y: Vec<f64> = (0..100).map(|i| (i as f64).sin()).collect();
+        for downsampling in [Downsampling::Lttb(10), Downsampling::MinMax(10)] {
+            let trace = Trace::new_2d(x.clone(), y.clone()).downsampling(downsampling);
+            let (x_data, y_data, _) = trace.data(None);
+            assert!(x_data.len() <= 10);
+            assert_eq!(x_data.len(), y_data.len());
+            assert!(x_data.as_numeric().unwrap().iter().all(|x| *x < 50.0));
+        }
+    }
+
+    #[test]
+    fn test_plotly_data() {
+        let trace = Trace::new_2d(["a", "b"], [1.0, f64::NAN]).name("Trace");
+        let json = serde_json::to_value(trace.plotly(&Format::default(), ArrayEncoding::default()))
+            .unwrap();
+        assert_eq!(json["type"], "scatter");
+        assert_eq!(json["name"], "Trace");
+        assert_eq!(json["x"], serde_json::json!(["a", "b"]));
+        assert_eq!(json["y"], serde_json::json!([1.0, null]));
+        assert!(json.get("z").is_none());
+
+        let trace = Trace::new_3d([1.0], [2.0], [3.0]);
+        let json = serde_json::to_value(trace.plotly(&Format::default(), ArrayEncoding::default()))
+            .unwrap();
+        assert_eq!(json["type"], "scatter3d");
+        assert_eq!(json["z"], serde_json::json!([3.0]));
+    }
+
+    #[test]
+    fn test_plotly_web_gl() {
+        let trace_type = |trace: &Trace, format: &Format| {
+            let json =
+                serde_json::to_value(trace.plotly(format, ArrayEncoding::default())).unwrap();
+            json["type"].as_str().unwrap().to_string()
+        };
+        let small = Trace::new_2d([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]).line_width(2.0);
+        let format = Format::default();
+        assert_eq!(trace_type(&small, &format), "scatter");
+
+        // Threshold of the figure.
+        let format = crate::FormatBuilder::default()
+            .web_gl_threshold(2)
+            .build()
+            .unwrap();
+        assert_eq!(trace_type(&small, &format), "scattergl");
+
+        // The trace overrides the threshold of the figure.
+        let small = small.web_gl(false);
+        assert_eq!(trace_type(&small, &format), "scatter");
+        let small = small.web_gl(true);
+        assert_eq!(trace_type(&small, &Format::default()), "scattergl");
+
+        // Styling is identical in both paths.
+        let svg = serde_json::to_value(
+            small
+                .web_gl(false)
+                .plotly(&format, ArrayEncoding::default()),
+        )
+        .unwrap();
+        let gl = serde_json::to_value(
+            Trace::new_2d([1.0, 2.0, 3.0], [4.0, 5.0, 6.0])
+                .line_width(2.0)
+                .web_gl(true)
+                .plotly(&format, ArrayEncoding::default()),
+        )
+        .unwrap();
+        assert_eq!(svg["line"], gl["line"]);
+
+        // Stacked traces and bar charts are never rendered using WebGL.
+        let stacked = Trace::new_2d([1.0, 2.0, 3.0], [4.0, 5.0, 6.0])
+            .stack_group("a")
+            .web_gl(true);
+        assert_eq!(trace_type(&stacked, &format), "scatter");
+        let bar = Trace::new_2d([1.0, 2.0, 3.0], [4.0, 5.0, 6.0])
+            .plot_type(PlotType::Bar)
+            .web_gl(true);
+        assert_eq!(trace_type(&bar, &format), "bar");
+    }
 }